@@ -0,0 +1,63 @@
+use std::error::Error;
+use std::path::Path;
+
+use aoc_common::Baseline;
+use clap::Subcommand;
+
+use crate::{select_years, target_dir};
+
+#[derive(Subcommand)]
+pub enum BenchCommand {
+    /// Store the medians of the last `cargo bench` run in each year's
+    /// `benches/baseline.toml`, keeping entries for days that were not run.
+    Record {
+        #[arg(long)]
+        year: Option<u16>,
+    },
+    /// Compare the medians of the last `cargo bench` run against the
+    /// recorded baselines and fail if any step got slower.
+    Compare {
+        #[arg(long)]
+        year: Option<u16>,
+        /// Slowdown in percent that counts as a regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+pub fn bench(command: BenchCommand) -> Result<(), Box<dyn Error>> {
+    let criterion_dir = target_dir().join("criterion");
+    match command {
+        BenchCommand::Record { year } => {
+            for year in select_years(year)? {
+                let current = Baseline::from_criterion(year, &criterion_dir)?;
+                if current.is_empty() {
+                    println!("No benchmarks found for {}", year.year);
+                    continue;
+                }
+                let path = Path::new(year.baseline);
+                let mut baseline = Baseline::load(path)?;
+                baseline.update(current);
+                baseline.save(path)?;
+                println!("Recorded {} baseline in {}", year.year, year.baseline);
+            }
+            Ok(())
+        }
+        BenchCommand::Compare { year, threshold } => {
+            let mut regressions = 0;
+            for year in select_years(year)? {
+                let baseline = Baseline::load(Path::new(year.baseline))?;
+                let current = Baseline::from_criterion(year, &criterion_dir)?;
+                println!("Comparing Advent of Code {}:", year.year);
+                for comparison in baseline.compare(&current, threshold) {
+                    println!("{comparison}");
+                    regressions += usize::from(comparison.regressed);
+                }
+            }
+            match regressions {
+                0 => Ok(()),
+                _ => Err(format!("{regressions} steps regressed by more than {threshold}%").into()),
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::error::Error;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{find_year, target_dir};
+
+/// Where the other implementations of the 2022 days live, `day_NN.cpp`.
+fn cpp_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../aoc_2022/cpp")
+}
+
+/// Runs another implementation of a 2022 day on the same input as the Rust
+/// solution and compares their answers and timing.
+///
+/// C and C++ sources are compiled first, with `$CC` or `$CXX` if set; any
+/// other file is run as is. The program gets the input on stdin and prints
+/// the answer to each part on its own line.
+pub fn cross_check(
+    day: u8,
+    source: Option<PathBuf>,
+    input_root: Option<&Path>,
+) -> Result<(), Box<dyn Error>> {
+    let year = find_year(2022)?;
+    let entry = year
+        .day(day)
+        .ok_or_else(|| format!("no solution registered for 2022 day {day}"))?;
+    let source = source.unwrap_or_else(|| cpp_dir().join(format!("day_{day:02}.cpp")));
+    if !source.exists() {
+        return Err(format!("no other implementation at {}", source.display()).into());
+    }
+    let input = year.load_input(input_root, day)?;
+
+    let program = build(&source)?;
+    let name = source.file_name().unwrap_or_default().to_string_lossy();
+    let (answers, other_time) = run(&program, &input)?;
+    let rust = entry.solution.solve_timed(&input)?;
+
+    let mut mismatches = 0;
+    for (part, rust_answer, other_answer) in [
+        ("A", &rust.part_a, answers.first()),
+        ("B", &rust.part_b, answers.get(1)),
+    ] {
+        let other_answer = other_answer.map_or("<missing>", String::as_str);
+        let verdict = if rust_answer == other_answer {
+            "ok"
+        } else {
+            mismatches += 1;
+            "MISMATCH"
+        };
+        println!("Day {day} part {part}: {rust_answer} (rust), {other_answer} ({name})  {verdict}");
+    }
+    println!(
+        "Rust took {:.2?}, {name} took {other_time:.2?} including process start-up",
+        rust.timings.total()
+    );
+
+    match mismatches {
+        0 => Ok(()),
+        _ => Err(format!("{mismatches} answers differ").into()),
+    }
+}
+
+/// Compiles `source` into the target directory, unless it is up to date.
+fn build(source: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    let compiler = match source.extension().and_then(OsStr::to_str) {
+        Some("cpp" | "cc" | "cxx") => std::env::var("CXX").unwrap_or("c++".into()),
+        Some("c") => std::env::var("CC").unwrap_or("cc".into()),
+        _ => return Ok(source.to_path_buf()),
+    };
+    let out_dir = target_dir().join("cross_check");
+    fs::create_dir_all(&out_dir)?;
+    let program = out_dir.join(source.file_stem().unwrap_or_default());
+
+    let modified = |path: &Path| fs::metadata(path).and_then(|meta| meta.modified()).ok();
+    if modified(&program) >= modified(source) {
+        return Ok(program);
+    }
+    let status = Command::new(&compiler)
+        .args(["-O2", "-Wall", "-o"])
+        .arg(&program)
+        .arg(source)
+        .status()
+        .map_err(|err| format!("could not run {compiler}: {err}"))?;
+    if !status.success() {
+        return Err(format!("{compiler} failed to compile {}", source.display()).into());
+    }
+    Ok(program)
+}
+
+fn run(program: &Path, input: &str) -> Result<(Vec<String>, Duration), Box<dyn Error>> {
+    let start = Instant::now();
+    let mut child = Command::new(program)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("could not run {}: {err}", program.display()))?;
+    let mut stdin = child.stdin.take().unwrap();
+    // Write the input from another thread, as a program that prints before it
+    // has read everything would otherwise block on a full stdout pipe.
+    let (written, output) = thread::scope(|scope| {
+        let writer = scope.spawn(move || stdin.write_all(input.as_bytes()));
+        let output = child.wait_with_output();
+        (
+            writer.join().expect("writing stdin should not panic"),
+            output,
+        )
+    });
+    let output = output?;
+    written?;
+    let elapsed = start.elapsed();
+    if !output.status.success() {
+        return Err(format!("{} exited with {}", program.display(), output.status).into());
+    }
+    Ok((answers(&String::from_utf8_lossy(&output.stdout)), elapsed))
+}
+
+/// The non-empty lines of a program's output.
+fn answers(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_skip_blank_lines() {
+        assert_eq!(answers("66719\n\n198551\r\n"), ["66719", "198551"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_output_larger_than_a_pipe() {
+        let input = "1234567\n".repeat(100_000);
+        let (lines, _) = run(Path::new("cat"), &input).unwrap();
+        assert_eq!(lines.len(), 100_000);
+    }
+
+    #[test]
+    fn test_every_cpp_day_is_registered() {
+        let year = find_year(2022).unwrap();
+        for entry in fs::read_dir(cpp_dir()).unwrap() {
+            let name = entry.unwrap().file_name().into_string().unwrap();
+            let Some(day) = name
+                .strip_prefix("day_")
+                .and_then(|name| name.strip_suffix(".cpp"))
+            else {
+                continue;
+            };
+            assert!(year.day(day.parse().unwrap()).is_some(), "{name}");
+        }
+    }
+}
@@ -0,0 +1,221 @@
+mod bench;
+mod cross_check;
+mod new;
+
+use std::env;
+use std::error::Error;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc_common::{
+    load_input, print_summary, run_day, run_days, run_year, verify_year, write_outcomes,
+    DayOutcome, Format, Part, RunConfig, Verdict, Year,
+};
+use bench::{bench, BenchCommand};
+use clap::builder::FalseyValueParser;
+use clap::{Args, Parser, Subcommand};
+use cross_check::cross_check;
+use new::new_day;
+
+static YEARS: &[&Year] = &[
+    &aoc_2021::days::YEAR,
+    &advent_of_code_2022::YEAR,
+    &aoc_2023::days::YEAR,
+    &aoc_2024::days::YEAR,
+    &aoc_2025::days::YEAR,
+];
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    /// Directory with one folder of inputs per year, e.g. `2024/06.txt`.
+    /// Defaults to the `inputs` folder of each year's crate.
+    #[arg(long, global = true, env = aoc_common::INPUT_DIR_VAR)]
+    input_dir: Option<PathBuf>,
+    /// Give up on a day after this many seconds and carry on with the next.
+    #[arg(long, global = true, env = aoc_common::TIMEOUT_VAR)]
+    timeout: Option<f64>,
+    /// Solve the days of each year concurrently.
+    #[arg(
+        long,
+        global = true,
+        env = aoc_common::PARALLEL_VAR,
+        value_parser = FalseyValueParser::new()
+    )]
+    parallel: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single part, a single day, a whole year or everything.
+    Run(RunArgs),
+    /// Check every day of a year, or of all years, against its `answers.toml`.
+    Verify {
+        #[arg(long)]
+        year: Option<u16>,
+    },
+    /// Record or compare benchmark baselines, after running `cargo bench`.
+    #[command(subcommand)]
+    Bench(BenchCommand),
+    /// Compare a 2022 day with another implementation of it, by default
+    /// `aoc_2022/cpp/day_NN.cpp`.
+    CrossCheck {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        source: Option<PathBuf>,
+    },
+    /// Start a new day from its year's template and register it.
+    New {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    #[arg(long, requires = "year")]
+    day: Option<u8>,
+    #[arg(long, requires = "day")]
+    part: Option<Part>,
+    /// Read the puzzle input from this file instead, or from stdin for `-`.
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// `table`, `json` or `csv`.
+    #[arg(long, default_value = "table", conflicts_with = "part")]
+    format: Format,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let config = RunConfig {
+        input_root: cli.input_dir,
+        timeout: cli.timeout.map(Duration::from_secs_f64),
+        parallel: cli.parallel,
+    };
+    let result = match cli.command {
+        Command::Run(args) => run(args, &config),
+        Command::Verify { year } => verify(year, &config),
+        Command::Bench(command) => bench(command),
+        Command::CrossCheck { day, source } => {
+            cross_check(day, source, config.input_root.as_deref())
+        }
+        Command::New { year, day } => new_day(year, day),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: RunArgs, config: &RunConfig) -> Result<(), Box<dyn Error>> {
+    let years = select_years(args.year)?;
+    let Some(day) = args.day else {
+        if args.format == Format::Table {
+            let outcomes: Vec<DayOutcome> = years
+                .iter()
+                .flat_map(|year| run_year(year, config))
+                .collect();
+            if years.len() > 1 {
+                println!("All years:");
+                print_summary(&outcomes);
+            }
+        } else {
+            let outcomes: Vec<DayOutcome> = years
+                .iter()
+                .flat_map(|year| run_days(year, config))
+                .collect();
+            write_outcomes(io::stdout().lock(), args.format, &outcomes)?;
+        }
+        return Ok(());
+    };
+
+    let year = years[0];
+    let entry = year
+        .day(day)
+        .ok_or_else(|| format!("no solution registered for {} day {day}", year.year))?;
+    let input = match args.input {
+        Some(path) => load_input(path)?,
+        None => year.load_input(config.input_root.as_deref(), day)?,
+    };
+
+    match args.part {
+        Some(part) => {
+            let start = Instant::now();
+            let answer = entry.solution.solve_part(&input, part);
+            let result_string = format!("Day {day} part {part}: {answer}");
+            println!("{result_string:60}  (took: {:.2?})", start.elapsed());
+        }
+        None => {
+            let outcome = run_day(year.year, entry, input, config.timeout);
+            write_outcomes(io::stdout().lock(), args.format, &[outcome])?;
+        }
+    }
+    Ok(())
+}
+
+fn verify(year: Option<u16>, config: &RunConfig) -> Result<(), Box<dyn Error>> {
+    let mut failed = 0;
+    for year in select_years(year)? {
+        failed += verify_year(year, config)?
+            .iter()
+            .flatten()
+            .flat_map(|verdict| [&verdict.part_a, &verdict.part_b])
+            .filter(|verdict| matches!(verdict, Verdict::Fail { .. }))
+            .count();
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{failed} answers did not match").into()),
+    }
+}
+
+/// Where cargo puts its build output, following `CARGO_TARGET_DIR` like
+/// cargo does.
+fn target_dir() -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../target"))
+}
+
+fn select_years(year: Option<u16>) -> Result<Vec<&'static Year>, String> {
+    match year {
+        Some(year) => Ok(vec![find_year(year)?]),
+        None => Ok(YEARS.to_vec()),
+    }
+}
+
+fn find_year(year: u16) -> Result<&'static Year, String> {
+    YEARS
+        .iter()
+        .copied()
+        .find(|y| y.year == year)
+        .ok_or_else(|| format!("no solutions for year {year}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_checked_parsers_do_not_panic(input in "[#.O?<>^v@LR:=, 0-9A-Za-z\n-]{0,80}") {
+            for day in YEARS.iter().flat_map(|year| year.checked_days()) {
+                let _ = day.solution.try_parse(&input);
+            }
+        }
+    }
+}
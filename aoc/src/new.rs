@@ -0,0 +1,215 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use aoc_common::Year;
+
+use crate::find_year;
+
+const REGISTRY_START: &str = "aoc_common::days! {";
+const MAX_WIDTH: usize = 100;
+
+/// Starts a day from its year's `template.rs`: writes `day_NN.rs` and adds it
+/// to the `days!` registry, which is also what the runner and the benches are
+/// generated from. The template's test fails until the example has been saved
+/// as `test_inputs/test_NN.txt` and its answer filled in.
+pub fn new_day(year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {day}, only 1 to 25").into());
+    }
+    let year = find_year(year)?;
+    let root = crate_root(year);
+    let days_dir = root.join("src/days");
+    let module = format!("day_{day:02}");
+
+    let day_path = days_dir.join(format!("{module}.rs"));
+    if day_path.exists() {
+        return Err(format!("{} already exists", day_path.display()).into());
+    }
+    let registry_path = days_dir.join("mod.rs");
+    let registry = register(&fs::read_to_string(&registry_path)?, &module)?;
+    let template = fs::read_to_string(days_dir.join("template.rs"))?;
+    let source = instantiate(&template, day)?;
+
+    fs::write(&day_path, source)?;
+    fs::write(&registry_path, registry)?;
+    println!("Created {}", day_path.display());
+
+    let test_input = root.join(format!("test_inputs/test_{day:02}.txt"));
+    if !test_input.exists() {
+        println!("Save the example as {}", test_input.display());
+    }
+    Ok(())
+}
+
+/// Every year keeps its inputs in `inputs` at the root of its crate.
+fn crate_root(year: &Year) -> &Path {
+    Path::new(year.input_dir)
+        .parent()
+        .expect("input_dir is inside the crate")
+}
+
+fn instantiate(template: &str, day: u8) -> Result<String, String> {
+    if !template.contains("Day::new(0, ") {
+        return Err("template.rs has no `Day::new(0, ...)` to number".into());
+    }
+    Ok(template
+        .replace("Day::new(0, ", &format!("Day::new({day}, "))
+        .replace("_day_input(\"00\")", &format!("_day_input(\"{day:02}\")")))
+}
+
+/// Adds `module` to the `days!` invocation in `source`, keeping the days
+/// sorted and wrapped like rustfmt would, and declares it.
+fn register(source: &str, module: &str) -> Result<String, String> {
+    let start = source
+        .find(REGISTRY_START)
+        .ok_or("no `aoc_common::days!` registry in days/mod.rs")?
+        + REGISTRY_START.len();
+    let end = start + source[start..].find('}').ok_or("unterminated registry")?;
+
+    let mut modules: Vec<&str> = source[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .collect();
+    if modules.contains(&module) {
+        return Err(format!("{module} is already registered"));
+    }
+    modules.push(module);
+    modules.sort_unstable();
+
+    let mut list = String::from("\n");
+    let mut line = String::new();
+    for module in modules {
+        if !line.is_empty() && 4 + line.len() + 1 + module.len() + 1 > MAX_WIDTH {
+            list += &format!("    {line}\n");
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line += &format!("{module},");
+    }
+    list += &format!("    {line}\n");
+
+    let source = format!("{}{list}{}", &source[..start], &source[end..]);
+    Ok(declare(&source, module))
+}
+
+/// Adds `pub mod module;` among the other day modules, in order. Days are
+/// declared outside of `days!` so that rustfmt can find them.
+fn declare(source: &str, module: &str) -> String {
+    let declaration = format!("pub mod {module};\n");
+    let is_day = |line: &str| {
+        line.strip_prefix("pub mod day_")
+            .and_then(|rest| rest.strip_suffix(';'))
+            .is_some_and(|number| number.len() == 2 && number.bytes().all(|b| b.is_ascii_digit()))
+    };
+    let mut offset = 0;
+    let mut first = None;
+    let mut after = None;
+    for line in source.split_inclusive('\n') {
+        if is_day(line.trim_end()) {
+            first.get_or_insert(offset);
+            if line < declaration.as_str() {
+                after = Some(offset + line.len());
+            }
+        }
+        offset += line.len();
+    }
+    match after.or(first) {
+        Some(at) => format!("{}{declaration}{}", &source[..at], &source[at..]),
+        None => {
+            let at = source.find(REGISTRY_START).unwrap_or(source.len());
+            format!("{}{declaration}\n{}", &source[..at], &source[at..])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "pub use aoc_common::read_day_input;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+
+aoc_common::days! {
+    day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11,
+}
+
+pub static YEAR: Year = Year {};
+";
+
+    #[test]
+    fn test_register_wraps_like_rustfmt() {
+        let registry = register(REGISTRY, "day_12").unwrap();
+        let registry = register(&registry, "day_13").unwrap();
+        assert_eq!(
+            registry,
+            "pub use aoc_common::read_day_input;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+
+aoc_common::days! {
+    day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11, day_12,
+    day_13,
+}
+
+pub static YEAR: Year = Year {};
+"
+        );
+    }
+
+    #[test]
+    fn test_declare_keeps_days_in_order() {
+        assert_eq!(
+            declare("pub mod day_01;\npub mod day_03;\n", "day_02"),
+            "pub mod day_01;\npub mod day_02;\npub mod day_03;\n"
+        );
+        assert_eq!(
+            declare("pub mod day_03;\n", "day_02"),
+            "pub mod day_02;\npub mod day_03;\n"
+        );
+        assert_eq!(
+            declare("use a;\n\naoc_common::days! {}\n", "day_01"),
+            "use a;\n\npub mod day_01;\n\naoc_common::days! {}\n"
+        );
+    }
+
+    #[test]
+    fn test_register_rejects_existing_day() {
+        assert!(register(REGISTRY, "day_03").is_err());
+    }
+
+    #[test]
+    fn test_instantiate_numbers_the_day() {
+        let template = "pub const DAY: Day = Day::new(0, &Solver);\nread_test_day_input(\"00\")";
+        assert_eq!(
+            instantiate(template, 7).unwrap(),
+            "pub const DAY: Day = Day::new(7, &Solver);\nread_test_day_input(\"07\")"
+        );
+    }
+}
@@ -0,0 +1 @@
+aoc_common::bench_main!(&aoc_2021::days::YEAR);
@@ -0,0 +1,85 @@
+use aoc_common::{Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (usize, usize) {
+    let depths = parse(input_file).unwrap();
+    (part_a(&depths), part_b(&depths))
+}
+
+pub const DAY: Day = Day::checked(1, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<u32>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(depths: &Self::Parsed<'_>) -> usize {
+        part_a(depths)
+    }
+
+    fn part_b(depths: &Self::Parsed<'_>) -> usize {
+        part_b(depths)
+    }
+}
+
+fn part_a(depths: &[u32]) -> usize {
+    count_increases(depths, 1)
+}
+
+fn part_b(depths: &[u32]) -> usize {
+    count_increases(depths, 3)
+}
+
+/// Consecutive windows of `window` depths share all but their first and
+/// last depth, so comparing the sums comes down to comparing those two.
+fn count_increases(depths: &[u32], window: usize) -> usize {
+    depths
+        .iter()
+        .zip(depths.iter().skip(window))
+        .filter(|(first, last)| last > first)
+        .count()
+}
+
+fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    input.lines().map(|line| Ok(line.trim().parse()?)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("01")
+    }
+
+    #[rstest]
+    fn test_parse(example_input: String) {
+        assert_eq!(
+            parse(&example_input),
+            Ok(vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263])
+        );
+        assert!(parse("199\n2oo").is_err());
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 7);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 5);
+    }
+}
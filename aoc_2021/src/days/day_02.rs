@@ -0,0 +1,112 @@
+use aoc_common::{Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (i64, i64) {
+    let commands = parse(input_file).unwrap();
+    (part_a(&commands), part_b(&commands))
+}
+
+pub const DAY: Day = Day::checked(2, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Command>;
+    type AnswerA = i64;
+    type AnswerB = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(commands: &Self::Parsed<'_>) -> i64 {
+        part_a(commands)
+    }
+
+    fn part_b(commands: &Self::Parsed<'_>) -> i64 {
+        part_b(commands)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+}
+
+fn part_a(commands: &[Command]) -> i64 {
+    let (position, depth) =
+        commands
+            .iter()
+            .fold((0, 0), |(position, depth), command| match command {
+                Command::Forward(x) => (position + x, depth),
+                Command::Down(x) => (position, depth + x),
+                Command::Up(x) => (position, depth - x),
+            });
+    position * depth
+}
+
+fn part_b(commands: &[Command]) -> i64 {
+    let (position, depth, _) =
+        commands
+            .iter()
+            .fold((0, 0, 0), |(position, depth, aim), command| match command {
+                Command::Forward(x) => (position + x, depth + aim * x, aim),
+                Command::Down(x) => (position, depth, aim + x),
+                Command::Up(x) => (position, depth, aim - x),
+            });
+    position * depth
+}
+
+fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (direction, amount) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(format!("no amount in {line:?}")))?;
+            let amount = amount.trim().parse()?;
+            match direction {
+                "forward" => Ok(Command::Forward(amount)),
+                "down" => Ok(Command::Down(amount)),
+                "up" => Ok(Command::Up(amount)),
+                _ => Err(ParseError::new(format!("unknown command {direction:?}"))),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("02")
+    }
+
+    #[rstest]
+    #[case("forward 5", Ok(vec![Command::Forward(5)]))]
+    #[case("down 8\nup 3", Ok(vec![Command::Down(8), Command::Up(3)]))]
+    #[case("back 2", Err(ParseError::new("unknown command \"back\"")))]
+    #[case("forward", Err(ParseError::new("no amount in \"forward\"")))]
+    fn test_parse(#[case] input: &str, #[case] expected: Result<Vec<Command>, ParseError>) {
+        assert_eq!(parse(input), expected);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 150);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 900);
+    }
+}
@@ -0,0 +1,125 @@
+use aoc_common::{Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (u32, u32) {
+    let report = parse(input_file).unwrap();
+    (part_a(&report), part_b(&report))
+}
+
+pub const DAY: Day = Day::checked(3, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Report;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(report: &Self::Parsed<'_>) -> u32 {
+        part_a(report)
+    }
+
+    fn part_b(report: &Self::Parsed<'_>) -> u32 {
+        part_b(report)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Report {
+    numbers: Vec<u32>,
+    width: usize,
+}
+
+fn part_a(report: &Report) -> u32 {
+    let gamma = (0..report.width)
+        .filter(|&bit| most_common(&report.numbers, bit))
+        .fold(0, |gamma, bit| gamma | 1 << bit);
+    let epsilon = !gamma & ((1 << report.width) - 1);
+    gamma * epsilon
+}
+
+fn part_b(report: &Report) -> u32 {
+    let oxygen = rating(report, true);
+    let co2 = rating(report, false);
+    oxygen * co2
+}
+
+/// Whether `bit` is set in at least half of `numbers`.
+fn most_common(numbers: &[u32], bit: usize) -> bool {
+    let ones = numbers.iter().filter(|&&n| n >> bit & 1 == 1).count();
+    2 * ones >= numbers.len()
+}
+
+/// Keeps the numbers with the most (or least) common value of each bit,
+/// from the left, until one is left.
+fn rating(report: &Report, keep_most_common: bool) -> u32 {
+    let mut numbers = report.numbers.clone();
+    for bit in (0..report.width).rev() {
+        if numbers.len() == 1 {
+            break;
+        }
+        let keep = most_common(&numbers, bit) == keep_most_common;
+        numbers.retain(|&n| (n >> bit & 1 == 1) == keep);
+    }
+    numbers[0]
+}
+
+fn parse(input: &str) -> Result<Report, ParseError> {
+    let lines: Vec<&str> = input.lines().map(str::trim).collect();
+    let width = lines.first().map_or(0, |line| line.len());
+    if width == 0 || width > 31 {
+        return Err(ParseError::new("numbers must have 1 to 31 bits"));
+    }
+    let numbers = lines
+        .iter()
+        .map(|line| {
+            if line.len() != width {
+                return Err(ParseError::new("numbers differ in width"));
+            }
+            line.chars().try_fold(0, |number, c| match c {
+                '0' => Ok(number << 1),
+                '1' => Ok(number << 1 | 1),
+                _ => Err(ParseError::unexpected_char(c)),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Report { numbers, width })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("03")
+    }
+
+    #[rstest]
+    #[case("101\n010", Ok(Report { numbers: vec![5, 2], width: 3 }))]
+    #[case("101\n01", Err(ParseError::new("numbers differ in width")))]
+    #[case("102", Err(ParseError::unexpected_char('2')))]
+    #[case("", Err(ParseError::new("numbers must have 1 to 31 bits")))]
+    fn test_parse(#[case] input: &str, #[case] expected: Result<Report, ParseError>) {
+        assert_eq!(parse(input), expected);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 198);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 230);
+    }
+}
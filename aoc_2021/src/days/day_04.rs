@@ -0,0 +1,172 @@
+use aoc_common::{Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (u32, u32) {
+    let bingo = parse(input_file).unwrap();
+    (part_a(&bingo), part_b(&bingo))
+}
+
+pub const DAY: Day = Day::checked(4, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Bingo;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(bingo: &Self::Parsed<'_>) -> u32 {
+        part_a(bingo)
+    }
+
+    fn part_b(bingo: &Self::Parsed<'_>) -> u32 {
+        part_b(bingo)
+    }
+}
+
+const SIZE: usize = 5;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Bingo {
+    draws: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Board {
+    numbers: [[u32; SIZE]; SIZE],
+}
+
+impl Board {
+    /// The index of the draw that completes a row or column, if any does.
+    fn winning_draw(&self, draws: &[u32]) -> Option<usize> {
+        let drawn_at = |number: u32| draws.iter().position(|&draw| draw == number);
+        let mut turns = [[None; SIZE]; SIZE];
+        for (row, numbers) in self.numbers.iter().enumerate() {
+            for (col, &number) in numbers.iter().enumerate() {
+                turns[row][col] = drawn_at(number);
+            }
+        }
+        let rows = (0..SIZE).map(|row| (0..SIZE).map(|col| turns[row][col]).max());
+        let cols = (0..SIZE).map(|col| (0..SIZE).map(|row| turns[row][col]).max());
+        // A line is complete once its last number is drawn, `None` if never
+        rows.chain(cols).filter_map(|line| line.flatten()).min()
+    }
+
+    fn score(&self, draws: &[u32]) -> u32 {
+        let unmarked: u32 = self
+            .numbers
+            .iter()
+            .flatten()
+            .filter(|number| !draws.contains(number))
+            .sum();
+        unmarked * draws.last().unwrap()
+    }
+}
+
+fn part_a(bingo: &Bingo) -> u32 {
+    let (draw, board) = winners(bingo)
+        .min_by_key(|(draw, _)| *draw)
+        .expect("no board wins");
+    board.score(&bingo.draws[..=draw])
+}
+
+fn part_b(bingo: &Bingo) -> u32 {
+    let (draw, board) = winners(bingo)
+        .max_by_key(|(draw, _)| *draw)
+        .expect("no board wins");
+    board.score(&bingo.draws[..=draw])
+}
+
+/// The boards that win, with the index of the draw they win at.
+fn winners(bingo: &Bingo) -> impl Iterator<Item = (usize, &Board)> {
+    bingo
+        .boards
+        .iter()
+        .filter_map(|board| Some((board.winning_draw(&bingo.draws)?, board)))
+}
+
+fn parse(input: &str) -> Result<Bingo, ParseError> {
+    let mut sections = input.trim().split("\n\n");
+    let draws = sections
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .map(|draw| draw.trim().parse())
+        .collect::<Result<_, _>>()?;
+    let boards = sections.map(parse_board).collect::<Result<_, _>>()?;
+    Ok(Bingo { draws, boards })
+}
+
+fn parse_board(section: &str) -> Result<Board, ParseError> {
+    let mut numbers = [[0; SIZE]; SIZE];
+    let mut rows = section.lines();
+    for row in numbers.iter_mut() {
+        let line = rows
+            .next()
+            .ok_or(ParseError::new("board has too few rows"))?;
+        let mut cells = line.split_whitespace();
+        for cell in row.iter_mut() {
+            let number = cells.next().ok_or(ParseError::new("row is too short"))?;
+            *cell = number.parse()?;
+        }
+        if cells.next().is_some() {
+            return Err(ParseError::new("row is too long"));
+        }
+    }
+    if rows.next().is_some() {
+        return Err(ParseError::new("board has too many rows"));
+    }
+    Ok(Board { numbers })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("04")
+    }
+
+    #[rstest]
+    fn test_parse(example_input: String) {
+        let bingo = parse(&example_input).unwrap();
+        assert_eq!(bingo.draws.len(), 27);
+        assert_eq!(bingo.boards.len(), 3);
+        assert_eq!(bingo.boards[2].numbers[4], [2, 0, 12, 3, 7]);
+    }
+
+    #[rstest]
+    #[case("1,2\n\n1 2 3 4 5", "board has too few rows")]
+    #[case(
+        "1,2\n\n1 2 3 4\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5",
+        "row is too short"
+    )]
+    #[case(
+        "1,2\n\n1 2 3 4 5 6\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5",
+        "row is too long"
+    )]
+    fn test_parse_invalid(#[case] input: &str, #[case] message: &str) {
+        assert_eq!(parse(input), Err(ParseError::new(message)));
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 4512);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 1924);
+    }
+}
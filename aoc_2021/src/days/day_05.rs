@@ -0,0 +1,146 @@
+use aoc_common::{Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub fn solve_day(input_file: &str) -> (usize, usize) {
+    let vents = parse(input_file).unwrap();
+    (part_a(&vents), part_b(&vents))
+}
+
+pub const DAY: Day = Day::checked(5, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Vent>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(vents: &Self::Parsed<'_>) -> usize {
+        part_a(vents)
+    }
+
+    fn part_b(vents: &Self::Parsed<'_>) -> usize {
+        part_b(vents)
+    }
+}
+
+type Point = (i32, i32);
+
+/// A line of vents, horizontal, vertical or at exactly 45 degrees.
+#[derive(Debug, PartialEq, Eq)]
+struct Vent {
+    start: Point,
+    end: Point,
+}
+
+impl Vent {
+    fn is_diagonal(&self) -> bool {
+        self.start.0 != self.end.0 && self.start.1 != self.end.1
+    }
+
+    fn points(&self) -> impl Iterator<Item = Point> {
+        let step = (
+            (self.end.0 - self.start.0).signum(),
+            (self.end.1 - self.start.1).signum(),
+        );
+        let length = (self.end.0 - self.start.0)
+            .abs()
+            .max((self.end.1 - self.start.1).abs());
+        let start = self.start;
+        (0..=length).map(move |i| (start.0 + i * step.0, start.1 + i * step.1))
+    }
+}
+
+fn part_a(vents: &[Vent]) -> usize {
+    overlaps(vents.iter().filter(|vent| !vent.is_diagonal()))
+}
+
+fn part_b(vents: &[Vent]) -> usize {
+    overlaps(vents.iter())
+}
+
+/// The number of points covered by at least two vents.
+fn overlaps<'a>(vents: impl Iterator<Item = &'a Vent>) -> usize {
+    let mut covered = HashMap::<Point, u32>::new();
+    for point in vents.flat_map(Vent::points) {
+        *covered.entry(point).or_default() += 1;
+    }
+    covered.values().filter(|&&count| count > 1).count()
+}
+
+fn parse(input: &str) -> Result<Vec<Vent>, ParseError> {
+    input.lines().map(parse_vent).collect()
+}
+
+fn parse_vent(line: &str) -> Result<Vent, ParseError> {
+    let point = |s: &str| -> Result<Point, ParseError> {
+        let (x, y) = s
+            .trim()
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(format!("not a point: {s:?}")))?;
+        Ok((x.parse()?, y.parse()?))
+    };
+    let (start, end) = line
+        .split_once("->")
+        .ok_or_else(|| ParseError::new(format!("not a line: {line:?}")))?;
+    let vent = Vent {
+        start: point(start)?,
+        end: point(end)?,
+    };
+    let (dx, dy) = (vent.end.0 - vent.start.0, vent.end.1 - vent.start.1);
+    if vent.is_diagonal() && dx.abs() != dy.abs() {
+        return Err(ParseError::new(format!("not at 45 degrees: {line:?}")));
+    }
+    Ok(vent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("05")
+    }
+
+    #[rstest]
+    #[case("0,9 -> 5,9", Ok(Vent { start: (0, 9), end: (5, 9) }))]
+    #[case("8,0 -> 0,8", Ok(Vent { start: (8, 0), end: (0, 8) }))]
+    #[case(
+        "8,0 -> 0,7",
+        Err(ParseError::new("not at 45 degrees: \"8,0 -> 0,7\""))
+    )]
+    #[case("8,0 - 0,8", Err(ParseError::new("not a line: \"8,0 - 0,8\"")))]
+    fn test_parse_vent(#[case] line: &str, #[case] expected: Result<Vent, ParseError>) {
+        assert_eq!(parse_vent(line), expected);
+    }
+
+    #[rstest]
+    fn test_points() {
+        let vent = Vent {
+            start: (9, 7),
+            end: (7, 9),
+        };
+        assert_eq!(vent.points().collect::<Vec<_>>(), [(9, 7), (8, 8), (7, 9)]);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 5);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 12);
+    }
+}
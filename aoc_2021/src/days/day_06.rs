@@ -0,0 +1,98 @@
+use aoc_common::{Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (u64, u64) {
+    let school = parse(input_file).unwrap();
+    (part_a(&school), part_b(&school))
+}
+
+pub const DAY: Day = Day::checked(6, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = School;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(school: &Self::Parsed<'_>) -> u64 {
+        part_a(school)
+    }
+
+    fn part_b(school: &Self::Parsed<'_>) -> u64 {
+        part_b(school)
+    }
+}
+
+/// How many lanternfish there are with each number of days left on their timer.
+type School = [u64; 9];
+
+fn part_a(school: &School) -> u64 {
+    population_after(school, 80)
+}
+
+fn part_b(school: &School) -> u64 {
+    population_after(school, 256)
+}
+
+fn population_after(school: &School, days: usize) -> u64 {
+    let mut school = *school;
+    for _ in 0..days {
+        // Fish at 0 reset to 6 and each have a child starting at 8
+        school.rotate_left(1);
+        school[6] += school[8];
+    }
+    school.iter().sum()
+}
+
+fn parse(input: &str) -> Result<School, ParseError> {
+    let mut school = [0; 9];
+    for timer in input.trim().split(',') {
+        let timer: usize = timer.parse()?;
+        *school
+            .get_mut(timer)
+            .ok_or_else(|| ParseError::new(format!("timer {timer} is above 8")))? += 1;
+    }
+    Ok(school)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("06")
+    }
+
+    #[rstest]
+    fn test_parse_example(example_input: String) {
+        assert_eq!(parse(&example_input), Ok([0, 1, 1, 2, 1, 0, 0, 0, 0]));
+    }
+
+    #[rstest]
+    #[case("3,9", Err(ParseError::new("timer 9 is above 8")))]
+    fn test_parse(#[case] input: &str, #[case] expected: Result<School, ParseError>) {
+        assert_eq!(parse(input), expected);
+    }
+
+    #[rstest]
+    #[case(18, 26)]
+    #[case(80, 5934)]
+    #[case(256, 26984457539)]
+    fn test_population_after(example_input: String, #[case] days: usize, #[case] expected: u64) {
+        assert_eq!(
+            population_after(&parse(&example_input).unwrap(), days),
+            expected
+        );
+    }
+}
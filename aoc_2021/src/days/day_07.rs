@@ -0,0 +1,99 @@
+use aoc_common::{Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (u64, u64) {
+    let crabs = parse(input_file).unwrap();
+    (part_a(&crabs), part_b(&crabs))
+}
+
+pub const DAY: Day = Day::checked(7, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<i64>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(crabs: &Self::Parsed<'_>) -> u64 {
+        part_a(crabs)
+    }
+
+    fn part_b(crabs: &Self::Parsed<'_>) -> u64 {
+        part_b(crabs)
+    }
+}
+
+/// With a constant cost per step the median is optimal.
+fn part_a(crabs: &[i64]) -> u64 {
+    let mut sorted = crabs.to_vec();
+    sorted.sort_unstable();
+    let median = sorted[sorted.len() / 2];
+    crabs.iter().map(|crab| crab.abs_diff(median)).sum()
+}
+
+/// The cost of moving a crab grows with the square of the distance, so the
+/// optimum is within half a step of the mean.
+fn part_b(crabs: &[i64]) -> u64 {
+    let cost = |target: i64| -> u64 {
+        crabs
+            .iter()
+            .map(|crab| {
+                let distance = crab.abs_diff(target);
+                distance * (distance + 1) / 2
+            })
+            .sum()
+    };
+    let mean = crabs.iter().sum::<i64>().div_euclid(crabs.len() as i64);
+    cost(mean).min(cost(mean + 1))
+}
+
+fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    let crabs: Vec<i64> = input
+        .trim()
+        .split(',')
+        .map(|crab| crab.parse())
+        .collect::<Result<_, _>>()?;
+    if crabs.iter().any(|crab| !(0..=1_000_000).contains(crab)) {
+        return Err(ParseError::new("crab positions must be in 0..=1000000"));
+    }
+    Ok(crabs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("07")
+    }
+
+    #[rstest]
+    fn test_parse(example_input: String) {
+        assert_eq!(
+            parse(&example_input),
+            Ok(vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14])
+        );
+        assert!(parse("1,,2").is_err());
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 37);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 168);
+    }
+}
@@ -0,0 +1,189 @@
+use aoc_common::{Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (usize, u32) {
+    let entries = parse(input_file).unwrap();
+    (part_a(&entries), part_b(&entries))
+}
+
+pub const DAY: Day = Day::checked(8, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Entry>;
+    type AnswerA = usize;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(entries: &Self::Parsed<'_>) -> usize {
+        part_a(entries)
+    }
+
+    fn part_b(entries: &Self::Parsed<'_>) -> u32 {
+        part_b(entries)
+    }
+}
+
+/// The lit segments of a display, one bit per wire from `a` up.
+type Segments = u8;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Entry {
+    patterns: [Segments; 10],
+    outputs: [Segments; 4],
+}
+
+impl Entry {
+    /// The pattern of each digit, worked out from how many segments the
+    /// patterns light and which of them they share.
+    fn digits(&self) -> Option<[Segments; 10]> {
+        let with_len = |len: u32| {
+            self.patterns
+                .iter()
+                .copied()
+                .filter(move |p| p.count_ones() == len)
+        };
+        let only = |len: u32| {
+            let mut patterns = with_len(len);
+            patterns.next().filter(|_| patterns.next().is_none())
+        };
+        let contains = |pattern: Segments, other: Segments| pattern & other == other;
+
+        let one = only(2)?;
+        let four = only(4)?;
+        let seven = only(3)?;
+        let eight = only(7)?;
+        let nine = with_len(6).find(|&p| contains(p, four))?;
+        let zero = with_len(6).find(|&p| p != nine && contains(p, one))?;
+        let six = with_len(6).find(|&p| p != nine && p != zero)?;
+        let three = with_len(5).find(|&p| contains(p, one))?;
+        let five = with_len(5).find(|&p| p != three && contains(six, p))?;
+        let two = with_len(5).find(|&p| p != three && p != five)?;
+
+        let digits = [zero, one, two, three, four, five, six, seven, eight, nine];
+        let mut sorted = digits;
+        sorted.sort_unstable();
+        let mut patterns = self.patterns;
+        patterns.sort_unstable();
+        (sorted == patterns).then_some(digits)
+    }
+
+    fn value(&self) -> u32 {
+        let digits = self.digits().expect("checked while parsing");
+        self.outputs.iter().fold(0, |value, output| {
+            let digit = digits.iter().position(|d| d == output).unwrap();
+            value * 10 + digit as u32
+        })
+    }
+}
+
+fn part_a(entries: &[Entry]) -> usize {
+    // 1, 4, 7 and 8 are the only digits lighting 2, 4, 3 and 7 segments
+    entries
+        .iter()
+        .flat_map(|entry| entry.outputs)
+        .filter(|output| matches!(output.count_ones(), 2 | 3 | 4 | 7))
+        .count()
+}
+
+fn part_b(entries: &[Entry]) -> u32 {
+    entries.iter().map(Entry::value).sum()
+}
+
+fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+    input.lines().map(parse_entry).collect()
+}
+
+fn parse_entry(line: &str) -> Result<Entry, ParseError> {
+    let (patterns, outputs) = line
+        .split_once('|')
+        .ok_or_else(|| ParseError::new(format!("no `|` in {line:?}")))?;
+    let entry = Entry {
+        patterns: parse_segments(patterns)?,
+        outputs: parse_segments(outputs)?,
+    };
+    let digits = entry
+        .digits()
+        .ok_or_else(|| ParseError::new(format!("patterns are not the ten digits: {line:?}")))?;
+    if !entry.outputs.iter().all(|output| digits.contains(output)) {
+        return Err(ParseError::new(format!("output is not a digit: {line:?}")));
+    }
+    Ok(entry)
+}
+
+fn parse_segments<const N: usize>(s: &str) -> Result<[Segments; N], ParseError> {
+    let mut all = [0; N];
+    let mut words = s.split_whitespace();
+    for segments in all.iter_mut() {
+        let word = words
+            .next()
+            .ok_or_else(|| ParseError::new(format!("expected {N} patterns in {s:?}")))?;
+        for c in word.chars() {
+            if !('a'..='g').contains(&c) {
+                return Err(ParseError::unexpected_char(c));
+            }
+            *segments |= 1 << (c as u8 - b'a');
+        }
+    }
+    if words.next().is_some() {
+        return Err(ParseError::new(format!("expected {N} patterns in {s:?}")));
+    }
+    Ok(all)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("08")
+    }
+
+    const ENTRY: &str =
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+
+    #[rstest]
+    fn test_value() {
+        assert_eq!(parse_entry(ENTRY).unwrap().value(), 5353);
+    }
+
+    #[rstest]
+    #[case("ab | ab", ParseError::new("expected 10 patterns in \"ab \""))]
+    #[case(
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ax | ab ab ab ab",
+        ParseError::unexpected_char('x')
+    )]
+    #[case(
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb abc",
+        ParseError::new("output is not a digit: \"acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb abc\"")
+    )]
+    #[case(
+        "ab ab ab ab ab ab ab ab ab ab | ab ab ab ab",
+        ParseError::new(
+            "patterns are not the ten digits: \"ab ab ab ab ab ab ab ab ab ab | ab ab ab ab\""
+        )
+    )]
+    fn test_parse_entry_invalid(#[case] line: &str, #[case] expected: ParseError) {
+        assert_eq!(parse_entry(line), Err(expected));
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 26);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 61229);
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_common::{bfs, Day, Grid, ParseError, Pos, Solution};
+
+pub fn solve_day(input_file: &str) -> (u32, usize) {
+    let heights = parse(input_file).unwrap();
+    (part_a(&heights), part_b(&heights))
+}
+
+pub const DAY: Day = Day::checked(9, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Grid<u8>;
+    type AnswerA = u32;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(heights: &Self::Parsed<'_>) -> u32 {
+        part_a(heights)
+    }
+
+    fn part_b(heights: &Self::Parsed<'_>) -> usize {
+        part_b(heights)
+    }
+}
+
+/// Locations never part of a basin.
+const PEAK: u8 = 9;
+
+fn low_points(heights: &Grid<u8>) -> impl Iterator<Item = Pos> + '_ {
+    heights.positions().filter(|&pos| {
+        heights
+            .neighbours4(pos)
+            .all(|neighbour| heights[neighbour] > heights[pos])
+    })
+}
+
+fn part_a(heights: &Grid<u8>) -> u32 {
+    low_points(heights)
+        .map(|pos| u32::from(heights[pos]) + 1)
+        .sum()
+}
+
+fn part_b(heights: &Grid<u8>) -> usize {
+    let mut sizes: Vec<usize> = low_points(heights)
+        .map(|low| {
+            let basin = bfs(
+                [low],
+                |&pos| {
+                    heights
+                        .neighbours4(pos)
+                        .filter(|&neighbour| heights[neighbour] != PEAK)
+                        .collect::<Vec<_>>()
+                },
+                |_| false,
+            );
+            basin.costs().len()
+        })
+        .collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes.iter().take(3).product()
+}
+
+fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_with(input, |c| {
+        c.to_digit(10)
+            .map(|digit| digit as u8)
+            .ok_or(ParseError::unexpected_char(c))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("09")
+    }
+
+    #[rstest]
+    fn test_low_points(example_input: String) {
+        let heights = parse(&example_input).unwrap();
+        let mut lows: Vec<Pos> = low_points(&heights).collect();
+        lows.sort_unstable();
+        assert_eq!(lows, [(1, 0), (2, 2), (6, 4), (9, 0)]);
+    }
+
+    #[rstest]
+    #[case("12\n3", ParseError::new("row 1 has 1 cells, expected 2"))]
+    #[case("1a", ParseError::unexpected_char('a'))]
+    fn test_parse_invalid(#[case] input: &str, #[case] expected: ParseError) {
+        assert_eq!(parse(input), Err(expected));
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 15);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 1134);
+    }
+}
@@ -0,0 +1,148 @@
+use aoc_common::{Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (u64, u64) {
+    let lines = parse(input_file).unwrap();
+    (part_a(&lines), part_b(&lines))
+}
+
+pub const DAY: Day = Day::checked(10, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Line>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(lines: &Self::Parsed<'_>) -> u64 {
+        part_a(lines)
+    }
+
+    fn part_b(lines: &Self::Parsed<'_>) -> u64 {
+        part_b(lines)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Line {
+    /// The first closing character that doesn't match its opening one.
+    Corrupted(char),
+    /// The closing characters still missing, in order.
+    Incomplete(Vec<char>),
+}
+
+fn closing(open: char) -> Option<char> {
+    match open {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        '<' => Some('>'),
+        _ => None,
+    }
+}
+
+fn part_a(lines: &[Line]) -> u64 {
+    lines
+        .iter()
+        .map(|line| match line {
+            Line::Corrupted(')') => 3,
+            Line::Corrupted(']') => 57,
+            Line::Corrupted('}') => 1197,
+            Line::Corrupted(_) => 25137,
+            Line::Incomplete(_) => 0,
+        })
+        .sum()
+}
+
+fn part_b(lines: &[Line]) -> u64 {
+    let mut scores: Vec<u64> = lines
+        .iter()
+        .filter_map(|line| match line {
+            Line::Incomplete(missing) => Some(completion_score(missing)),
+            Line::Corrupted(_) => None,
+        })
+        .collect();
+    scores.sort_unstable();
+    *scores.get(scores.len() / 2).expect("no incomplete lines")
+}
+
+fn completion_score(missing: &[char]) -> u64 {
+    missing.iter().fold(0, |score, c| {
+        let points = match c {
+            ')' => 1,
+            ']' => 2,
+            '}' => 3,
+            _ => 4,
+        };
+        score * 5 + points
+    })
+}
+
+fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    input.lines().map(parse_line).collect()
+}
+
+fn parse_line(line: &str) -> Result<Line, ParseError> {
+    let mut expected = Vec::new();
+    for c in line.chars() {
+        if let Some(close) = closing(c) {
+            expected.push(close);
+        } else if matches!(c, ')' | ']' | '}' | '>') {
+            if expected.pop() != Some(c) {
+                return Ok(Line::Corrupted(c));
+            }
+        } else {
+            return Err(ParseError::unexpected_char(c));
+        }
+    }
+    expected.reverse();
+    Ok(Line::Incomplete(expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("10")
+    }
+
+    #[rstest]
+    #[case("{([(<{}[<>[]}>{[]{[(<()>", Ok(Line::Corrupted('}')))]
+    #[case("<([]){()}[{}])", Ok(Line::Corrupted(')')))]
+    #[case("[({(<(())[]>[[{[]{<()<>>", Ok(Line::Incomplete("}}]])})]".chars().collect())))]
+    #[case("(]x", Ok(Line::Corrupted(']')))]
+    #[case("(x]", Err(ParseError::unexpected_char('x')))]
+    fn test_parse_line(#[case] line: &str, #[case] expected: Result<Line, ParseError>) {
+        assert_eq!(parse_line(line), expected);
+    }
+
+    #[rstest]
+    #[case("}}]])})]", 288957)]
+    #[case("])}>", 294)]
+    fn test_completion_score(#[case] missing: &str, #[case] expected: u64) {
+        let missing: Vec<char> = missing.chars().collect();
+        assert_eq!(completion_score(&missing), expected);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 26397);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 288957);
+    }
+}
@@ -0,0 +1,112 @@
+use aoc_common::{Day, Grid, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (usize, usize) {
+    let octopuses = parse(input_file).unwrap();
+    (part_a(&octopuses), part_b(&octopuses))
+}
+
+pub const DAY: Day = Day::checked(11, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Grid<u8>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(octopuses: &Self::Parsed<'_>) -> usize {
+        part_a(octopuses)
+    }
+
+    fn part_b(octopuses: &Self::Parsed<'_>) -> usize {
+        part_b(octopuses)
+    }
+}
+
+/// Raises every energy level by one and lets the octopuses above 9 flash,
+/// each flash raising the ones around it. Returns how many flashed.
+fn step(octopuses: &mut Grid<u8>) -> usize {
+    let mut flashing = Vec::new();
+    for pos in octopuses.positions() {
+        octopuses[pos] += 1;
+        if octopuses[pos] == 10 {
+            flashing.push(pos);
+        }
+    }
+    let mut flashes = 0;
+    while let Some(pos) = flashing.pop() {
+        flashes += 1;
+        for neighbour in octopuses.neighbours8(pos).collect::<Vec<_>>() {
+            octopuses[neighbour] += 1;
+            if octopuses[neighbour] == 10 {
+                flashing.push(neighbour);
+            }
+        }
+    }
+    for pos in octopuses.positions() {
+        if octopuses[pos] > 9 {
+            octopuses[pos] = 0;
+        }
+    }
+    flashes
+}
+
+fn part_a(octopuses: &Grid<u8>) -> usize {
+    let mut octopuses = octopuses.clone();
+    (0..100).map(|_| step(&mut octopuses)).sum()
+}
+
+fn part_b(octopuses: &Grid<u8>) -> usize {
+    let mut octopuses = octopuses.clone();
+    let all = octopuses.cells().len();
+    (1..).find(|_| step(&mut octopuses) == all).unwrap()
+}
+
+fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_with(input, |c| {
+        c.to_digit(10)
+            .map(|digit| digit as u8)
+            .ok_or(ParseError::unexpected_char(c))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("11")
+    }
+
+    #[rstest]
+    fn test_step() {
+        let mut octopuses = parse("11111\n19991\n19191\n19991\n11111").unwrap();
+        assert_eq!(step(&mut octopuses), 9);
+        assert_eq!(
+            octopuses,
+            parse("34543\n40004\n50005\n40004\n34543").unwrap()
+        );
+        assert_eq!(step(&mut octopuses), 0);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 1656);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 195);
+    }
+}
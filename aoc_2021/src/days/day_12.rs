@@ -0,0 +1,161 @@
+use aoc_common::{Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub fn solve_day(input_file: &str) -> (usize, usize) {
+    let caves = parse(input_file).unwrap();
+    (part_a(&caves), part_b(&caves))
+}
+
+pub const DAY: Day = Day::checked(12, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Caves;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(caves: &Self::Parsed<'_>) -> usize {
+        part_a(caves)
+    }
+
+    fn part_b(caves: &Self::Parsed<'_>) -> usize {
+        part_b(caves)
+    }
+}
+
+/// The cave system, with every cave numbered and `start` and `end` first.
+#[derive(Debug, PartialEq, Eq)]
+struct Caves {
+    /// The caves connected to each cave, except `start` which can't be
+    /// revisited.
+    connections: Vec<Vec<usize>>,
+    small: Vec<bool>,
+}
+
+const START: usize = 0;
+const END: usize = 1;
+
+impl Caves {
+    /// Paths from `cave` to the end, where `visited` has a bit for every
+    /// small cave on the way so far.
+    fn paths(&self, cave: usize, visited: u64, may_revisit: bool) -> usize {
+        if cave == END {
+            return 1;
+        }
+        self.connections[cave]
+            .iter()
+            .map(|&next| {
+                let bit = 1 << next;
+                if !self.small[next] {
+                    self.paths(next, visited, may_revisit)
+                } else if visited & bit == 0 {
+                    self.paths(next, visited | bit, may_revisit)
+                } else if may_revisit {
+                    self.paths(next, visited, false)
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+}
+
+fn part_a(caves: &Caves) -> usize {
+    caves.paths(START, 1 << START, false)
+}
+
+fn part_b(caves: &Caves) -> usize {
+    caves.paths(START, 1 << START, true)
+}
+
+fn parse(input: &str) -> Result<Caves, ParseError> {
+    let mut ids = HashMap::from([("start", START), ("end", END)]);
+    let mut caves = Caves {
+        connections: vec![Vec::new(), Vec::new()],
+        small: vec![true, true],
+    };
+    for line in input.lines() {
+        let (a, b) = line
+            .split_once('-')
+            .ok_or_else(|| ParseError::new(format!("not a connection: {line:?}")))?;
+        let mut pair = [0; 2];
+        for (id, name) in pair.iter_mut().zip([a, b]) {
+            if let Some(c) = name.chars().find(|c| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::unexpected_char(c));
+            }
+            if name.is_empty() {
+                return Err(ParseError::new(format!("no cave name in {line:?}")));
+            }
+            let next = ids.len();
+            *id = *ids.entry(name).or_insert(next);
+            if *id == next {
+                caves.connections.push(Vec::new());
+                caves
+                    .small
+                    .push(name.chars().all(|c| c.is_ascii_lowercase()));
+            }
+        }
+        let [a, b] = pair;
+        if !caves.small[a] && !caves.small[b] {
+            return Err(ParseError::new(format!(
+                "two big caves next to each other make endless paths: {line:?}"
+            )));
+        }
+        for (from, to) in [(a, b), (b, a)] {
+            if to != START {
+                caves.connections[from].push(to);
+            }
+        }
+    }
+    if ids.len() > u64::BITS as usize {
+        return Err(ParseError::new("too many caves"));
+    }
+    Ok(caves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("12")
+    }
+
+    const LARGER: &str =
+        "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc";
+
+    #[rstest]
+    #[case(
+        "A-B",
+        ParseError::new("two big caves next to each other make endless paths: \"A-B\"")
+    )]
+    #[case("start-a1", ParseError::unexpected_char('1'))]
+    #[case("start", ParseError::new("not a connection: \"start\""))]
+    fn test_parse_invalid(#[case] input: &str, #[case] expected: ParseError) {
+        assert_eq!(parse(input), Err(expected));
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 10);
+        assert_eq!(part_a(&parse(LARGER).unwrap()), 19);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 36);
+        assert_eq!(part_b(&parse(LARGER).unwrap()), 103);
+    }
+}
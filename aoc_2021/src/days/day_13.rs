@@ -0,0 +1,189 @@
+use aoc_common::{Day, ParseError, Solution};
+use std::collections::BTreeSet;
+
+pub fn solve_day(input_file: &str) -> (usize, String) {
+    let manual = parse(input_file).unwrap();
+    (part_a(&manual), part_b(&manual))
+}
+
+pub const DAY: Day = Day::checked(13, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Manual;
+    type AnswerA = usize;
+    type AnswerB = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(manual: &Self::Parsed<'_>) -> usize {
+        part_a(manual)
+    }
+
+    fn part_b(manual: &Self::Parsed<'_>) -> String {
+        part_b(manual)
+    }
+}
+
+type Dot = (u32, u32);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Fold {
+    /// Folds the right half over to the left, along `x`.
+    Left(u32),
+    /// Folds the bottom half up, along `y`.
+    Up(u32),
+}
+
+impl Fold {
+    /// Where `dot` ends up, if it isn't so far past the line that it would
+    /// land beyond the edge of the paper.
+    fn try_apply(self, (x, y): Dot) -> Option<Dot> {
+        let mirror = |v: u32, line: u32| match v.checked_sub(line) {
+            Some(past) if past > 0 => line.checked_sub(past),
+            _ => Some(v),
+        };
+        match self {
+            Fold::Left(line) => Some((mirror(x, line)?, y)),
+            Fold::Up(line) => Some((x, mirror(y, line)?)),
+        }
+    }
+
+    fn apply(self, dot: Dot) -> Dot {
+        self.try_apply(dot).expect("checked while parsing")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Manual {
+    dots: BTreeSet<Dot>,
+    folds: Vec<Fold>,
+}
+
+fn fold(dots: &BTreeSet<Dot>, fold: Fold) -> BTreeSet<Dot> {
+    dots.iter().map(|&dot| fold.apply(dot)).collect()
+}
+
+fn part_a(manual: &Manual) -> usize {
+    match manual.folds.first() {
+        Some(&first) => fold(&manual.dots, first).len(),
+        None => manual.dots.len(),
+    }
+}
+
+/// The dots after every fold, as rows of `#` and `.`.
+fn part_b(manual: &Manual) -> String {
+    let dots = manual
+        .folds
+        .iter()
+        .fold(manual.dots.clone(), |dots, &f| fold(&dots, f));
+    let width = dots.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+    let height = dots.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| if dots.contains(&(x, y)) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse(input: &str) -> Result<Manual, ParseError> {
+    let (dots, folds) = input.trim().split_once("\n\n").ok_or(ParseError::new(
+        "no blank line between the dots and the folds",
+    ))?;
+    let dots = dots
+        .lines()
+        .map(|line| {
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::new(format!("not a dot: {line:?}")))?;
+            Ok((x.parse()?, y.parse()?))
+        })
+        .collect::<Result<BTreeSet<Dot>, ParseError>>()?;
+    let folds: Vec<Fold> = folds.lines().map(parse_fold).collect::<Result<_, _>>()?;
+
+    let mut folded = dots.clone();
+    for &f in &folds {
+        folded = folded
+            .iter()
+            .map(|&dot| f.try_apply(dot))
+            .collect::<Option<_>>()
+            .ok_or_else(|| ParseError::new(format!("{f:?} puts a dot off the paper")))?;
+    }
+    Ok(Manual { dots, folds })
+}
+
+fn parse_fold(line: &str) -> Result<Fold, ParseError> {
+    let not_a_fold = || ParseError::new(format!("not a fold: {line:?}"));
+    let (axis, at) = line
+        .strip_prefix("fold along ")
+        .and_then(|fold| fold.split_once('='))
+        .ok_or_else(not_a_fold)?;
+    let at = at.parse()?;
+    match axis {
+        "x" => Ok(Fold::Left(at)),
+        "y" => Ok(Fold::Up(at)),
+        _ => Err(not_a_fold()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("13")
+    }
+
+    #[rstest]
+    #[case("fold along y=7", Ok(Fold::Up(7)))]
+    #[case("fold along x=5", Ok(Fold::Left(5)))]
+    #[case(
+        "fold along z=5",
+        Err(ParseError::new("not a fold: \"fold along z=5\""))
+    )]
+    fn test_parse_fold(#[case] line: &str, #[case] expected: Result<Fold, ParseError>) {
+        assert_eq!(parse_fold(line), expected);
+    }
+
+    #[rstest]
+    #[case(Fold::Up(7), (3, 10), (3, 4))]
+    #[case(Fold::Up(7), (3, 4), (3, 4))]
+    #[case(Fold::Left(5), (9, 0), (1, 0))]
+    fn test_apply(#[case] fold: Fold, #[case] dot: Dot, #[case] expected: Dot) {
+        assert_eq!(fold.apply(dot), expected);
+    }
+
+    #[rstest]
+    fn test_parse_dot_off_the_paper() {
+        assert_eq!(
+            parse("0,0\n5,0\n\nfold along x=2"),
+            Err(ParseError::new("Left(2) puts a dot off the paper"))
+        );
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 17);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(
+            part_b(&parse(&example_input).unwrap()),
+            "#####\n#...#\n#...#\n#...#\n#####"
+        );
+    }
+}
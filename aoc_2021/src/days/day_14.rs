@@ -0,0 +1,159 @@
+use aoc_common::{Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub fn solve_day(input_file: &str) -> (u64, u64) {
+    let polymer = parse(input_file).unwrap();
+    (part_a(&polymer), part_b(&polymer))
+}
+
+pub const DAY: Day = Day::checked(14, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Polymer;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(polymer: &Self::Parsed<'_>) -> u64 {
+        part_a(polymer)
+    }
+
+    fn part_b(polymer: &Self::Parsed<'_>) -> u64 {
+        part_b(polymer)
+    }
+}
+
+type Pair = (char, char);
+
+#[derive(Debug, PartialEq, Eq)]
+struct Polymer {
+    template: Vec<char>,
+    rules: HashMap<Pair, char>,
+}
+
+impl Polymer {
+    /// The most common element's count minus the least common one's, after
+    /// `steps` rounds of insertions.
+    ///
+    /// The polymer doubles in length every step, so only how often each pair
+    /// appears is kept track of.
+    fn spread_after(&self, steps: usize) -> u64 {
+        let mut pairs = HashMap::<Pair, u64>::new();
+        for pair in self.template.windows(2) {
+            *pairs.entry((pair[0], pair[1])).or_default() += 1;
+        }
+        for _ in 0..steps {
+            let mut next = HashMap::new();
+            for (&(a, b), &count) in &pairs {
+                match self.rules.get(&(a, b)) {
+                    Some(&c) => {
+                        *next.entry((a, c)).or_default() += count;
+                        *next.entry((c, b)).or_default() += count;
+                    }
+                    None => *next.entry((a, b)).or_default() += count,
+                }
+            }
+            pairs = next;
+        }
+
+        // Every element is the first of a pair, except the very last one
+        let mut elements = HashMap::<char, u64>::new();
+        for (&(a, _), &count) in &pairs {
+            *elements.entry(a).or_default() += count;
+        }
+        if let Some(&last) = self.template.last() {
+            *elements.entry(last).or_default() += 1;
+        }
+        let most = elements.values().max().unwrap_or(&0);
+        let least = elements.values().min().unwrap_or(&0);
+        most - least
+    }
+}
+
+fn part_a(polymer: &Polymer) -> u64 {
+    polymer.spread_after(10)
+}
+
+fn part_b(polymer: &Polymer) -> u64 {
+    polymer.spread_after(40)
+}
+
+fn element(c: char) -> Result<char, ParseError> {
+    if c.is_ascii_uppercase() {
+        Ok(c)
+    } else {
+        Err(ParseError::unexpected_char(c))
+    }
+}
+
+fn parse(input: &str) -> Result<Polymer, ParseError> {
+    let (template, rules) = input
+        .trim()
+        .split_once("\n\n")
+        .ok_or(ParseError::new("no blank line after the template"))?;
+    let template = template.chars().map(element).collect::<Result<_, _>>()?;
+    let rules = rules.lines().map(parse_rule).collect::<Result<_, _>>()?;
+    Ok(Polymer { template, rules })
+}
+
+fn parse_rule(line: &str) -> Result<(Pair, char), ParseError> {
+    let not_a_rule = || ParseError::new(format!("not a rule: {line:?}"));
+    let (pair, insert) = line.split_once(" -> ").ok_or_else(not_a_rule)?;
+    let elements = pair
+        .chars()
+        .chain(insert.chars())
+        .map(element)
+        .collect::<Result<Vec<_>, _>>()?;
+    match elements[..] {
+        [a, b, c] => Ok(((a, b), c)),
+        _ => Err(not_a_rule()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("14")
+    }
+
+    #[rstest]
+    #[case("CH -> B", Ok((('C', 'H'), 'B')))]
+    #[case("CH -> b", Err(ParseError::unexpected_char('b')))]
+    #[case("CHH -> B", Err(ParseError::new("not a rule: \"CHH -> B\"")))]
+    fn test_parse_rule(#[case] line: &str, #[case] expected: Result<(Pair, char), ParseError>) {
+        assert_eq!(parse_rule(line), expected);
+    }
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(1, 1)]
+    #[case(2, 5)]
+    fn test_spread_after(example_input: String, #[case] steps: usize, #[case] expected: u64) {
+        // NNCB, then NCNBCHB, then NBCCNBBBCBHCB
+        assert_eq!(parse(&example_input).unwrap().spread_after(steps), expected);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 1588);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 2188189693529);
+    }
+}
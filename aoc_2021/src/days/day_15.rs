@@ -0,0 +1,110 @@
+use aoc_common::{dijkstra, Day, Grid, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (u32, u32) {
+    let risks = parse(input_file).unwrap();
+    (part_a(&risks), part_b(&risks))
+}
+
+pub const DAY: Day = Day::checked(15, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Grid<u8>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(risks: &Self::Parsed<'_>) -> u32 {
+        part_a(risks)
+    }
+
+    fn part_b(risks: &Self::Parsed<'_>) -> u32 {
+        part_b(risks)
+    }
+}
+
+/// The lowest total risk from the top left to the bottom right, not counting
+/// the risk of the starting position.
+fn lowest_risk(risks: &Grid<u8>) -> u32 {
+    let goal = (risks.width() - 1, risks.height() - 1);
+    let search = dijkstra(
+        [(0, 0)],
+        |&pos| {
+            risks
+                .neighbours4(pos)
+                .map(|next| (next, u32::from(risks[next])))
+                .collect::<Vec<_>>()
+        },
+        |&pos| pos == goal,
+    );
+    search.goal_cost().unwrap()
+}
+
+/// The full map: the tile repeated five times in both directions, each
+/// repeat to the right or down one riskier, wrapping from 9 back to 1.
+fn full_map(tile: &Grid<u8>) -> Grid<u8> {
+    let (width, height) = (tile.width(), tile.height());
+    Grid::from_fn(width * 5, height * 5, |(x, y)| {
+        let risk = tile[(x % width, y % height)] as usize + x / width + y / height;
+        ((risk - 1) % 9 + 1) as u8
+    })
+}
+
+fn part_a(risks: &Grid<u8>) -> u32 {
+    lowest_risk(risks)
+}
+
+fn part_b(risks: &Grid<u8>) -> u32 {
+    lowest_risk(&full_map(risks))
+}
+
+fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_with(input, |c| match c.to_digit(10) {
+        Some(risk @ 1..=9) => Ok(risk as u8),
+        _ => Err(ParseError::unexpected_char(c)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("15")
+    }
+
+    #[rstest]
+    fn test_full_map() {
+        let full = full_map(&parse("8").unwrap());
+        assert_eq!(full.row(0), [8, 9, 1, 2, 3]);
+        assert_eq!(full.row(4), [3, 4, 5, 6, 7]);
+    }
+
+    #[rstest]
+    #[case("12\n30", ParseError::unexpected_char('0'))]
+    #[case("", ParseError::new("empty grid"))]
+    fn test_parse_invalid(#[case] input: &str, #[case] expected: ParseError) {
+        assert_eq!(parse(input), Err(expected));
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 40);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 315);
+    }
+}
@@ -0,0 +1,262 @@
+use aoc_common::{Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (u64, u64) {
+    let packet = parse(input_file).unwrap();
+    (part_a(&packet), part_b(&packet))
+}
+
+pub const DAY: Day = Day::checked(16, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Packet;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(packet: &Self::Parsed<'_>) -> u64 {
+        part_a(packet)
+    }
+
+    fn part_b(packet: &Self::Parsed<'_>) -> u64 {
+        part_b(packet)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Packet {
+    version: u8,
+    contents: Contents,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Contents {
+    Literal(u64),
+    Operator(Operation, Vec<Packet>),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Operation {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+impl Packet {
+    fn version_sum(&self) -> u64 {
+        let nested = match &self.contents {
+            Contents::Literal(_) => 0,
+            Contents::Operator(_, packets) => packets.iter().map(Packet::version_sum).sum(),
+        };
+        u64::from(self.version) + nested
+    }
+
+    fn value(&self) -> u64 {
+        let (operation, packets) = match &self.contents {
+            Contents::Literal(value) => return *value,
+            Contents::Operator(operation, packets) => (operation, packets),
+        };
+        let mut values = packets.iter().map(Packet::value);
+        match operation {
+            Operation::Sum => values.sum(),
+            Operation::Product => values.product(),
+            Operation::Minimum => values.min().unwrap(),
+            Operation::Maximum => values.max().unwrap(),
+            Operation::GreaterThan | Operation::LessThan | Operation::EqualTo => {
+                let (a, b) = (values.next().unwrap(), values.next().unwrap());
+                let holds = match operation {
+                    Operation::GreaterThan => a > b,
+                    Operation::LessThan => a < b,
+                    _ => a == b,
+                };
+                u64::from(holds)
+            }
+        }
+    }
+}
+
+fn part_a(packet: &Packet) -> u64 {
+    packet.version_sum()
+}
+
+fn part_b(packet: &Packet) -> u64 {
+    packet.value()
+}
+
+/// Reads the transmission most significant bit first.
+struct Bits<'a> {
+    bits: &'a [bool],
+    read: usize,
+}
+
+impl Bits<'_> {
+    fn take(&mut self, count: usize) -> Result<u64, ParseError> {
+        let bits = self
+            .bits
+            .get(self.read..self.read + count)
+            .ok_or(ParseError::new("transmission ends mid packet"))?;
+        self.read += count;
+        Ok(bits
+            .iter()
+            .fold(0, |value, &bit| value << 1 | u64::from(bit)))
+    }
+
+    fn packet(&mut self) -> Result<Packet, ParseError> {
+        let version = self.take(3)? as u8;
+        let operation = match self.take(3)? {
+            0 => Operation::Sum,
+            1 => Operation::Product,
+            2 => Operation::Minimum,
+            3 => Operation::Maximum,
+            4 => {
+                return Ok(Packet {
+                    version,
+                    contents: Contents::Literal(self.literal()?),
+                })
+            }
+            5 => Operation::GreaterThan,
+            6 => Operation::LessThan,
+            _ => Operation::EqualTo,
+        };
+
+        let mut packets = Vec::new();
+        if self.take(1)? == 0 {
+            let length = self.take(15)? as usize;
+            let end = self.read + length;
+            while self.read < end {
+                packets.push(self.packet()?);
+            }
+            if self.read != end {
+                return Err(ParseError::new("sub-packets overrun their length"));
+            }
+        } else {
+            for _ in 0..self.take(11)? {
+                packets.push(self.packet()?);
+            }
+        }
+
+        let valid = match operation {
+            Operation::GreaterThan | Operation::LessThan | Operation::EqualTo => packets.len() == 2,
+            _ => !packets.is_empty(),
+        };
+        if !valid {
+            return Err(ParseError::new(format!(
+                "{operation:?} can't take {} sub-packets",
+                packets.len()
+            )));
+        }
+        Ok(Packet {
+            version,
+            contents: Contents::Operator(operation, packets),
+        })
+    }
+
+    fn literal(&mut self) -> Result<u64, ParseError> {
+        let mut value = 0u64;
+        loop {
+            let more = self.take(1)? == 1;
+            if value.leading_zeros() < 4 {
+                return Err(ParseError::new("literal doesn't fit in 64 bits"));
+            }
+            value = value << 4 | self.take(4)?;
+            if !more {
+                return Ok(value);
+            }
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Packet, ParseError> {
+    let mut bits = Vec::new();
+    for c in input.trim().chars() {
+        let digit = c.to_digit(16).ok_or(ParseError::unexpected_char(c))?;
+        bits.extend((0..4).rev().map(|shift| digit >> shift & 1 == 1));
+    }
+    let mut bits = Bits {
+        bits: &bits,
+        read: 0,
+    };
+    let packet = bits.packet()?;
+    // Anything left over is padding to a whole number of hex digits
+    if bits.bits[bits.read..].contains(&true) {
+        return Err(ParseError::new("trailing bits after the outermost packet"));
+    }
+    Ok(packet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("16")
+    }
+
+    #[rstest]
+    fn test_parse_literal() {
+        assert_eq!(
+            parse("D2FE28"),
+            Ok(Packet {
+                version: 6,
+                contents: Contents::Literal(2021)
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("D2FE2", ParseError::new("transmission ends mid packet"))]
+    #[case("D2FE28F", ParseError::new("trailing bits after the outermost packet"))]
+    #[case("D2FG28", ParseError::unexpected_char('G'))]
+    // A less than packet with a single literal in it
+    #[case("DA004408", ParseError::new("LessThan can't take 1 sub-packets"))]
+    fn test_parse_invalid(#[case] input: &str, #[case] expected: ParseError) {
+        assert_eq!(parse(input), Err(expected));
+    }
+
+    #[rstest]
+    #[case("8A004A801A8002F478", 16)]
+    #[case("620080001611562C8802118E34", 12)]
+    #[case("C0015000016115A2E0802F182340", 23)]
+    #[case("A0016C880162017C3686B18A3D4780", 31)]
+    fn test_version_sum(#[case] input: &str, #[case] expected: u64) {
+        assert_eq!(parse(input).unwrap().version_sum(), expected);
+    }
+
+    #[rstest]
+    #[case("C200B40A82", 3)]
+    #[case("04005AC33890", 54)]
+    #[case("880086C3E88112", 7)]
+    #[case("CE00C43D881120", 9)]
+    #[case("D8005AC2A8F0", 1)]
+    #[case("F600BC2D8F", 0)]
+    #[case("9C005AC2F8F0", 0)]
+    #[case("9C0141080250320F1802104A08", 1)]
+    fn test_value(#[case] input: &str, #[case] expected: u64) {
+        assert_eq!(parse(input).unwrap().value(), expected);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 31);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 54);
+    }
+}
@@ -0,0 +1,140 @@
+use aoc_common::{Day, ParseError, Solution};
+use std::ops::RangeInclusive;
+
+pub fn solve_day(input_file: &str) -> (i64, usize) {
+    let target = parse(input_file).unwrap();
+    (part_a(&target), part_b(&target))
+}
+
+pub const DAY: Day = Day::checked(17, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Target;
+    type AnswerA = i64;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(target: &Self::Parsed<'_>) -> i64 {
+        part_a(target)
+    }
+
+    fn part_b(target: &Self::Parsed<'_>) -> usize {
+        part_b(target)
+    }
+}
+
+/// The target area, which is always ahead of and below the probe.
+#[derive(Debug, PartialEq, Eq)]
+struct Target {
+    x: RangeInclusive<i64>,
+    y: RangeInclusive<i64>,
+}
+
+impl Target {
+    fn is_hit_by(&self, (mut dx, mut dy): (i64, i64)) -> bool {
+        let (mut x, mut y) = (0, 0);
+        while x <= *self.x.end() && y >= *self.y.start() {
+            if self.x.contains(&x) && self.y.contains(&y) {
+                return true;
+            }
+            x += dx;
+            y += dy;
+            dx -= dx.signum();
+            dy -= 1;
+        }
+        false
+    }
+}
+
+/// A probe thrown up at `dy` comes back down through 0 at `-dy - 1`, so the
+/// fastest it can go without overshooting is straight to the bottom of the
+/// target in one step from there.
+fn part_a(target: &Target) -> i64 {
+    let dy = -target.y.start() - 1;
+    dy * (dy + 1) / 2
+}
+
+fn part_b(target: &Target) -> usize {
+    let (bottom, right) = (*target.y.start(), *target.x.end());
+    (1..=right)
+        .flat_map(|dx| (bottom..-bottom).map(move |dy| (dx, dy)))
+        .filter(|&velocity| target.is_hit_by(velocity))
+        .count()
+}
+
+fn parse(input: &str) -> Result<Target, ParseError> {
+    let (x, y) = input
+        .trim()
+        .strip_prefix("target area: x=")
+        .and_then(|area| area.split_once(", y="))
+        .ok_or(ParseError::new("no target area"))?;
+    let range = |range: &str| -> Result<RangeInclusive<i64>, ParseError> {
+        let (start, end) = range
+            .split_once("..")
+            .ok_or_else(|| ParseError::new(format!("not a range: {range:?}")))?;
+        Ok(start.parse()?..=end.parse()?)
+    };
+    let target = Target {
+        x: range(x)?,
+        y: range(y)?,
+    };
+    if target.x.is_empty() || *target.x.start() <= 0 {
+        return Err(ParseError::new("target must be ahead of the probe"));
+    }
+    if target.y.is_empty() || *target.y.end() >= 0 {
+        return Err(ParseError::new("target must be below the probe"));
+    }
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("17")
+    }
+
+    #[rstest]
+    #[case(
+        "target area: x=-30..-20, y=-10..-5",
+        "target must be ahead of the probe"
+    )]
+    #[case("target area: x=20..30, y=5..10", "target must be below the probe")]
+    #[case("target area: x=20..30, y=-5..-10", "target must be below the probe")]
+    #[case("target area: x=20..30", "no target area")]
+    fn test_parse_invalid(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(parse(input), Err(ParseError::new(expected)));
+    }
+
+    #[rstest]
+    #[case((7, 2), true)]
+    #[case((6, 3), true)]
+    #[case((9, 0), true)]
+    #[case((17, -4), false)]
+    fn test_is_hit_by(example_input: String, #[case] velocity: (i64, i64), #[case] expected: bool) {
+        assert_eq!(parse(&example_input).unwrap().is_hit_by(velocity), expected);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 45);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 112);
+    }
+}
@@ -0,0 +1,242 @@
+use aoc_common::{Day, ParseError, Solution};
+use std::iter::Peekable;
+use std::str::Chars;
+
+pub fn solve_day(input_file: &str) -> (u32, u32) {
+    let numbers = parse(input_file).unwrap();
+    (part_a(&numbers), part_b(&numbers))
+}
+
+pub const DAY: Day = Day::checked(18, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Number>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(numbers: &Self::Parsed<'_>) -> u32 {
+        part_a(numbers)
+    }
+
+    fn part_b(numbers: &Self::Parsed<'_>) -> u32 {
+        part_b(numbers)
+    }
+}
+
+/// A snailfish number as its regular numbers from left to right, each with
+/// how many pairs it is nested in.
+///
+/// Exploding and splitting only ever look at neighbouring regular numbers, so
+/// there is no need to keep the tree around.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Number(Vec<Regular>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Regular {
+    value: u32,
+    depth: u8,
+}
+
+impl Number {
+    fn add(&self, other: &Number) -> Number {
+        let regulars = self.0.iter().chain(&other.0);
+        let mut sum = Number(
+            regulars
+                .map(|&Regular { value, depth }| Regular {
+                    value,
+                    depth: depth + 1,
+                })
+                .collect(),
+        );
+        while sum.explode() || sum.split() {}
+        sum
+    }
+
+    /// Explodes the leftmost pair nested inside four others, if there is one.
+    fn explode(&mut self) -> bool {
+        // Reduced numbers are never nested deeper than four, so after adding
+        // two of them the pairs that explode always hold two regular numbers
+        let Some(i) = self.0.iter().position(|regular| regular.depth > 4) else {
+            return false;
+        };
+        let (left, right) = (self.0[i].value, self.0[i + 1].value);
+        if i > 0 {
+            self.0[i - 1].value += left;
+        }
+        if let Some(next) = self.0.get_mut(i + 2) {
+            next.value += right;
+        }
+        self.0.splice(i..i + 2, [Regular { value: 0, depth: 4 }]);
+        true
+    }
+
+    /// Splits the leftmost regular number of 10 or more, if there is one.
+    fn split(&mut self) -> bool {
+        let Some(i) = self.0.iter().position(|regular| regular.value >= 10) else {
+            return false;
+        };
+        let Regular { value, depth } = self.0[i];
+        let halves = [value / 2, value.div_ceil(2)].map(|value| Regular {
+            value,
+            depth: depth + 1,
+        });
+        self.0.splice(i..=i, halves);
+        true
+    }
+
+    fn magnitude(&self) -> u32 {
+        // Combine pairs as soon as both of their halves are on the stack
+        let mut stack: Vec<Regular> = Vec::new();
+        for &regular in &self.0 {
+            stack.push(regular);
+            while let [.., left, right] = stack[..] {
+                if left.depth != right.depth {
+                    break;
+                }
+                stack.truncate(stack.len() - 2);
+                stack.push(Regular {
+                    value: 3 * left.value + 2 * right.value,
+                    depth: left.depth - 1,
+                });
+            }
+        }
+        stack[0].value
+    }
+}
+
+fn part_a(numbers: &[Number]) -> u32 {
+    let (first, rest) = numbers.split_first().unwrap();
+    rest.iter()
+        .fold(first.clone(), |sum, number| sum.add(number))
+        .magnitude()
+}
+
+fn part_b(numbers: &[Number]) -> u32 {
+    let mut largest = 0;
+    for (i, a) in numbers.iter().enumerate() {
+        for (j, b) in numbers.iter().enumerate() {
+            if i != j {
+                largest = largest.max(a.add(b).magnitude());
+            }
+        }
+    }
+    largest
+}
+
+fn expect(chars: &mut Peekable<Chars<'_>>, expected: char) -> Result<(), ParseError> {
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        Some(c) => Err(ParseError::unexpected_char(c)),
+        None => Err(ParseError::new(format!("expected {expected:?}"))),
+    }
+}
+
+/// Adds the element at `depth` to `regulars`, rejecting anything that isn't
+/// already reduced.
+fn parse_element(
+    chars: &mut Peekable<Chars<'_>>,
+    depth: u8,
+    regulars: &mut Vec<Regular>,
+) -> Result<(), ParseError> {
+    if chars.peek() != Some(&'[') {
+        let digit = chars.next().ok_or(ParseError::new("expected a number"))?;
+        let value = digit
+            .to_digit(10)
+            .ok_or(ParseError::unexpected_char(digit))?;
+        regulars.push(Regular { value, depth });
+        return Ok(());
+    }
+    if depth == 4 {
+        return Err(ParseError::new("pair nested inside four others"));
+    }
+    expect(chars, '[')?;
+    parse_element(chars, depth + 1, regulars)?;
+    expect(chars, ',')?;
+    parse_element(chars, depth + 1, regulars)?;
+    expect(chars, ']')
+}
+
+fn parse_number(line: &str) -> Result<Number, ParseError> {
+    if !line.starts_with('[') {
+        return Err(ParseError::new(format!("not a pair: {line:?}")));
+    }
+    let mut chars = line.chars().peekable();
+    let mut regulars = Vec::new();
+    parse_element(&mut chars, 0, &mut regulars)?;
+    match chars.next() {
+        Some(c) => Err(ParseError::unexpected_char(c)),
+        None => Ok(Number(regulars)),
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Number>, ParseError> {
+    let numbers: Vec<Number> = input.lines().map(parse_number).collect::<Result<_, _>>()?;
+    if numbers.is_empty() {
+        return Err(ParseError::new("no numbers"));
+    }
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("18")
+    }
+
+    #[rstest]
+    #[case("[[1,2],3", ParseError::new("expected ']'"))]
+    #[case(
+        "[[[[[1,2],3],4],5],6]",
+        ParseError::new("pair nested inside four others")
+    )]
+    #[case("[10,2]", ParseError::unexpected_char('0'))]
+    #[case("[1,2]]", ParseError::unexpected_char(']'))]
+    #[case("1", ParseError::new("not a pair: \"1\""))]
+    fn test_parse_number_invalid(#[case] line: &str, #[case] expected: ParseError) {
+        assert_eq!(parse_number(line), Err(expected));
+    }
+
+    #[rstest]
+    fn test_add() {
+        let sum = parse_number("[[[[4,3],4],4],[7,[[8,4],9]]]")
+            .unwrap()
+            .add(&parse_number("[1,1]").unwrap());
+        assert_eq!(
+            sum,
+            parse_number("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case("[[1,2],[[3,4],5]]", 143)]
+    #[case("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384)]
+    #[case("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]", 3488)]
+    fn test_magnitude(#[case] line: &str, #[case] expected: u32) {
+        assert_eq!(parse_number(line).unwrap().magnitude(), expected);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 4140);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 3993);
+    }
+}
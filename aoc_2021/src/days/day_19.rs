@@ -0,0 +1,199 @@
+use aoc_common::{Coord3, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub fn solve_day(input_file: &str) -> (usize, u32) {
+    let map = parse(input_file).unwrap();
+    (part_a(&map), part_b(&map))
+}
+
+pub const DAY: Day = Day::checked(19, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Map;
+    type AnswerA = usize;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(map: &Self::Parsed<'_>) -> usize {
+        part_a(map)
+    }
+
+    fn part_b(map: &Self::Parsed<'_>) -> u32 {
+        part_b(map)
+    }
+}
+
+type Point = Coord3<i32>;
+
+/// How many beacons two scanners must both see to be sure they overlap.
+const OVERLAP: usize = 12;
+
+/// Every scanner and beacon, relative to the first scanner.
+#[derive(Debug, PartialEq, Eq)]
+struct Map {
+    scanners: Vec<Point>,
+    beacons: HashSet<Point>,
+}
+
+/// The 24 ways a scanner can be facing: every permutation of the axes with
+/// every combination of signs, except the mirror images.
+fn rotations() -> impl Iterator<Item = impl Fn(Point) -> Point> {
+    const PERMUTATIONS: [([usize; 3], i32); 6] = [
+        ([0, 1, 2], 1),
+        ([0, 2, 1], -1),
+        ([1, 0, 2], -1),
+        ([1, 2, 0], 1),
+        ([2, 0, 1], 1),
+        ([2, 1, 0], -1),
+    ];
+    PERMUTATIONS.into_iter().flat_map(|(axes, parity)| {
+        (0..8)
+            .map(|signs| [signs & 1, signs >> 1 & 1, signs >> 2 & 1].map(|bit| 1 - 2 * bit))
+            .filter(move |signs| signs.iter().product::<i32>() == parity)
+            .map(move |signs| {
+                move |point: Point| {
+                    let point = [point.x, point.y, point.z];
+                    let [x, y, z] = [0, 1, 2].map(|i| point[axes[i]] * signs[i]);
+                    Coord3::new(x, y, z)
+                }
+            })
+    })
+}
+
+/// Where the scanner that saw `report` is, and its beacons, if it overlaps
+/// with the already located `beacons`.
+fn locate(beacons: &[Point], report: &[Point]) -> Option<(Point, Vec<Point>)> {
+    rotations().find_map(|rotate| {
+        let rotated: Vec<Point> = report.iter().map(|&point| rotate(point)).collect();
+        let mut offsets = HashMap::<Point, usize>::new();
+        for &known in beacons {
+            for &seen in &rotated {
+                let count = offsets.entry(known - seen).or_default();
+                *count += 1;
+                if *count == OVERLAP {
+                    let scanner = known - seen;
+                    return Some((
+                        scanner,
+                        rotated.iter().map(|&point| point + scanner).collect(),
+                    ));
+                }
+            }
+        }
+        None
+    })
+}
+
+fn part_a(map: &Map) -> usize {
+    map.beacons.len()
+}
+
+fn part_b(map: &Map) -> u32 {
+    let scanners = &map.scanners;
+    scanners
+        .iter()
+        .flat_map(|a| scanners.iter().map(|&b| a.manhattan(b)))
+        .max()
+        .unwrap()
+}
+
+/// Locating the scanners is the bulk of the work and both parts need it, so
+/// it happens here. Fails if some scanners can't be tied to the first one.
+fn parse(input: &str) -> Result<Map, ParseError> {
+    let mut reports = Vec::new();
+    for (i, report) in input.trim().split("\n\n").enumerate() {
+        let mut lines = report.lines();
+        if lines.next() != Some(format!("--- scanner {i} ---").as_str()) {
+            return Err(ParseError::new(format!("expected scanner {i} to be next")));
+        }
+        // Scanners only see 1000 units out, so keep well clear of overflowing
+        let beacons = lines
+            .map(|line| {
+                let Coord3 { x, y, z } = line.parse::<Coord3<i16>>()?;
+                Ok(Coord3::new(x.into(), y.into(), z.into()))
+            })
+            .collect::<Result<Vec<Point>, ParseError>>()?;
+        reports.push(beacons);
+    }
+
+    let mut unlocated: Vec<usize> = (1..reports.len()).collect();
+    let mut scanners = vec![Coord3::default(); reports.len()];
+    let mut located = vec![reports[0].clone()];
+    let mut next = 0;
+    while let Some(beacons) = located.get(next) {
+        let mut found = Vec::new();
+        unlocated.retain(|&i| match locate(beacons, &reports[i]) {
+            Some((scanner, beacons)) => {
+                scanners[i] = scanner;
+                found.push(beacons);
+                false
+            }
+            None => true,
+        });
+        located.extend(found);
+        next += 1;
+    }
+    if let Some(i) = unlocated.first() {
+        return Err(ParseError::new(format!(
+            "scanner {i} overlaps with no others"
+        )));
+    }
+
+    let beacons = located.into_iter().flatten().collect();
+    Ok(Map { scanners, beacons })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("19")
+    }
+
+    #[rstest]
+    fn test_rotations() {
+        let point = Coord3::new(1, 2, 3);
+        let rotated: HashSet<Point> = rotations().map(|rotate| rotate(point)).collect();
+        assert_eq!(rotated.len(), 24);
+        assert!(rotated.contains(&Coord3::new(-2, 1, 3)));
+        assert!(!rotated.contains(&Coord3::new(-1, 2, 3)));
+    }
+
+    #[rstest]
+    fn test_scanners(example_input: String) {
+        let map = parse(&example_input).unwrap();
+        assert_eq!(map.scanners[1], Coord3::new(1105, -68, 43));
+    }
+
+    #[rstest]
+    #[case("--- scanner 1 ---\n1,2,3", "expected scanner 0 to be next")]
+    #[case(
+        "--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n1,2,3",
+        "scanner 1 overlaps with no others"
+    )]
+    fn test_parse_invalid(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(parse(input), Err(ParseError::new(expected)));
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 96);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 3570);
+    }
+}
@@ -0,0 +1,169 @@
+use aoc_common::{Day, Grid, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (usize, usize) {
+    let trench = parse(input_file).unwrap();
+    (part_a(&trench), part_b(&trench))
+}
+
+pub const DAY: Day = Day::checked(20, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Trench;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(trench: &Self::Parsed<'_>) -> usize {
+        part_a(trench)
+    }
+
+    fn part_b(trench: &Self::Parsed<'_>) -> usize {
+        part_b(trench)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Trench {
+    algorithm: Vec<bool>,
+    image: Image,
+}
+
+/// The part of the infinite image that can differ from the rest, which is
+/// all `background`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Image {
+    pixels: Grid<bool>,
+    background: bool,
+}
+
+impl Image {
+    /// Each enhancement can only reach one pixel further into the background.
+    fn enhance(&self, algorithm: &[bool]) -> Image {
+        // Pixel (x, y) of the enhanced image is (x - 1, y - 1) before, so the
+        // square around it starts at (x - 2, y - 2)
+        let lit = |x: usize, y: usize| match (x.checked_sub(2), y.checked_sub(2)) {
+            (Some(x), Some(y)) => self.pixels.get((x, y)).copied().unwrap_or(self.background),
+            _ => self.background,
+        };
+        let (width, height) = (self.pixels.width() + 2, self.pixels.height() + 2);
+        let pixels = Grid::from_fn(width, height, |(x, y)| {
+            let mut index = 0;
+            for y in y..y + 3 {
+                for x in x..x + 3 {
+                    index = index << 1 | usize::from(lit(x, y));
+                }
+            }
+            algorithm[index]
+        });
+        let background = algorithm[if self.background { 511 } else { 0 }];
+        Image { pixels, background }
+    }
+
+    fn lit_after(&self, algorithm: &[bool], steps: usize) -> usize {
+        let mut image = self.clone();
+        for _ in 0..steps {
+            image = image.enhance(algorithm);
+        }
+        image.pixels.cells().iter().filter(|&&lit| lit).count()
+    }
+}
+
+fn part_a(trench: &Trench) -> usize {
+    trench.image.lit_after(&trench.algorithm, 2)
+}
+
+fn part_b(trench: &Trench) -> usize {
+    trench.image.lit_after(&trench.algorithm, 50)
+}
+
+fn pixel(c: char) -> Result<bool, ParseError> {
+    match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(ParseError::unexpected_char(c)),
+    }
+}
+
+fn parse(input: &str) -> Result<Trench, ParseError> {
+    let (algorithm, image) = input
+        .trim()
+        .split_once("\n\n")
+        .ok_or(ParseError::new("no blank line after the algorithm"))?;
+    let algorithm: Vec<bool> = algorithm.chars().map(pixel).collect::<Result<_, _>>()?;
+    if algorithm.len() != 512 {
+        return Err(ParseError::new(format!(
+            "the algorithm has {} entries instead of 512",
+            algorithm.len()
+        )));
+    }
+    if algorithm[0] && algorithm[511] {
+        return Err(ParseError::new("the background lights up and stays lit"));
+    }
+    let pixels = Grid::parse_with(image, pixel)?;
+    Ok(Trench {
+        algorithm,
+        image: Image {
+            pixels,
+            background: false,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("20")
+    }
+
+    #[rstest]
+    fn test_parse_invalid() {
+        assert_eq!(
+            parse("#.#\n\n#"),
+            Err(ParseError::new(
+                "the algorithm has 3 entries instead of 512"
+            ))
+        );
+        let flashing = format!("#{}#\n\n#", ".".repeat(510));
+        assert_eq!(
+            parse(&flashing),
+            Err(ParseError::new("the background lights up and stays lit"))
+        );
+    }
+
+    #[rstest]
+    fn test_flashing_background() {
+        // Only a square that is all dark lights up
+        let algorithm: Vec<bool> = (0..512).map(|i| i == 0).collect();
+        let image = Image {
+            pixels: Grid::new(1, 1, false),
+            background: false,
+        };
+        let once = image.enhance(&algorithm);
+        assert!(once.background);
+        assert!(!once.enhance(&algorithm).background);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 35);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 3351);
+    }
+}
@@ -0,0 +1,171 @@
+use aoc_common::{Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub fn solve_day(input_file: &str) -> (u32, u64) {
+    let starts = parse(input_file).unwrap();
+    (part_a(&starts), part_b(&starts))
+}
+
+pub const DAY: Day = Day::checked(21, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = [u8; 2];
+    type AnswerA = u32;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(starts: &Self::Parsed<'_>) -> u32 {
+        part_a(starts)
+    }
+
+    fn part_b(starts: &Self::Parsed<'_>) -> u64 {
+        part_b(starts)
+    }
+}
+
+/// Moves `steps` forward around the track of 1 to 10.
+fn advance(position: u8, steps: u32) -> u8 {
+    ((u32::from(position) - 1 + steps) % 10 + 1) as u8
+}
+
+/// The losing score times how often the die was rolled.
+fn part_a(starts: &[u8; 2]) -> u32 {
+    let mut positions = *starts;
+    let mut scores = [0u32; 2];
+    let mut rolls = 0;
+    for player in (0..2).cycle() {
+        // The deterministic die goes 1 to 100, but only the sum mod 10 matters
+        let steps = 3 * rolls + 6;
+        rolls += 3;
+        positions[player] = advance(positions[player], steps);
+        scores[player] += u32::from(positions[player]);
+        if scores[player] >= 1000 {
+            return scores[1 - player] * rolls;
+        }
+    }
+    unreachable!()
+}
+
+/// How often each total comes up when rolling the Dirac die three times.
+const DIRAC_ROLLS: [(u32, u64); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+/// Plays the Dirac die on its own for a player starting at `start`. For each
+/// of their turns, gives in how many universes they win on that turn and in
+/// how many they still haven't won after it.
+fn turns(start: u8) -> Vec<(u64, u64)> {
+    let mut playing = HashMap::from([((start, 0u8), 1u64)]);
+    let mut turns = Vec::new();
+    while !playing.is_empty() {
+        let mut won = 0;
+        let mut next = HashMap::new();
+        for ((position, score), count) in playing {
+            for (steps, universes) in DIRAC_ROLLS {
+                let position = advance(position, steps);
+                let score = score + position;
+                if score >= 21 {
+                    won += count * universes;
+                } else {
+                    *next.entry((position, score)).or_default() += count * universes;
+                }
+            }
+        }
+        turns.push((won, next.values().sum()));
+        playing = next;
+    }
+    turns
+}
+
+/// How many universes the player who wins in more universes wins in.
+///
+/// The players don't affect each other, so a player wins on a turn in every
+/// pairing of a universe where they win then with one where the other player
+/// hasn't won yet.
+fn part_b(starts: &[u8; 2]) -> u64 {
+    let (first, second) = (turns(starts[0]), turns(starts[1]));
+    // Universes of `turns` still playing after `taken` turns
+    let playing = |turns: &[(u64, u64)], taken: usize| match taken {
+        0 => 1,
+        taken => turns.get(taken - 1).map_or(0, |&(_, playing)| playing),
+    };
+    let first_wins: u64 = (first.iter().enumerate())
+        .map(|(turn, &(won, _))| won * playing(&second, turn))
+        .sum();
+    let second_wins: u64 = (second.iter().enumerate())
+        .map(|(turn, &(won, _))| won * playing(&first, turn + 1))
+        .sum();
+    first_wins.max(second_wins)
+}
+
+fn parse(input: &str) -> Result<[u8; 2], ParseError> {
+    let mut starts = [0; 2];
+    let mut lines = input.lines();
+    for (player, start) in (1..).zip(&mut starts) {
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(format!("no start for player {player}")))?;
+        let position = line
+            .strip_prefix(&format!("Player {player} starting position: "))
+            .ok_or_else(|| ParseError::new(format!("not player {player}'s start: {line:?}")))?;
+        *start = match position.parse()? {
+            position @ 1..=10 => position,
+            position => return Err(ParseError::new(format!("no space {position} on the track"))),
+        };
+    }
+    match lines.next() {
+        Some(line) => Err(ParseError::new(format!("unexpected line {line:?}"))),
+        None => Ok(starts),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("21")
+    }
+
+    #[rstest]
+    #[case(7, 5, 2)]
+    #[case(10, 10, 10)]
+    #[case(1, 9, 10)]
+    fn test_advance(#[case] position: u8, #[case] steps: u32, #[case] expected: u8) {
+        assert_eq!(advance(position, steps), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "Player 1 starting position: 11\nPlayer 2 starting position: 8",
+        "no space 11 on the track"
+    )]
+    #[case("Player 1 starting position: 4", "no start for player 2")]
+    #[case(
+        "Player 2 starting position: 4\nPlayer 1 starting position: 8",
+        "not player 1's start: \"Player 2 starting position: 4\""
+    )]
+    fn test_parse_invalid(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(parse(input), Err(ParseError::new(expected)));
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 739785);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 444356092776315);
+    }
+}
@@ -0,0 +1,186 @@
+use aoc_common::{Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (u128, u128) {
+    let steps = parse(input_file).unwrap();
+    (part_a(&steps), part_b(&steps))
+}
+
+pub const DAY: Day = Day::checked(22, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Step>;
+    type AnswerA = u128;
+    type AnswerB = u128;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(steps: &Self::Parsed<'_>) -> u128 {
+        part_a(steps)
+    }
+
+    fn part_b(steps: &Self::Parsed<'_>) -> u128 {
+        part_b(steps)
+    }
+}
+
+/// The cubes from `min` to `max` along each axis, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cuboid {
+    min: [i32; 3],
+    max: [i32; 3],
+}
+
+impl Cuboid {
+    fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = [0, 1, 2].map(|axis| self.min[axis].max(other.min[axis]));
+        let max = [0, 1, 2].map(|axis| self.max[axis].min(other.max[axis]));
+        (0..3)
+            .all(|axis| min[axis] <= max[axis])
+            .then_some(Cuboid { min, max })
+    }
+
+    /// In `i128`, which fits the volume of any cuboid with `i32` corners.
+    fn volume(&self) -> i128 {
+        (0..3)
+            .map(|axis| i128::from(self.max[axis]) - i128::from(self.min[axis]) + 1)
+            .product()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Step {
+    on: bool,
+    cuboid: Cuboid,
+}
+
+/// How many cubes are on after every step.
+///
+/// Keeps a list of cuboids that count for or against the total. Every new
+/// step cancels out its overlap with what is already in the list, and steps
+/// that turn cubes on then add themselves.
+fn cubes_on(steps: impl IntoIterator<Item = (bool, Cuboid)>) -> u128 {
+    let mut counted: Vec<(Cuboid, i128)> = Vec::new();
+    for (on, cuboid) in steps {
+        let overlaps: Vec<_> = counted
+            .iter()
+            .filter_map(|(other, sign)| Some((cuboid.intersection(other)?, -sign)))
+            .collect();
+        counted.extend(overlaps);
+        if on {
+            counted.push((cuboid, 1));
+        }
+    }
+    let total: i128 = counted
+        .iter()
+        .map(|(cuboid, sign)| sign * cuboid.volume())
+        .sum();
+    total as u128
+}
+
+/// Only the cubes within 50 of the origin.
+fn part_a(steps: &[Step]) -> u128 {
+    let region = Cuboid {
+        min: [-50; 3],
+        max: [50; 3],
+    };
+    cubes_on(
+        steps
+            .iter()
+            .filter_map(|step| Some((step.on, step.cuboid.intersection(&region)?))),
+    )
+}
+
+fn part_b(steps: &[Step]) -> u128 {
+    cubes_on(steps.iter().map(|step| (step.on, step.cuboid)))
+}
+
+fn parse_step(line: &str) -> Result<Step, ParseError> {
+    let not_a_step = || ParseError::new(format!("not a step: {line:?}"));
+    let (on, ranges) = line.split_once(' ').ok_or_else(not_a_step)?;
+    let on = match on {
+        "on" => true,
+        "off" => false,
+        _ => return Err(not_a_step()),
+    };
+    let mut cuboid = Cuboid {
+        min: [0; 3],
+        max: [0; 3],
+    };
+    let mut ranges = ranges.split(',');
+    for (axis, name) in ["x=", "y=", "z="].into_iter().enumerate() {
+        let (min, max) = ranges
+            .next()
+            .and_then(|range| range.strip_prefix(name)?.split_once(".."))
+            .ok_or_else(not_a_step)?;
+        let (min, max) = (min.parse()?, max.parse()?);
+        if min > max {
+            return Err(ParseError::new(format!("range {min}..{max} is backwards")));
+        }
+        (cuboid.min[axis], cuboid.max[axis]) = (min, max);
+    }
+    match ranges.next() {
+        Some(_) => Err(not_a_step()),
+        None => Ok(Step { on, cuboid }),
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+    input.lines().map(parse_step).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("22")
+    }
+
+    const SMALL: &str = "on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10";
+
+    #[rstest]
+    #[case("on x=1..2,y=3..4", "not a step: \"on x=1..2,y=3..4\"")]
+    #[case("of x=1..2,y=3..4,z=5..6", "not a step: \"of x=1..2,y=3..4,z=5..6\"")]
+    #[case("on x=1..2,y=4..3,z=5..6", "range 4..3 is backwards")]
+    fn test_parse_step_invalid(#[case] line: &str, #[case] expected: &str) {
+        assert_eq!(parse_step(line), Err(ParseError::new(expected)));
+    }
+
+    #[rstest]
+    fn test_volume_does_not_overflow() {
+        let everything = Cuboid {
+            min: [i32::MIN; 3],
+            max: [i32::MAX; 3],
+        };
+        assert_eq!(everything.volume(), 1 << 96);
+    }
+
+    #[rstest]
+    fn test_small() {
+        assert_eq!(part_b(&parse(SMALL).unwrap()), 39);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 258308);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 1976267656768);
+    }
+}
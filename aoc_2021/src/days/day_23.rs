@@ -0,0 +1,251 @@
+use aoc_common::{dijkstra, Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (u32, u32) {
+    let burrow = parse(input_file).unwrap();
+    (part_a(&burrow), part_b(&burrow))
+}
+
+pub const DAY: Day = Day::checked(23, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Burrow;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(burrow: &Self::Parsed<'_>) -> u32 {
+        part_a(burrow)
+    }
+
+    fn part_b(burrow: &Self::Parsed<'_>) -> u32 {
+        part_b(burrow)
+    }
+}
+
+/// An amphipod type, from 0 for Amber to 3 for Desert.
+type Amphipod = u8;
+
+const HALLWAY: usize = 11;
+
+/// The hallway space outside `room`, where amphipods never stop.
+fn entrance(room: usize) -> usize {
+    2 + 2 * room
+}
+
+fn energy(amphipod: Amphipod, steps: usize) -> u32 {
+    10u32.pow(amphipod.into()) * steps as u32
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Burrow {
+    hallway: [Option<Amphipod>; HALLWAY],
+    /// Each room from the hallway down, room `i` being where amphipod `i`
+    /// belongs.
+    rooms: [Vec<Option<Amphipod>>; 4],
+}
+
+impl Burrow {
+    fn is_organised(&self) -> bool {
+        (0..4).all(|room| {
+            self.rooms[room]
+                .iter()
+                .all(|&a| a == Some(room as Amphipod))
+        })
+    }
+
+    /// Whether the hallway between `from` and `to` is free, not counting
+    /// `from` itself.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let between = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+        self.hallway[between].iter().all(Option::is_none)
+    }
+
+    /// Every move one amphipod can make, with the energy it takes.
+    ///
+    /// Going from one room straight into another is the same as stopping in
+    /// the hallway in between, so amphipods only ever move from a room into
+    /// the hallway or from the hallway into their own room.
+    fn moves(&self) -> Vec<(Burrow, u32)> {
+        let mut moves = Vec::new();
+        for (from, &amphipod) in self.hallway.iter().enumerate() {
+            let Some(amphipod) = amphipod else { continue };
+            let room = &self.rooms[usize::from(amphipod)];
+            let door = entrance(amphipod.into());
+            if room.iter().any(|&a| a.is_some_and(|a| a != amphipod)) || !self.is_clear(from, door)
+            {
+                continue;
+            }
+            let Some(depth) = room.iter().rposition(Option::is_none) else {
+                continue;
+            };
+            let mut next = self.clone();
+            next.hallway[from] = None;
+            next.rooms[usize::from(amphipod)][depth] = Some(amphipod);
+            moves.push((next, energy(amphipod, from.abs_diff(door) + depth + 1)));
+        }
+
+        for (i, room) in self.rooms.iter().enumerate() {
+            let Some(depth) = room.iter().position(Option::is_some) else {
+                continue;
+            };
+            if room[depth..].iter().all(|&a| a == Some(i as Amphipod)) {
+                continue;
+            }
+            let amphipod = room[depth].unwrap();
+            let door = entrance(i);
+            for to in (0..HALLWAY).filter(|&to| (0..4).all(|room| entrance(room) != to)) {
+                if self.is_clear(door, to) {
+                    let mut next = self.clone();
+                    next.rooms[i][depth] = None;
+                    next.hallway[to] = Some(amphipod);
+                    moves.push((next, energy(amphipod, depth + 1 + door.abs_diff(to))));
+                }
+            }
+        }
+        moves
+    }
+
+    fn least_energy(&self) -> u32 {
+        let search = dijkstra([self.clone()], Burrow::moves, Burrow::is_organised);
+        search.goal_cost().unwrap()
+    }
+}
+
+fn part_a(burrow: &Burrow) -> u32 {
+    burrow.least_energy()
+}
+
+/// The part of the diagram that was folded away, which goes between the top
+/// and the bottom row.
+const UNFOLDED: [[Amphipod; 2]; 4] = [[3, 3], [2, 1], [1, 0], [0, 2]];
+
+fn part_b(burrow: &Burrow) -> u32 {
+    let mut unfolded = burrow.clone();
+    for (room, inserted) in unfolded.rooms.iter_mut().zip(UNFOLDED) {
+        room.splice(1..1, inserted.map(Some));
+    }
+    unfolded.least_energy()
+}
+
+fn parse(input: &str) -> Result<Burrow, ParseError> {
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    let [top, hallway, rows @ .., bottom] = &lines[..] else {
+        return Err(ParseError::new("too few lines for a burrow"));
+    };
+    if *top != "#############" || *bottom != "  #########" {
+        return Err(ParseError::new("burrow isn't walled in"));
+    }
+    if *hallway != "#...........#" {
+        return Err(ParseError::new("hallway isn't empty"));
+    }
+    if rows.is_empty() {
+        return Err(ParseError::new("no rooms"));
+    }
+
+    let mut rooms: [Vec<Option<Amphipod>>; 4] = Default::default();
+    for (i, row) in rows.iter().enumerate() {
+        let walls = if i == 0 {
+            "###.#.#.#.###"
+        } else {
+            "  #.#.#.#.#"
+        };
+        if row.len() != walls.len() {
+            return Err(ParseError::new(format!("not a row of rooms: {row:?}")));
+        }
+        let mut room = 0;
+        for (c, wall) in row.chars().zip(walls.chars()) {
+            match (wall, c) {
+                ('.', 'A'..='D') => {
+                    rooms[room].push(Some(c as u8 - b'A'));
+                    room += 1;
+                }
+                _ if wall == c => {}
+                _ => return Err(ParseError::unexpected_char(c)),
+            }
+        }
+    }
+
+    for amphipod in 0..4 {
+        let count = rooms
+            .iter()
+            .flatten()
+            .filter(|&&a| a == Some(amphipod))
+            .count();
+        if count != rows.len() {
+            return Err(ParseError::new(format!(
+                "{count} amphipods of type {} for {} spaces",
+                (b'A' + amphipod) as char,
+                rows.len()
+            )));
+        }
+    }
+    Ok(Burrow {
+        hallway: [None; HALLWAY],
+        rooms,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("23")
+    }
+
+    #[rstest]
+    fn test_parse(example_input: String) {
+        let burrow = parse(&example_input).unwrap();
+        assert_eq!(burrow.rooms[0], [Some(1), Some(0)]);
+        assert_eq!(burrow.rooms[3], [Some(3), Some(0)]);
+    }
+
+    #[rstest]
+    #[case(
+        "#############\n#...........#\n###B#C#B#D###\n  #A#D#C#B#\n  #########",
+        "1 amphipods of type A for 2 spaces"
+    )]
+    #[case(
+        "#############\n#.....A.....#\n###B#C#B#D###\n  #########",
+        "hallway isn't empty"
+    )]
+    #[case(
+        "#############\n#...........#\n###B#C#B#D##\n  #########",
+        "not a row of rooms: \"###B#C#B#D##\""
+    )]
+    fn test_parse_invalid(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(parse(input), Err(ParseError::new(expected)));
+    }
+
+    #[rstest]
+    fn test_already_organised() {
+        let burrow = parse("#############\n#...........#\n###A#B#C#D###\n  #########").unwrap();
+        assert_eq!(burrow.least_energy(), 0);
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 12521);
+    }
+
+    #[rstest]
+    fn test_part_b(example_input: String) {
+        assert_eq!(part_b(&parse(&example_input).unwrap()), 44169);
+    }
+}
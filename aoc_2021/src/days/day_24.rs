@@ -0,0 +1,240 @@
+use aoc_common::{Day, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (u64, u64) {
+    let pairs = parse(input_file).unwrap();
+    (part_a(&pairs), part_b(&pairs))
+}
+
+pub const DAY: Day = Day::checked(24, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Pair>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(pairs: &Self::Parsed<'_>) -> u64 {
+        part_a(pairs)
+    }
+
+    fn part_b(pairs: &Self::Parsed<'_>) -> u64 {
+        part_b(pairs)
+    }
+}
+
+/// MONAD handles each digit with the same 18 instructions, which only differ
+/// in the three numbers in braces.
+const BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z {}", "add x {}", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y {}",
+    "mul y x", "add z y",
+];
+
+const DIGITS: usize = 14;
+
+/// `z` is a stack of base 26 digits. A block that doesn't divide `z` pushes
+/// its digit plus an offset. A block that does pops that back off, and only
+/// leaves nothing behind if its own digit is `difference` more.
+#[derive(Debug, PartialEq, Eq)]
+struct Pair {
+    push: usize,
+    pop: usize,
+    difference: i64,
+}
+
+fn model_number(digits: [i64; DIGITS]) -> u64 {
+    digits
+        .iter()
+        .fold(0, |number, &digit| number * 10 + digit as u64)
+}
+
+fn part_a(pairs: &[Pair]) -> u64 {
+    let mut digits = [0; DIGITS];
+    for pair in pairs {
+        digits[pair.push] = 9.min(9 - pair.difference);
+        digits[pair.pop] = digits[pair.push] + pair.difference;
+    }
+    model_number(digits)
+}
+
+fn part_b(pairs: &[Pair]) -> u64 {
+    let mut digits = [0; DIGITS];
+    for pair in pairs {
+        digits[pair.push] = 1.max(1 - pair.difference);
+        digits[pair.pop] = digits[pair.push] + pair.difference;
+    }
+    model_number(digits)
+}
+
+/// The three numbers in one block of MONAD.
+fn parse_block(lines: &[&str]) -> Result<[i64; 3], ParseError> {
+    let mut numbers = Vec::new();
+    for (&line, template) in lines.iter().zip(BLOCK) {
+        let mismatch = || ParseError::new(format!("expected {template:?}, not {line:?}"));
+        match template.strip_suffix("{}") {
+            Some(prefix) => numbers.push(line.strip_prefix(prefix).ok_or_else(mismatch)?.parse()?),
+            None if line == template => {}
+            None => return Err(mismatch()),
+        }
+    }
+    Ok(numbers.try_into().unwrap())
+}
+
+/// Only accepts MONAD itself, with a valid model number. Anything else would
+/// need an actual search through the inputs.
+fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    if lines.len() != DIGITS * BLOCK.len() {
+        return Err(ParseError::new(format!(
+            "{} instructions instead of MONAD's {}",
+            lines.len(),
+            DIGITS * BLOCK.len()
+        )));
+    }
+
+    let mut pairs = Vec::new();
+    let mut stack = Vec::new();
+    for (i, block) in lines.chunks(BLOCK.len()).enumerate() {
+        match parse_block(block)? {
+            // No digit is 10 or more, so this always pushes
+            [1, check, offset] if check > 9 => stack.push((i, offset)),
+            [26, check, _] => {
+                let (push, offset) = stack
+                    .pop()
+                    .ok_or_else(|| ParseError::new(format!("digit {i} pops off an empty stack")))?;
+                let difference = offset
+                    .checked_add(check)
+                    .filter(|difference| (-8..=8).contains(difference))
+                    .ok_or_else(|| {
+                        ParseError::new(format!("no digits {push} and {i} work together"))
+                    })?;
+                pairs.push(Pair {
+                    push,
+                    pop: i,
+                    difference,
+                });
+            }
+            _ => {
+                return Err(ParseError::new(format!(
+                    "digit {i} neither pushes nor pops"
+                )))
+            }
+        }
+    }
+    match stack.pop() {
+        Some((i, _)) => Err(ParseError::new(format!("digit {i} is never popped"))),
+        None => Ok(pairs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+    use std::collections::HashMap;
+
+    /// The numbers of a made up MONAD, block by block.
+    const NUMBERS: [[i64; 3]; DIGITS] = [
+        [1, 13, 13],
+        [1, 12, 5],
+        [26, -11, 7],
+        [26, -5, 5],
+        [1, 11, 6],
+        [1, 12, 15],
+        [1, 11, 10],
+        [1, 11, 5],
+        [1, 12, 4],
+        [26, -5, 9],
+        [26, 1, 3],
+        [26, -12, 7],
+        [26, -11, 16],
+        [26, -4, 15],
+    ];
+
+    #[fixture]
+    fn monad() -> String {
+        let mut lines = Vec::new();
+        for numbers in NUMBERS {
+            let mut numbers = numbers.iter();
+            for template in BLOCK {
+                match template.strip_suffix("{}") {
+                    Some(prefix) => lines.push(format!("{prefix}{}", numbers.next().unwrap())),
+                    None => lines.push(template.to_string()),
+                }
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Runs the program on the ALU, returning `z`.
+    fn run(program: &str, number: u64) -> i64 {
+        let mut digits = number.to_string().into_bytes().into_iter();
+        let mut registers = HashMap::from([("w", 0), ("x", 0), ("y", 0), ("z", 0)]);
+        for line in program.lines() {
+            let parts: Vec<&str> = line.split(' ').collect();
+            if let ["inp", a] = parts[..] {
+                registers.insert(a, i64::from(digits.next().unwrap() - b'0'));
+                continue;
+            }
+            let [instruction, a, b] = parts[..] else {
+                panic!("not an instruction: {line:?}")
+            };
+            let b = registers
+                .get(b)
+                .copied()
+                .unwrap_or_else(|| b.parse().unwrap());
+            let a = registers.get_mut(a).unwrap();
+            match instruction {
+                "add" => *a += b,
+                "mul" => *a *= b,
+                "div" => *a /= b,
+                "mod" => *a %= b,
+                "eql" => *a = i64::from(*a == b),
+                _ => panic!("not an instruction: {line:?}"),
+            }
+        }
+        registers["z"]
+    }
+
+    #[rstest]
+    fn test_answers_are_valid(monad: String) {
+        let pairs = parse(&monad).unwrap();
+        for number in [part_a(&pairs), part_b(&pairs)] {
+            assert_eq!(run(&monad, number), 0);
+            assert!(number.to_string().bytes().all(|digit| digit != b'0'));
+        }
+    }
+
+    #[rstest]
+    fn test_parse_invalid(monad: String) {
+        let doubled = monad.replacen("div z 1\n", "div z 2\n", 1);
+        assert_eq!(
+            parse(&doubled),
+            Err(ParseError::new("digit 0 neither pushes nor pops"))
+        );
+        let dropped = monad.replacen("mul x 0\n", "", 1);
+        assert_eq!(
+            parse(&dropped),
+            Err(ParseError::new("251 instructions instead of MONAD's 252"))
+        );
+    }
+
+    #[rstest]
+    fn test_part_a(monad: String) {
+        assert_eq!(part_a(&parse(&monad).unwrap()), 19397593989799);
+    }
+
+    #[rstest]
+    fn test_part_b(monad: String) {
+        assert_eq!(part_b(&parse(&monad).unwrap()), 17191131217153);
+    }
+}
@@ -0,0 +1,113 @@
+use aoc_common::{Day, Grid, ParseError, Solution};
+
+pub fn solve_day(input_file: &str) -> (usize, &'static str) {
+    let floor = parse(input_file).unwrap();
+    (part_a(&floor), part_b(&floor))
+}
+
+pub const DAY: Day = Day::checked(25, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Grid<Cell>;
+    type AnswerA = usize;
+    type AnswerB = &'static str;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(floor: &Self::Parsed<'_>) -> usize {
+        part_a(floor)
+    }
+
+    fn part_b(floor: &Self::Parsed<'_>) -> &'static str {
+        part_b(floor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    East,
+    South,
+}
+
+/// Moves every sea cucumber of `herd` that has room one step, wrapping
+/// around the edges. Returns whether any of them moved.
+fn move_herd(floor: &mut Grid<Cell>, herd: Cell, step: (isize, isize)) -> bool {
+    let moving: Vec<_> = floor
+        .positions()
+        .filter(|&pos| floor[pos] == herd)
+        .map(|pos| (pos, floor.wrapping_neighbour(pos, step)))
+        .filter(|&(_, to)| floor[to] == Cell::Empty)
+        .collect();
+    for &(from, to) in &moving {
+        floor[from] = Cell::Empty;
+        floor[to] = herd;
+    }
+    !moving.is_empty()
+}
+
+/// The first step on which no sea cucumber moves.
+fn part_a(floor: &Grid<Cell>) -> usize {
+    let mut floor = floor.clone();
+    (1..)
+        .find(|_| {
+            let east = move_herd(&mut floor, Cell::East, (1, 0));
+            let south = move_herd(&mut floor, Cell::South, (0, 1));
+            !east && !south
+        })
+        .unwrap()
+}
+
+/// The last star comes from having all the others.
+fn part_b(_floor: &Grid<Cell>) -> &'static str {
+    "Merry Christmas"
+}
+
+fn parse(input: &str) -> Result<Grid<Cell>, ParseError> {
+    Grid::parse_with(input, |c| match c {
+        '.' => Ok(Cell::Empty),
+        '>' => Ok(Cell::East),
+        'v' => Ok(Cell::South),
+        _ => Err(ParseError::unexpected_char(c)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("25")
+    }
+
+    #[rstest]
+    fn test_move_herd() {
+        let mut floor = parse("...>>>>>...").unwrap();
+        assert!(move_herd(&mut floor, Cell::East, (1, 0)));
+        assert_eq!(floor, parse("...>>>>.>..").unwrap());
+        assert!(!move_herd(&mut floor, Cell::South, (0, 1)));
+    }
+
+    #[rstest]
+    fn test_wraps_around() {
+        let mut floor = parse(".>\nv.").unwrap();
+        move_herd(&mut floor, Cell::East, (1, 0));
+        assert_eq!(floor, parse(">.\nv.").unwrap());
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&parse(&example_input).unwrap()), 58);
+    }
+}
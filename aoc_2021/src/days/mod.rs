@@ -0,0 +1,57 @@
+pub use aoc_common::{read_day_input, read_day_input_if_present, read_test_day_input};
+use aoc_common::{DayOutcome, RunConfig, Year};
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+
+aoc_common::days! {
+    day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11, day_12,
+    day_13, day_14, day_15, day_16, day_17, day_18, day_19, day_20, day_21, day_22, day_23, day_24,
+    day_25,
+}
+
+pub static YEAR: Year = Year {
+    year: 2021,
+    days: DAYS,
+    input_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    baseline: concat!(env!("CARGO_MANIFEST_DIR"), "/benches/baseline.toml"),
+};
+
+pub fn solve_days() -> Vec<DayOutcome> {
+    aoc_common::run_year(&YEAR, &RunConfig::from_env())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_in_day_order() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.day as usize, i + 1);
+        }
+    }
+}
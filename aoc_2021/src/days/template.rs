@@ -0,0 +1,54 @@
+use aoc_common::{Day, Solution};
+
+pub fn solve_day(input_file: &str) -> (u32, u32) {
+    let a = part_a(input_file);
+    let b = part_b(input_file);
+    (a, b)
+}
+
+pub const DAY: Day = Day::new(0, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u32 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u32 {
+        part_b(input)
+    }
+}
+
+fn part_a(_input_file: &str) -> u32 {
+    0
+}
+
+fn part_b(_input_file: &str) -> u32 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::read_test_day_input;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn example_input() -> String {
+        read_test_day_input("00")
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&example_input), 0)
+    }
+}
@@ -0,0 +1,5 @@
+use aoc_2021::days::solve_days;
+
+fn main() {
+    solve_days();
+}
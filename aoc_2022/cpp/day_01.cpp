@@ -0,0 +1,25 @@
+// Day 1: Calorie Counting. Reads the puzzle input from stdin and prints the
+// answer to each part on its own line, for `aoc cross-check --day 1`.
+#include <algorithm>
+#include <functional>
+#include <iostream>
+#include <numeric>
+#include <string>
+#include <vector>
+
+int main() {
+    std::vector<long> elves{0};
+    std::string line;
+    while (std::getline(std::cin, line)) {
+        if (line.empty()) {
+            elves.push_back(0);
+        } else {
+            elves.back() += std::stol(line);
+        }
+    }
+
+    std::sort(elves.begin(), elves.end(), std::greater<long>());
+    std::cout << elves[0] << '\n';
+    auto top_three = elves.begin() + std::min<size_t>(3, elves.size());
+    std::cout << std::accumulate(elves.begin(), top_three, 0L) << '\n';
+}
@@ -0,0 +1,4 @@
+aoc_common::scaling_main!(
+    &advent_of_code_2022::YEAR,
+    advent_of_code_2022::generate::GENERATORS
+);
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::collections::BinaryHeap;
 
 pub fn solve(input: &str) -> (u32, u32) {
@@ -6,6 +7,28 @@ pub fn solve(input: &str) -> (u32, u32) {
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(1, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u32 {
+        solve_part_1(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u32 {
+        solve_part_2(input)
+    }
+}
+
 fn solve_part_1(input_str: &str) -> u32 {
     let max_calorie = calories_per_elf(input_str)
         .max()
@@ -1,9 +1,33 @@
+use aoc_common::{Day, Solution};
+
 pub fn solve(input: &str) -> (u32, u32) {
     let part_1 = solve_part_1(input);
     let part_2 = solve_part_2(input);
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(2, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u32 {
+        solve_part_1(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u32 {
+        solve_part_2(input)
+    }
+}
+
 fn solve_part_1(input_str: &str) -> u32 {
     input_str
         .split('\n')
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use itertools::Itertools;
 
 pub fn solve(input: &str) -> (usize, usize) {
@@ -6,6 +7,28 @@ pub fn solve(input: &str) -> (usize, usize) {
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(4, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> usize {
+        solve_part_1(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> usize {
+        solve_part_2(input)
+    }
+}
+
 fn solve_part_1(input_str: &str) -> usize {
     parse_input(input_str).filter(check_overlap).count()
 }
@@ -83,6 +106,7 @@ mod tests_day4 {
     #[case("2-4", (2, 4))]
     #[case("6-8", (6, 8))]
     #[case("3-8", (3, 8))]
+    #[case("0-65535", (0, u16::MAX))]
     fn test_parse_bounds(#[case] bound_str: &str, #[case] expected: (u16, u16)) {
         assert_eq!(parse_bounds(bound_str), expected)
     }
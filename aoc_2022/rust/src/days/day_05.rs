@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use itertools::Itertools;
 use regex::Regex;
 
@@ -13,6 +14,28 @@ pub fn solve(input_str: &str) -> (String, String) {
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(5, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = (CrateStacks, Operations);
+    type AnswerA = String;
+    type AnswerB = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a((crates, operations): &Self::Parsed<'_>) -> String {
+        solve_part_1(crates.clone(), operations)
+    }
+
+    fn part_b((crates, operations): &Self::Parsed<'_>) -> String {
+        solve_part_2(crates.clone(), operations)
+    }
+}
+
 fn solve_part_1(crates: CrateStacks, operations: &Operations) -> String {
     let crates = apply_operations_p1(crates, operations);
     let mut out = vec![];
@@ -55,7 +78,7 @@ fn extract_crate_part(input_str: &str) -> Vec<Vec<char>> {
     let mut i = 0;
     let mut crates: CrateStacks = vec![];
     // Parse crate stacks
-    for _crate in input_str.chars().into_iter().chunks(4).into_iter() {
+    for _crate in input_str.chars().chunks(4).into_iter() {
         match _crate.collect::<CrateStack>()[..] {
             ['[', x, ']', end_char] => {
                 match crates.get_mut(i) {
@@ -118,14 +141,14 @@ fn apply_operation_p2(mut crates: CrateStacks, operation: &Operation) -> CrateSt
 #[cfg(test)]
 mod tests {
 
-    use crate::days::read_day_input;
+    use crate::days::read_test_day_input;
 
     use super::*;
     use rstest::*;
 
     #[fixture]
     fn input_str() -> String {
-        read_day_input("test_day_05")
+        read_test_day_input("05")
     }
 
     #[fixture]
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::collections::VecDeque;
 
 use itertools::{
@@ -12,6 +13,28 @@ pub fn solve(input_str: &str) -> (usize, usize) {
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(6, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> usize {
+        solve_part_1(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> usize {
+        solve_part_2(input)
+    }
+}
+
 fn solve_part_1(input_str: &str) -> usize {
     input_str
         .chars()
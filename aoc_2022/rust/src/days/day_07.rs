@@ -1,8 +1,6 @@
+use aoc_common::{Day, Solution};
 use regex::Regex;
-use std::{
-    borrow::{Borrow, BorrowMut},
-    str::FromStr,
-};
+use std::{borrow::BorrowMut, str::FromStr};
 
 use lazy_static::lazy_static;
 
@@ -13,6 +11,28 @@ pub fn solve(input_str: &str) -> (u32, u32) {
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(7, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = FS;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a(fs: &Self::Parsed<'_>) -> u32 {
+        solve_part_1(fs)
+    }
+
+    fn part_b(fs: &Self::Parsed<'_>) -> u32 {
+        solve_part_2(fs)
+    }
+}
+
 fn solve_part_1(fs: &FS) -> u32 {
     fs.calculate_total_size_of_directories_of_at_most_100000()
 }
@@ -92,7 +112,7 @@ impl FS {
     fn _find_smallest_directory_larger_then(&self, min_size: u32, mut current_best: u32) -> u32 {
         if let FS::Dir(_, sub_items, _) = self {
             for sub_item in sub_items {
-                if let FS::File(_, _) = sub_item.borrow() {
+                if let FS::File(_, _) = sub_item {
                     continue;
                 }
                 if sub_item.get_size() > min_size && sub_item.get_size() < current_best {
@@ -120,10 +140,10 @@ impl FromStr for FS {
             static ref RE: Regex = Regex::new(r"(?P<size>[0-9]*) (?P<name>.*)").unwrap();
         }
         let capture = RE.captures(s).unwrap();
-        return Ok(FS::File(
+        Ok(FS::File(
             capture.name("name").unwrap().as_str().to_string(),
             capture.name("size").unwrap().as_str().parse().unwrap(),
-        ));
+        ))
     }
 }
 
@@ -232,7 +252,7 @@ mod tests {
 
     #[rstest]
     fn test_parse_day_input() {
-        let input = read_day_input("day_07");
+        let input = read_day_input("07");
         parse_input(input.as_str());
     }
 }
@@ -1,7 +1,6 @@
+use aoc_common::{Day, Grid, ParseError, Pos, Solution, ORTHOGONAL};
 use std::cmp::max;
 
-use itertools::Itertools;
-
 pub fn solve(input_str: &str) -> (u32, usize) {
     let height_map = parse_input(input_str);
     let part_1 = solve_part_1(&height_map);
@@ -9,167 +8,109 @@ pub fn solve(input_str: &str) -> (u32, usize) {
     (part_1, part_2)
 }
 
-fn solve_part_1(height_map: &Vec<Vec<u8>>) -> u32 {
-    let visibility_map = create_visibility_map(height_map);
-    visibility_map.iter().fold(0, |sum, line| {
-        sum + line.iter().fold(0, |sum, vis| sum + *vis as u32)
-    })
-}
+pub const DAY: Day = Day::new(8, &Solver);
 
-fn solve_part_2(height_map: &Vec<Vec<u8>>) -> usize {
-    let mut max_scenery = 0;
-    for i in 1..(height_map.len() - 1) {
-        for j in 1..(height_map[0].len() - 1) {
-            max_scenery = max(max_scenery, calculate_scenary_score(i, j, height_map));
-        }
-    }
-    max_scenery
-}
+struct Solver;
 
-fn calculate_scenary_score(i: usize, j: usize, height_map: &[Vec<u8>]) -> usize {
-    let tree = height_map[i][j];
-    let mut up = 0;
-    for k in (0..i).rev() {
-        up = i - k;
-        if height_map[k][j] >= tree {
-            break;
-        }
-    }
+impl Solution for Solver {
+    type Parsed<'a> = Grid<u8>;
+    type AnswerA = u32;
+    type AnswerB = usize;
 
-    let mut down = 0;
-    for (k, tree_line) in height_map.iter().enumerate().skip(i + 1) {
-        down = k - i;
-        if tree_line[j] >= tree {
-            break;
-        }
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
     }
 
-    let mut left = 0;
-    let tree_line = &height_map[i];
-    for k in (0..j).rev() {
-        left = j - k;
-        if tree_line[k] >= tree {
-            break;
-        }
+    fn part_a(height_map: &Self::Parsed<'_>) -> u32 {
+        solve_part_1(height_map)
     }
 
-    let mut right = 0;
-    let tree_line = &height_map[i];
-    for (k, tree_view) in tree_line
-        .iter()
-        .enumerate()
-        .take(height_map[i].len())
-        .skip(j + 1)
-    {
-        right = k - j;
-        if tree_view >= &tree {
-            break;
-        }
+    fn part_b(height_map: &Self::Parsed<'_>) -> usize {
+        solve_part_2(height_map)
     }
-    up * left * down * right
 }
 
-fn parse_input(input_str: &str) -> Vec<Vec<u8>> {
-    input_str
-        .trim()
-        .split('\n')
-        .map(|line| {
-            line.chars()
-                .map(|tree| tree.to_digit(10).unwrap().try_into().unwrap())
-                .collect_vec()
-        })
-        .collect_vec()
+fn solve_part_1(height_map: &Grid<u8>) -> u32 {
+    let visibility_map = create_visibility_map(height_map);
+    visibility_map.cells().iter().filter(|&&vis| vis).count() as u32
 }
 
-fn create_visibility_map_lr(height_map: &Vec<Vec<u8>>) -> Vec<Vec<bool>> {
-    let mut left_vis = Vec::with_capacity(height_map.len());
-    let mut right_vis = Vec::with_capacity(height_map.len());
-    for tree_line in height_map {
-        let mut line_vis = Vec::<bool>::with_capacity(tree_line.len());
-        let mut max_height = 0;
-        for tree in tree_line {
-            if tree >= &max_height {
-                line_vis.push(true);
-                max_height = tree + 1;
-            } else {
-                line_vis.push(false);
-            }
-        }
-        left_vis.push(line_vis);
-
-        let mut line_vis = Vec::<bool>::with_capacity(tree_line.len());
-        max_height = 0;
-        for tree in tree_line.iter().rev() {
-            if tree >= &max_height {
-                line_vis.push(true);
-                max_height = tree + 1;
-            } else {
-                line_vis.push(false);
-            }
+fn solve_part_2(height_map: &Grid<u8>) -> usize {
+    let mut max_scenery = 0;
+    for i in 1..(height_map.height() - 1) {
+        for j in 1..(height_map.width() - 1) {
+            max_scenery = max(max_scenery, calculate_scenary_score(i, j, height_map));
         }
-        line_vis.reverse();
-        right_vis.push(line_vis);
     }
-    combine_visibility_map(left_vis, right_vis)
+    max_scenery
 }
 
-fn combine_visibility_map(left: Vec<Vec<bool>>, right: Vec<Vec<bool>>) -> Vec<Vec<bool>> {
-    left.into_iter()
-        .zip(right)
-        .map(|(left, right)| {
-            left.into_iter()
-                .zip(right)
-                .map(|(left, right)| left || right)
-                .collect_vec()
+fn calculate_scenary_score(i: usize, j: usize, height_map: &Grid<u8>) -> usize {
+    let tree = height_map[(j, i)];
+    ORTHOGONAL
+        .into_iter()
+        .map(|direction| {
+            let mut pos = (j, i);
+            let mut seen = 0;
+            while let Some(next) = height_map.neighbour(pos, direction) {
+                seen += 1;
+                if height_map[next] >= tree {
+                    break;
+                }
+                pos = next;
+            }
+            seen
         })
-        .collect_vec()
+        .product()
 }
 
-fn create_visibility_map_v(height_map: &Vec<Vec<u8>>) -> Vec<Vec<bool>> {
-    let mut top: Vec<Vec<bool>> = Vec::with_capacity(height_map.len());
-    top.resize_with(height_map.len(), || {
-        let mut line = Vec::with_capacity(height_map[0].len());
-        line.resize(height_map[0].len(), false);
-        line
-    });
-    let mut bot: Vec<Vec<bool>> = Vec::with_capacity(height_map.len());
-    bot.resize_with(height_map.len(), || {
-        let mut line = Vec::with_capacity(height_map[0].len());
-        line.resize(height_map[0].len(), false);
-        line
-    });
+fn parse_input(input_str: &str) -> Grid<u8> {
+    Grid::parse_with(input_str, |tree| {
+        tree.to_digit(10)
+            .map(|height| height as u8)
+            .ok_or(ParseError::unexpected_char(tree))
+    })
+    .unwrap()
+}
 
-    for j in 0..height_map.get(0).unwrap().len() {
-        let mut max_height = 0;
-        for i in 0..height_map.len() {
-            let tree = height_map[i][j];
-            if tree >= max_height {
-                top[i][j] = true;
-                max_height = tree + 1;
-            } else {
-                top[i][j] = false;
-            }
+/// Marks the trees along `line` that are taller than all before them.
+fn mark_visible(height_map: &Grid<u8>, line: impl Iterator<Item = Pos>, vis: &mut Grid<bool>) {
+    let mut max_height = 0;
+    for pos in line {
+        let tree = height_map[pos];
+        if tree >= max_height {
+            vis[pos] = true;
+            max_height = tree + 1;
         }
+    }
+}
 
-        max_height = 0;
-        for i in (0..height_map.len()).rev() {
-            let tree = height_map[i][j];
-            if tree >= max_height {
-                bot[i][j] = true;
-                max_height = tree + 1;
-            } else {
-                bot[i][j] = false;
-            }
-        }
+fn create_visibility_map_lr(height_map: &Grid<u8>) -> Grid<bool> {
+    let (width, height) = (height_map.width(), height_map.height());
+    let mut vis = Grid::new(width, height, false);
+    for y in 0..height {
+        mark_visible(height_map, (0..width).map(|x| (x, y)), &mut vis);
+        mark_visible(height_map, (0..width).rev().map(|x| (x, y)), &mut vis);
     }
-    combine_visibility_map(top, bot)
+    vis
 }
 
-fn create_visibility_map(height_map: &Vec<Vec<u8>>) -> Vec<Vec<bool>> {
-    combine_visibility_map(
-        create_visibility_map_lr(height_map),
-        create_visibility_map_v(height_map),
-    )
+fn create_visibility_map_v(height_map: &Grid<u8>) -> Grid<bool> {
+    let (width, height) = (height_map.width(), height_map.height());
+    let mut vis = Grid::new(width, height, false);
+    for x in 0..width {
+        mark_visible(height_map, (0..height).map(|y| (x, y)), &mut vis);
+        mark_visible(height_map, (0..height).rev().map(|y| (x, y)), &mut vis);
+    }
+    vis
+}
+
+fn create_visibility_map(height_map: &Grid<u8>) -> Grid<bool> {
+    let lr = create_visibility_map_lr(height_map);
+    let v = create_visibility_map_v(height_map);
+    Grid::from_fn(height_map.width(), height_map.height(), |pos| {
+        lr[pos] || v[pos]
+    })
 }
 
 #[cfg(test)]
@@ -182,13 +123,14 @@ mod tests {
     fn test_parse_input(#[case] input_str: &str) {
         assert_eq!(
             parse_input(input_str),
-            vec![
+            Grid::from_rows(vec![
                 vec![3, 0, 3, 7, 3],
                 vec![2, 5, 5, 1, 2],
                 vec![6, 5, 3, 3, 2],
                 vec![3, 3, 5, 4, 9],
                 vec![3, 5, 3, 9, 0],
-            ]
+            ])
+            .unwrap()
         )
     }
 
@@ -220,39 +162,29 @@ mod tests {
 
     #[rstest]
     fn test_create_visibility_map() {
-        let example_input = vec![
-            vec![3, 0, 3, 7, 3],
-            vec![2, 5, 5, 1, 2],
-            vec![6, 5, 3, 3, 2],
-            vec![3, 3, 5, 4, 9],
-            vec![3, 5, 3, 9, 0],
-        ];
-        let expected_visibility = vec![
+        let example_input = parse_input("30373\n25512\n65332\n33549\n35390\n");
+        let expected_visibility = Grid::from_rows(vec![
             vec![true, true, true, true, true],
             vec![true, true, true, false, true],
             vec![true, true, false, true, true],
             vec![true, false, true, false, true],
             vec![true, true, true, true, true],
-        ];
+        ])
+        .unwrap();
         assert_eq!(create_visibility_map(&example_input), expected_visibility);
     }
 
     #[rstest]
     fn test_create_visibility_map_lr() {
-        let example_input = vec![
-            vec![3, 0, 3, 7, 3],
-            vec![2, 5, 5, 1, 2],
-            vec![6, 5, 3, 3, 2],
-            vec![3, 3, 5, 4, 9],
-            vec![3, 5, 3, 9, 0],
-        ];
-        let expected_visibility = vec![
+        let example_input = parse_input("30373\n25512\n65332\n33549\n35390\n");
+        let expected_visibility = Grid::from_rows(vec![
             vec![true, false, false, true, true],
             vec![true, true, true, false, true],
             vec![true, true, false, true, true],
             vec![true, false, true, false, true],
             vec![true, true, false, true, true],
-        ];
+        ])
+        .unwrap();
         assert_eq!(
             create_visibility_map_lr(&example_input),
             expected_visibility
@@ -261,20 +193,15 @@ mod tests {
 
     #[rstest]
     fn test_create_visibility_map_v() {
-        let example_input = vec![
-            vec![3, 0, 3, 7, 3],
-            vec![2, 5, 5, 1, 2],
-            vec![6, 5, 3, 3, 2],
-            vec![3, 3, 5, 4, 9],
-            vec![3, 5, 3, 9, 0],
-        ];
-        let expected_visibility = vec![
+        let example_input = parse_input("30373\n25512\n65332\n33549\n35390\n");
+        let expected_visibility = Grid::from_rows(vec![
             vec![true, true, true, true, true],
             vec![false, true, true, false, false],
             vec![true, false, false, false, false],
             vec![false, false, true, false, true],
             vec![true, true, true, true, true],
-        ];
+        ])
+        .unwrap();
         assert_eq!(create_visibility_map_v(&example_input), expected_visibility);
     }
 }
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::{
     cmp::{max, min},
     collections::HashSet,
@@ -12,6 +13,28 @@ pub fn solve(input: &str) -> (usize, usize) {
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(9, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Movement>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a(movements: &Self::Parsed<'_>) -> usize {
+        solve_part_1(movements)
+    }
+
+    fn part_b(movements: &Self::Parsed<'_>) -> usize {
+        solve_part_2(movements)
+    }
+}
+
 fn solve_part_1(movements: &Vec<Movement>) -> usize {
     let rope = vec![(0, 0); 2];
     simulate_movement(rope, movements).len()
@@ -159,7 +182,7 @@ mod tests {
 
     #[fixture]
     fn actual_input() -> String {
-        read_day_input("day_09")
+        read_day_input("09")
     }
 
     #[fixture]
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::{num::ParseIntError, str::FromStr};
 
 pub fn solve(input: &str) -> (i32, String) {
@@ -8,6 +9,28 @@ pub fn solve(input: &str) -> (i32, String) {
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(10, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Cpu;
+    type AnswerA = i32;
+    type AnswerB = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Cpu::default().apply_many(parse_input(input))
+    }
+
+    fn part_a(cpu: &Self::Parsed<'_>) -> i32 {
+        solve_part_1(cpu)
+    }
+
+    fn part_b(cpu: &Self::Parsed<'_>) -> String {
+        solve_part_2(cpu)
+    }
+}
+
 fn parse_input(input_str: &str) -> Vec<Op> {
     input_str
         .split('\n')
@@ -88,8 +111,7 @@ impl Cpu {
         let (_, register) = self
             .states
             .iter()
-            .filter(|(state_, _)| state_ < &state)
-            .last()
+            .rfind(|(state_, _)| state_ < &state)
             .unwrap();
         *register
     }
@@ -130,14 +152,14 @@ impl Op {
 
 #[cfg(test)]
 mod tests {
-    use crate::days::read_day_input;
+    use crate::days::read_test_day_input;
 
     use super::*;
     use rstest::*;
 
     #[fixture]
     fn test_input_str() -> String {
-        read_day_input("test_day_10")
+        read_test_day_input("10")
     }
 
     #[fixture]
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::{collections::VecDeque, str::FromStr, string::ParseError};
 
 use itertools::Itertools;
@@ -9,6 +10,28 @@ pub fn solve(input: &str) -> (usize, usize) {
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(11, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> usize {
+        solve_part_1(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> usize {
+        solve_part_2(input)
+    }
+}
+
 fn parse_input(input: &str) -> Vec<Monkey> {
     input
         .trim()
@@ -44,12 +67,12 @@ fn solve_part_2(input_str: &str) -> usize {
                     let wl = monkeys[i].operation.new_wory_level(item);
                     let wl = wl % modulo;
                     monkeys[i].inspected += 1;
-                    if wl % monkeys[i].div_check == 0 {
+                    if wl.is_multiple_of(monkeys[i].div_check) {
                         let j = monkeys[i].true_monkey;
-                        monkeys[j as usize].items.push_back(wl);
+                        monkeys[j].items.push_back(wl);
                     } else {
                         let j = monkeys[i].false_monkey;
-                        monkeys[j as usize].items.push_back(wl);
+                        monkeys[j].items.push_back(wl);
                     };
                 }
             }
@@ -72,12 +95,12 @@ fn do_round(mut monkeys: Vec<Monkey>) -> Vec<Monkey> {
             let wl = monkeys[i].operation.new_wory_level(item);
             let wl = wl / 3;
             monkeys[i].inspected += 1;
-            if wl % monkeys[i].div_check == 0 {
+            if wl.is_multiple_of(monkeys[i].div_check) {
                 let j = monkeys[i].true_monkey;
-                monkeys[j as usize].items.push_back(wl);
+                monkeys[j].items.push_back(wl);
             } else {
                 let j = monkeys[i].false_monkey;
-                monkeys[j as usize].items.push_back(wl);
+                monkeys[j].items.push_back(wl);
             };
         }
     }
@@ -201,7 +224,7 @@ impl FromStr for Monkey {
 
 #[cfg(test)]
 mod tests {
-    use crate::days::read_day_input;
+    use crate::days::read_test_day_input;
 
     use super::*;
     use rstest::*;
@@ -222,7 +245,7 @@ Monkey {
 
     #[fixture]
     fn test_input() -> String {
-        read_day_input("test_day_11")
+        read_test_day_input("11")
     }
 
     #[fixture]
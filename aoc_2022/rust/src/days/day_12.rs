@@ -1,3 +1,4 @@
+use aoc_common::{bfs, Day, Solution, ORTHOGONAL};
 use std::cmp::min;
 
 type MapObjective = (Vec<Vec<u32>>, (usize, usize), (usize, usize));
@@ -10,6 +11,28 @@ pub fn solve(input: &str) -> (u32, u32) {
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(12, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = MapObjective;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a((height_map, start, end): &Self::Parsed<'_>) -> u32 {
+        solve_part_1(&convert_to_distance_map(height_map, end), start)
+    }
+
+    fn part_b((height_map, _, end): &Self::Parsed<'_>) -> u32 {
+        solve_part_2(&convert_to_distance_map(height_map, end), height_map)
+    }
+}
+
 fn solve_part_1(distance_map: &[Vec<u32>], start: &(usize, usize)) -> u32 {
     distance_map[start.0][start.1]
 }
@@ -38,65 +61,42 @@ fn parse_input(input_str: &str) -> MapObjective {
         .split('\n')
         .enumerate()
         .fold((0, 0), |loc, (i, line)| {
-            line.chars().enumerate().fold(loc, |loc, (j, c)| {
-                if c == 'S' {
-                    (i as usize, j as usize)
-                } else {
-                    loc
-                }
-            })
+            line.chars()
+                .enumerate()
+                .fold(loc, |loc, (j, c)| if c == 'S' { (i, j) } else { loc })
         });
     let end = input_str
         .split('\n')
         .enumerate()
         .fold((0, 0), |loc, (i, line)| {
-            line.chars().enumerate().fold(loc, |loc, (j, c)| {
-                if c == 'E' {
-                    (i as usize, j as usize)
-                } else {
-                    loc
-                }
-            })
+            line.chars()
+                .enumerate()
+                .fold(loc, |loc, (j, c)| if c == 'E' { (i, j) } else { loc })
         });
     map[start.0][start.1] = 0;
     map[end.0][end.1] = 25;
     (map, start, end)
 }
 
-fn convert_to_distance_map(map: &Vec<Vec<u32>>, end: &(usize, usize)) -> Vec<Vec<u32>> {
-    let mut distance_map = vec![vec![u32::MAX - 1; map[0].len()]; map.len()];
-    distance_map[end.0][end.1] = 0;
-    let mut changed = true;
-    while changed {
-        changed = false;
-        for i in 0..map.len() {
-            for j in 0..map[0].len() {
-                let h = map[i][j];
-                let mut shortest_distance = u32::MAX;
-                if 0 < i && h + 2 > map[i - 1][j] {
-                    shortest_distance = min(shortest_distance, distance_map[i - 1][j] + 1);
-                }
-
-                if (i + 1) < map.len() && h + 2 > map[i + 1][j] {
-                    shortest_distance = min(shortest_distance, distance_map[i + 1][j] + 1);
-                }
-
-                if (j + 1) < map[0].len() && h + 2 > map[i][j + 1] {
-                    shortest_distance = min(shortest_distance, distance_map[i][j + 1] + 1);
-                }
-
-                if 0 < j && h + 2 > map[i][j - 1] {
-                    shortest_distance = min(shortest_distance, distance_map[i][j - 1] + 1);
-                }
-
-                if shortest_distance < distance_map[i][j] {
-                    changed = true;
-                    distance_map[i][j] = shortest_distance
-                }
-            }
-        }
-    }
+/// Fewest steps from every square to `end`, searching backwards from it.
+fn convert_to_distance_map(map: &[Vec<u32>], end: &(usize, usize)) -> Vec<Vec<u32>> {
+    let (height, width) = (map.len(), map[0].len());
+    let search = bfs(
+        [*end],
+        |&(i, j)| {
+            ORTHOGONAL.into_iter().filter_map(move |(di, dj)| {
+                let (ni, nj) = (i.checked_add_signed(di)?, j.checked_add_signed(dj)?);
+                // Going backwards, so the step is from the neighbour to here.
+                (ni < height && nj < width && map[i][j] <= map[ni][nj] + 1).then_some((ni, nj))
+            })
+        },
+        |_| false,
+    );
 
+    let mut distance_map = vec![vec![u32::MAX - 1; width]; height];
+    for (&(i, j), &steps) in search.costs() {
+        distance_map[i][j] = steps as u32;
+    }
     distance_map
 }
 
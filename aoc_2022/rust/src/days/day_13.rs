@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::{str::FromStr, string::ParseError};
 
 use itertools::Itertools;
@@ -10,6 +11,28 @@ pub fn solve(input: &str) -> (usize, usize) {
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(13, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<(Packet, Packet)>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a(pairs: &Self::Parsed<'_>) -> usize {
+        solve_part_1(pairs)
+    }
+
+    fn part_b(pairs: &Self::Parsed<'_>) -> usize {
+        solve_part_2(&mut pairs.clone())
+    }
+}
+
 fn solve_part_1(pairs: &[(Packet, Packet)]) -> usize {
     pairs
         .iter()
@@ -95,14 +118,14 @@ impl Packet {
 
 #[cfg(test)]
 mod tests {
-    use crate::days::read_day_input;
+    use crate::days::read_test_day_input;
 
     use super::*;
     use rstest::*;
 
     #[fixture]
     fn example_input_str() -> String {
-        read_day_input("test_day_13")
+        read_test_day_input("13")
     }
 
     #[fixture]
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::{
     cmp::{max, min},
     collections::HashSet,
@@ -10,6 +11,28 @@ pub fn solve(input: &str) -> (u16, u16) {
     solve_combo(rocks)
 }
 
+pub const DAY: Day = Day::new(14, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = HashSet<(u16, u16)>;
+    type AnswerA = u16;
+    type AnswerB = u16;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_rocks(input)
+    }
+
+    fn part_a(rocks: &Self::Parsed<'_>) -> u16 {
+        solve_combo(rocks.clone()).0
+    }
+
+    fn part_b(rocks: &Self::Parsed<'_>) -> u16 {
+        solve_combo(rocks.clone()).1
+    }
+}
+
 fn solve_combo(mut rocks: HashSet<(u16, u16)>) -> (u16, u16) {
     let mut hit_floor = false;
     let initial_spawn = (500, 0);
@@ -62,8 +85,10 @@ fn parse_rock(input_str: &str) -> HashSet<(u16, u16)> {
         .map(|rock_str| {
             let (x, y) = rock_str.split_once(',').unwrap();
             (
-                x.parse::<u16>().expect(&format!("{x} should be parsable")),
-                y.parse::<u16>().expect(&format!("{y} should be parsable")),
+                x.parse::<u16>()
+                    .unwrap_or_else(|_| panic!("{x} should be parsable")),
+                y.parse::<u16>()
+                    .unwrap_or_else(|_| panic!("{y} should be parsable")),
             )
         })
         .tuple_windows()
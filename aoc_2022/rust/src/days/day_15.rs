@@ -1,25 +1,49 @@
-use std::{collections::HashSet, num::ParseIntError, str::FromStr, vec};
+use aoc_common::{Day, IntervalSet, ParseError, Solution};
+use std::{str::FromStr, vec};
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
 pub fn solve(input: &str) -> (usize, u64) {
-    let sensors = parse_input(input);
+    let sensors = parse_input(input).unwrap();
     let part_1 = solve_part_1(&sensors, 2000000);
     let part_2 = solve_part_2(&sensors, 4000000);
     (part_1, part_2)
 }
 
-fn solve_part_1(sensors: &Vec<Sensor>, row: i64) -> usize {
+pub const DAY: Day = Day::checked(15, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Sensor>;
+    type AnswerA = usize;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(sensors: &Self::Parsed<'_>) -> usize {
+        solve_part_1(sensors, 2000000)
+    }
+
+    fn part_b(sensors: &Self::Parsed<'_>) -> u64 {
+        solve_part_2(sensors, 4000000)
+    }
+}
+
+fn solve_part_1(sensors: &[Sensor], row: i64) -> usize {
     sensors
         .iter()
-        .map(|s| s.get_excluded_region_at_y(row))
-        .reduce(|mut collector, s| {
-            collector.extend(s);
-            collector
+        .fold(IntervalSet::new(), |excluded, s| {
+            excluded.union(&s.get_excluded_region_at_y(row))
         })
-        .unwrap_or_default()
-        .len()
+        .len() as usize
 }
 
 fn solve_part_2(sensors: &Vec<Sensor>, max_row: i64) -> u64 {
@@ -54,13 +78,8 @@ fn manhattan_distance(left: &(i64, i64), right: &(i64, i64)) -> u64 {
     left.0.abs_diff(right.0) + left.1.abs_diff(right.1)
 }
 
-fn parse_input(input_str: &str) -> Vec<Sensor> {
-    input_str
-        .trim()
-        .split('\n')
-        .map(Sensor::from_str)
-        .collect::<Result<_, _>>()
-        .unwrap()
+fn parse_input(input_str: &str) -> Result<Vec<Sensor>, ParseError> {
+    input_str.trim().split('\n').map(Sensor::from_str).collect()
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -71,15 +90,15 @@ struct Sensor {
 
 impl Sensor {
     fn can_contain_unknown_beacon(&self, loc: &(i64, i64)) -> bool {
-        manhattan_distance(&self.loc, &self.beacon) < manhattan_distance(&self.loc, &loc)
+        manhattan_distance(&self.loc, &self.beacon) < manhattan_distance(&self.loc, loc)
     }
 
-    fn get_excluded_region_at_y(&self, row: i64) -> HashSet<i64> {
+    fn get_excluded_region_at_y(&self, row: i64) -> IntervalSet<i64> {
         let exclusion_range = self.range() as i64;
         let dist_to_row = self.loc.1.abs_diff(row) as i64;
         let diff = exclusion_range - dist_to_row;
         if diff <= 0 {
-            return HashSet::new();
+            return IntervalSet::new();
         }
         let mut start = -diff + self.loc.0;
         let mut end = diff + self.loc.0;
@@ -90,7 +109,7 @@ impl Sensor {
         if end == self.beacon.0 {
             end -= 1;
         }
-        HashSet::from_iter(start..=end)
+        IntervalSet::from(start..=end)
     }
 
     fn range(&self) -> u64 {
@@ -111,7 +130,7 @@ impl Sensor {
 }
 
 impl FromStr for Sensor {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         lazy_static! {
@@ -120,38 +139,13 @@ impl FromStr for Sensor {
             )
             .unwrap();
         }
-        let captures = RE.captures(s).unwrap();
-        let _own_loc = (
-            captures
-                .get(1)
-                .expect(&format!("Could not parse {s}"))
-                .as_str()
-                .parse()
-                .expect(&format!("Could not parse {s}")),
-            captures
-                .get(2)
-                .expect(&format!("Could not parse {s}"))
-                .as_str()
-                .parse()
-                .expect(&format!("Could not parse {s}")),
-        );
-        let _beacon_loc = (
-            captures
-                .get(3)
-                .expect(&format!("Could not parse {s}"))
-                .as_str()
-                .parse()
-                .expect(&format!("Could not parse {s}")),
-            captures
-                .get(4)
-                .expect(&format!("Could not parse {s}"))
-                .as_str()
-                .parse()
-                .expect(&format!("Could not parse {s}")),
-        );
+        let captures = RE
+            .captures(s)
+            .ok_or_else(|| ParseError::new(format!("Could not parse {s}")))?;
+        let number = |i: usize| captures[i].parse::<i64>();
         Ok(Sensor {
-            loc: _own_loc,
-            beacon: _beacon_loc,
+            loc: (number(1)?, number(2)?),
+            beacon: (number(3)?, number(4)?),
         })
     }
 }
@@ -189,17 +183,17 @@ mod tests {
 
     #[fixture]
     fn example_sensors(example_input_str: String) -> Vec<Sensor> {
-        parse_input(&example_input_str)
+        parse_input(&example_input_str).unwrap()
     }
 
     #[fixture]
     fn actual_input_str() -> String {
-        read_day_input("day_15")
+        read_day_input("15")
     }
 
     #[fixture]
     fn actual_sensors(actual_input_str: String) -> Vec<Sensor> {
-        parse_input(&actual_input_str)
+        parse_input(&actual_input_str).unwrap()
     }
     #[rstest]
     fn test_solve_part_1(example_sensors: Vec<Sensor>) {
@@ -207,7 +201,6 @@ mod tests {
     }
 
     #[rstest]
-    #[ignore]
     fn test_solve_part_1_actual(actual_sensors: Vec<Sensor>) {
         assert_eq!(solve_part_1(&actual_sensors, 2000000), 5125700);
     }
@@ -222,16 +215,19 @@ mod tests {
         #[case] row: i64,
         #[case] len_free: usize,
     ) {
-        assert_eq!(sensor.get_excluded_region_at_y(row).len(), len_free)
+        assert_eq!(
+            sensor.get_excluded_region_at_y(row).len() as usize,
+            len_free
+        )
     }
 
     #[rstest]
-    #[case(Sensor { loc: (8, 7), beacon: (2, 10)}, 0, HashSet::from([6, 7, 8, 9, 10]))]
-    #[case(Sensor { loc: (8, 7), beacon: (2, 10)}, -1, HashSet::from([7, 8, 9]))]
+    #[case(Sensor { loc: (8, 7), beacon: (2, 10)}, 0, IntervalSet::from(6..=10))]
+    #[case(Sensor { loc: (8, 7), beacon: (2, 10)}, -1, IntervalSet::from(7..=9))]
     fn test_get_excluded_region_at_y_set(
         #[case] sensor: Sensor,
         #[case] row: i64,
-        #[case] set: HashSet<i64>,
+        #[case] set: IntervalSet<i64>,
     ) {
         assert_eq!(sensor.get_excluded_region_at_y(row), set)
     }
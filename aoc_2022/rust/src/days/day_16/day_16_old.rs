@@ -3,13 +3,6 @@ use std::collections::{HashMap, HashSet};
 use lazy_static::lazy_static;
 use regex::Regex;
 
-pub fn solve(input: &str) -> (u32, u32) {
-    let valves = parse_input(input);
-    let part_1 = solve_part_1(&valves, 30);
-    let part_2 = solve_part_2(&valves, 25);
-    (part_1, part_2)
-}
-
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Valve<'a> {
     name: &'a str,
@@ -17,7 +10,7 @@ pub struct Valve<'a> {
     connects_to: Vec<&'a str>,
 }
 
-pub fn parse_input(input: &str) -> Vec<Valve> {
+pub fn parse_input(input: &str) -> Vec<Valve<'_>> {
     input.trim_end().split("\n").map(Valve::from_str).collect()
 }
 
@@ -30,7 +23,7 @@ impl Valve<'_> {
         }
     }
 
-    fn from_str<'a>(s: &str) -> Valve {
+    fn from_str(s: &str) -> Valve<'_> {
         lazy_static! {
             static ref RE_VALVE: Regex = Regex::new(r"(-?[A-Z]{2})").unwrap();
             static ref RE_FR: Regex = Regex::new(r"(-?\d+)").unwrap();
@@ -69,7 +62,7 @@ fn recursively_open_valves(
             closed_valves.remove(&(closed_valve, flow_rate));
             let remaining = minutes_remaining - distance;
             let released = recursively_open_valves(
-                &closed_valve,
+                closed_valve,
                 closed_valves,
                 distances,
                 remaining,
@@ -118,11 +111,11 @@ fn find_shortest_path(src: &str, target: &str, graph: &HashMap<&str, &Vec<&str>>
         reachable = reachable
             .into_iter()
             .flat_map(|valve| *graph.get(valve).unwrap())
-            .map(|s| *s)
+            .copied()
             .collect();
         length += 1;
     }
-    return length;
+    length
 }
 
 pub fn solve_part_2<'a>(valves: &'a Vec<Valve<'a>>, minutes_remaining: u32) -> u32 {
@@ -133,7 +126,7 @@ pub fn solve_part_2<'a>(valves: &'a Vec<Valve<'a>>, minutes_remaining: u32) -> u
         .map(|v| (v.name, &v.flow_rate))
         .collect();
 
-    let mut initial_state = State::new(
+    let initial_state = State::new(
         "AA",
         0,
         "AA",
@@ -144,7 +137,7 @@ pub fn solve_part_2<'a>(valves: &'a Vec<Valve<'a>>, minutes_remaining: u32) -> u
         closed_valves,
     );
 
-    let sol = recursively_open_valves_part_2(&mut initial_state, 0);
+    let sol = recursively_open_valves_part_2(&initial_state);
     sol.max(23) - 23
 }
 
@@ -161,6 +154,7 @@ struct State<'a> {
 }
 
 impl<'a> State<'a> {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         hum_goal: &'a str,
         distance_to_hum_goal: u32,
@@ -184,7 +178,7 @@ impl<'a> State<'a> {
         state
     }
 
-    fn generate_next_states<'b>(self: &'b Self) -> Vec<State<'b>> {
+    fn generate_next_states<'b>(&'b self) -> Vec<State<'b>> {
         let mut next_states = vec![];
         if self.distance_to_hum_goal == 0 {
             // Open valve and find new goal
@@ -223,7 +217,7 @@ impl<'a> State<'a> {
                 next_states.push(State::new(
                     self.hum_goal,
                     self.distance_to_hum_goal,
-                    &new_target,
+                    new_target,
                     *distance,
                     self.total_preasure_released + (time_new_valve_open * *flow_rate),
                     self.minutes_remaining,
@@ -253,11 +247,11 @@ impl<'a> State<'a> {
     }
 }
 
-fn recursively_open_valves_part_2<'a>(state: &'a State, i: u32) -> u32 {
+fn recursively_open_valves_part_2(state: &State) -> u32 {
     let mut max = state.total_preasure_released;
     let mut best_path = vec![];
     for next_state in state.generate_next_states() {
-        let other = recursively_open_valves_part_2(&next_state.clone(), i + 1);
+        let other = recursively_open_valves_part_2(&next_state.clone());
         if other > max {
             max = other;
         }
@@ -269,13 +263,13 @@ fn recursively_open_valves_part_2<'a>(state: &'a State, i: u32) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::days::read_day_input;
+    use crate::days::read_test_day_input;
 
     use rstest::*;
 
     #[fixture]
     fn example_input_str() -> &'static str {
-        Box::leak(read_day_input("test_day_16").into_boxed_str())
+        Box::leak(read_test_day_input("16").into_boxed_str())
     }
 
     #[fixture]
@@ -1,8 +1,12 @@
 mod day_16_old;
+#[allow(dead_code)]
 mod network;
+#[allow(dead_code)]
 mod parse;
+#[allow(dead_code)]
 mod valve;
-use day_16_old::{parse_input, solve_part_1, solve_part_2};
+use aoc_common::{Day, Solution};
+use day_16_old::{parse_input, solve_part_1, solve_part_2, Valve};
 
 pub fn solve(input: &str) -> (u32, u32) {
     let valves = parse_input(input);
@@ -11,17 +15,38 @@ pub fn solve(input: &str) -> (u32, u32) {
     (part_1, part_2)
 }
 
+pub const DAY: Day = Day::new(16, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Valve<'a>>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a(valves: &Self::Parsed<'_>) -> u32 {
+        solve_part_1(valves, 30)
+    }
+
+    fn part_b(valves: &Self::Parsed<'_>) -> u32 {
+        solve_part_2(valves, 25)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::day_16_old::Valve;
     use super::*;
-    use crate::days::read_day_input;
+    use crate::days::read_test_day_input;
 
     use rstest::*;
 
     #[fixture]
     fn example_input_str() -> &'static str {
-        Box::leak(read_day_input("test_day_16").into_boxed_str())
+        Box::leak(read_test_day_input("16").into_boxed_str())
     }
 
     #[fixture]
@@ -40,18 +40,18 @@ impl Network {
         }
     }
 
-    fn solve_part_1(self: Self) -> u16 {
+    fn solve_part_1(self) -> u16 {
         let mut closed_valves = self
             .valves
             .iter()
             .filter(|(_, valve)| valve.flow_rate != 0)
-            .map(|(name, _)| name.clone())
+            .map(|(name, _)| *name)
             .collect::<HashSet<Name>>();
         self.recursive_solve_part_1("AA".into(), 30, &mut closed_valves)
     }
 
     fn recursive_solve_part_1(
-        self: &Self,
+        &self,
         cur_location: Name,
         minutes_left: u16,
         closed_valves: &mut HashSet<Name>,
@@ -64,8 +64,9 @@ impl Network {
             }
             closed_valves.remove(&valve);
 
+            // Walking there takes `distance` minutes and opening it one more.
             let valve_flow_rate =
-                self.valves.get(&valve).unwrap().flow_rate * (minutes_left - distance);
+                self.valves.get(&valve).unwrap().flow_rate * (minutes_left - distance - 1);
 
             let rest_flow_rate =
                 self.recursive_solve_part_1(valve, minutes_left - (distance + 1), closed_valves);
@@ -82,7 +83,7 @@ impl Network {
     }
 }
 
-fn calculate_distance_matrix<'a>(valves: &HashMap<Name, Valve>) -> HashMap<(Name, Name), u16> {
+fn calculate_distance_matrix(valves: &HashMap<Name, Valve>) -> HashMap<(Name, Name), u16> {
     let non_broken_valves: Vec<Valve> = valves
         .iter()
         .filter(|(_, valve)| valve.flow_rate != 0)
@@ -93,7 +94,7 @@ fn calculate_distance_matrix<'a>(valves: &HashMap<Name, Valve>) -> HashMap<(Name
         for target in &non_broken_valves {
             distances.insert(
                 (src.name, target.name),
-                find_shortest_path(src.name, target.name, &valves) + 1,
+                find_shortest_path(src.name, target.name, valves),
             );
         }
     }
@@ -101,7 +102,7 @@ fn calculate_distance_matrix<'a>(valves: &HashMap<Name, Valve>) -> HashMap<(Name
     for target in &non_broken_valves {
         distances.insert(
             ("AA".into(), target.name),
-            find_shortest_path("AA".into(), target.name, &valves),
+            find_shortest_path("AA".into(), target.name, valves),
         );
     }
 
@@ -115,16 +116,16 @@ fn find_shortest_path(src: Name, target: Name, graph: &HashMap<Name, Valve>) ->
         reachable = reachable
             .iter()
             .flat_map(|valve| graph.get(valve).unwrap().connects_to())
-            .map(|s| *s)
+            .copied()
             .collect();
         length += 1;
     }
-    return length;
+    length
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::days::read_day_input;
+    use crate::days::{read_day_input, read_test_day_input};
 
     use super::*;
 
@@ -132,7 +133,7 @@ mod tests {
 
     #[fixture]
     fn example_input_str() -> &'static str {
-        Box::leak(read_day_input("test_day_16").into_boxed_str())
+        Box::leak(read_test_day_input("16").into_boxed_str())
     }
 
     #[fixture]
@@ -147,7 +148,7 @@ mod tests {
 
     #[fixture]
     fn input_str() -> &'static str {
-        Box::leak(read_day_input("day_16").into_boxed_str())
+        Box::leak(read_day_input("16").into_boxed_str())
     }
 
     #[fixture]
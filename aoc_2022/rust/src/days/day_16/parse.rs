@@ -1,4 +1,4 @@
-use super::valve::{Name, Valve};
+use super::valve::Valve;
 
 pub fn parse_input(input: &str) -> Vec<Valve> {
     input.trim_end().split("\n").map(Valve::from_str).collect()
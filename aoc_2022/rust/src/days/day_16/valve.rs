@@ -1,5 +1,3 @@
-use std::str::FromStr;
-
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -14,7 +12,7 @@ pub struct Valve {
 pub struct Name(u8, u8);
 
 impl Valve {
-    pub fn new<'a>(name: Name, flow_rate: u16, connects_to: Vec<Name>) -> Valve {
+    pub fn new(name: Name, flow_rate: u16, connects_to: Vec<Name>) -> Valve {
         Valve {
             name,
             flow_rate,
@@ -37,7 +35,7 @@ impl Valve {
         Valve::new(name[0].into(), flow_rate, connects_to)
     }
 
-    pub fn connects_to<'a>(&'a self) -> &'a Vec<Name> {
+    pub fn connects_to(&self) -> &Vec<Name> {
         &self.connects_to
     }
 }
@@ -60,9 +58,9 @@ impl From<u16> for Name {
     }
 }
 
-impl Into<u16> for Name {
-    fn into(self) -> u16 {
-        self.0 as u16 + (self.1 as u16 * 26)
+impl From<Name> for u16 {
+    fn from(val: Name) -> Self {
+        val.0 as u16 + (val.1 as u16 * 26)
     }
 }
 
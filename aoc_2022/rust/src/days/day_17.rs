@@ -1,55 +1,179 @@
-use std::iter::repeat;
-use std::{str::FromStr, string::ParseError};
+use aoc_common::{find_cycle_by_key, Day, ParseError, Solution};
 
-pub fn solve(input: &str) -> (u32, u32) {
-    let jets = parse_input(input);
+pub fn solve(input: &str) -> (u64, u64) {
+    let jets = parse_input(input).unwrap();
     let part_1 = solve_part_1(&jets);
-    let part_2 = solve_part_2(input);
+    let part_2 = solve_part_2(&jets);
     (part_1, part_2)
 }
 
-fn solve_part_1(jets: &Vec<Jet>) -> u32 {
+pub const DAY: Day = Day::checked(17, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Jet>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(jets: &Self::Parsed<'_>) -> u64 {
+        solve_part_1(jets)
+    }
+
+    fn part_b(jets: &Self::Parsed<'_>) -> u64 {
+        solve_part_2(jets)
+    }
+}
+
+fn solve_part_1(jets: &[Jet]) -> u64 {
     simulation(jets, 2022)
 }
 
-fn solve_part_2(input_str: &str) -> u32 {
-    0
+fn solve_part_2(jets: &[Jet]) -> u64 {
+    simulation(jets, 1_000_000_000_000)
 }
 
-fn parse_input(input_str: &str) -> Vec<Jet> {
-    input_str
+fn parse_input(input_str: &str) -> Result<Vec<Jet>, ParseError> {
+    let jets: Vec<Jet> = input_str
+        .trim()
         .chars()
-        .map(|c| match c {
-            '<' => Jet::Left,
-            '>' => Jet::Right,
-            _ => unreachable!(),
-        })
-        .collect()
+        .map(Jet::try_from)
+        .collect::<Result<_, _>>()?;
+    if jets.is_empty() {
+        return Err(ParseError::new("no jets"));
+    }
+    Ok(jets)
 }
 
-fn simulation(jets: &Vec<Jet>, num_rounds: usize) -> u32 {
-    let rocks = vec![Rock::Plus, Rock::Minus, Rock::L, Rock::Stick, Rock::Block];
-    let jets_iter = jets.iter().cycle();
-    let mut rows = Vec::new();
-    for rock in rocks.iter().cycle().take(num_rounds) {
-        println!("{:?}", rock);
-        rows.push(rock);
-    }
-
-    // Loop:
-    // Start falling
-    // Jet push
-    // Rock drops 1
-    // Jet push
-    0
+const WIDTH: i64 = 7;
+const FULL_ROW: u8 = (1 << WIDTH) - 1;
+const ROCKS: [Rock; 5] = [Rock::Minus, Rock::Plus, Rock::L, Rock::Stick, Rock::Block];
+
+/// How many rows of the surface a fingerprint starts out with.
+const SURFACE_DEPTH: usize = 32;
+
+/// The height of the tower after `num_rounds` rocks have come to rest.
+///
+/// The chamber repeats once a rock starts at the same rock and jet index
+/// over the same surface, after which whole cycles are skipped. Rows below
+/// where any rock fell can't change how the next one falls, so the surface
+/// is only compared that deep. If a rock fell deeper than the fingerprint
+/// went, the search starts over with a deeper one. A tower with a well that
+/// rocks keep falling further into never repeats, so this doesn't finish.
+fn simulation(jets: &[Jet], num_rounds: usize) -> u64 {
+    let mut depth = SURFACE_DEPTH;
+    loop {
+        let mut chamber = Chamber::new(jets);
+        let initial = (chamber.height(), chamber.fingerprint(depth));
+        let (cycle, states) = find_cycle_by_key(
+            initial,
+            |_| {
+                chamber.drop_rock();
+                (chamber.height(), chamber.fingerprint(depth))
+            },
+            |(_, fingerprint)| fingerprint.clone(),
+        );
+        if chamber.deepest_fall > depth {
+            depth = chamber.deepest_fall.max(2 * depth);
+            continue;
+        }
+
+        let height = |round: usize| states[round].0 as u64;
+        let lap_height = height(cycle.start + cycle.length) - height(cycle.start);
+        return height(cycle.index_after(num_rounds)) + cycle.laps(num_rounds) as u64 * lap_height;
+    }
 }
 
-// Rock order
-// minus-sign
-// plus-sign
-// L-shape
-// i-shape
-// block
+struct Chamber<'a> {
+    /// One bit per column, from the floor up.
+    rows: Vec<u8>,
+    jets: &'a [Jet],
+    next_jet: usize,
+    next_rock: usize,
+    /// The most rows below the top of the tower any rock has looked at, the
+    /// floor included.
+    deepest_fall: usize,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [Jet]) -> Self {
+        Chamber {
+            rows: Vec::new(),
+            jets,
+            next_jet: 0,
+            next_rock: 0,
+            deepest_fall: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn drop_rock(&mut self) {
+        let offsets = ROCKS[self.next_rock].as_offsets();
+        self.next_rock = (self.next_rock + 1) % ROCKS.len();
+
+        let mut pos = (2, self.height() as i64 + 3);
+        loop {
+            let jet = self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+            let pushed = match jet {
+                Jet::Left => (pos.0 - 1, pos.1),
+                Jet::Right => (pos.0 + 1, pos.1),
+            };
+            if self.fits(&offsets, pushed) {
+                pos = pushed;
+            }
+            let fallen = (pos.0, pos.1 - 1);
+            if !self.fits(&offsets, fallen) {
+                break;
+            }
+            pos = fallen;
+        }
+        // The row under the rock is the deepest one it checked.
+        let depth = (self.height() as i64 - (pos.1 - 1)) as usize;
+        self.deepest_fall = self.deepest_fall.max(depth);
+
+        for (dx, dy) in offsets {
+            let y = (pos.1 + dy) as usize;
+            if y >= self.rows.len() {
+                self.rows.resize(y + 1, 0);
+            }
+            self.rows[y] |= 1 << (pos.0 + dx);
+        }
+    }
+
+    fn fits(&self, offsets: &[(i64, i64)], pos: (i64, i64)) -> bool {
+        offsets.iter().all(|(dx, dy)| {
+            let (x, y) = (pos.0 + dx, pos.1 + dy);
+            (0..WIDTH).contains(&x)
+                && y >= 0
+                && self
+                    .rows
+                    .get(y as usize)
+                    .is_none_or(|row| row & (1 << x) == 0)
+        })
+    }
+
+    /// What the next rock will fall into, down to `depth` rows below the
+    /// top, with the floor below the tower counting as full rows.
+    fn fingerprint(&self, depth: usize) -> (usize, usize, Vec<u8>) {
+        let mut surface = vec![FULL_ROW; depth];
+        let top = self.rows.len().saturating_sub(depth);
+        let rows = &self.rows[top..];
+        surface[depth - rows.len()..].copy_from_slice(rows);
+        (self.next_rock, self.next_jet, surface)
+    }
+}
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Jet {
@@ -57,6 +181,18 @@ enum Jet {
     Right,
 }
 
+impl TryFrom<char> for Jet {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, ParseError> {
+        match c {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(ParseError::unexpected_char(c)),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Rock {
     Plus,
@@ -67,13 +203,15 @@ enum Rock {
 }
 
 impl Rock {
-    fn as_offsets(&self) -> Vec<(u32, u32)> {
+    /// The cells of the rock as `(x, y)` from its bottom left corner, with y
+    /// going up.
+    fn as_offsets(&self) -> Vec<(i64, i64)> {
         match self {
-            Rock::Plus => vec![(0, 1), (1, 0), (1, 1), (2, 1), (1, 2)],
-            Rock::Minus => vec![(0, 1), (0, 2), (0, 3), (0, 4)],
-            Rock::L => vec![(0, 1), (0, 2), (0, 3), (0, 4), (0, 5)],
-            Rock::Stick => vec![(0, 0), (1, 0), (2, 0), (3, 0)],
-            Rock::Block => vec![(0, 0), (0, 1), (1, 0), (1, 1)],
+            Rock::Plus => vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            Rock::Minus => vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+            Rock::L => vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            Rock::Stick => vec![(0, 0), (0, 1), (0, 2), (0, 3)],
+            Rock::Block => vec![(0, 0), (1, 0), (0, 1), (1, 1)],
         }
     }
 }
@@ -88,7 +226,14 @@ mod tests {
     #[case(">>", vec![Jet::Right, Jet::Right])]
     #[case("<<", vec![Jet::Left, Jet::Left])]
     fn test_parse_input(#[case] example_input_str: &str, #[case] expected: Vec<Jet>) {
-        assert_eq!(parse_input(example_input_str), expected)
+        assert_eq!(parse_input(example_input_str), Ok(expected))
+    }
+
+    #[rstest]
+    #[case("<x>", ParseError::unexpected_char('x'))]
+    #[case("\n", ParseError::new("no jets"))]
+    fn test_parse_input_invalid(#[case] input_str: &str, #[case] expected: ParseError) {
+        assert_eq!(parse_input(input_str), Err(expected))
     }
 
     #[rstest]
@@ -99,12 +244,45 @@ mod tests {
     #[case(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>", 5, 9)] // Block
     #[case(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>", 6, 10)] // Minus
     #[case(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>", 2022, 3068)] // Given example
+    #[case(
+        ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>",
+        1_000_000_000_000,
+        1514285714288
+    )]
     fn test_simulation(
         #[case] jets_str: &str,
         #[case] rounds: usize,
-        #[case] expected_height: u32,
+        #[case] expected_height: u64,
     ) {
-        let jets = parse_input(jets_str);
+        let jets = parse_input(jets_str).unwrap();
         assert_eq!(simulation(&jets, rounds), expected_height)
     }
+
+    #[rstest]
+    fn test_deepest_fall() {
+        let jets = parse_input(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>").unwrap();
+        let mut chamber = Chamber::new(&jets);
+        chamber.drop_rock();
+        // The minus lands on the floor, which is the row below it.
+        assert_eq!(chamber.deepest_fall, 1);
+        for _ in 0..2022 {
+            chamber.drop_rock();
+        }
+        assert!((4..=SURFACE_DEPTH).contains(&chamber.deepest_fall));
+    }
+
+    #[rstest]
+    #[case(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>")]
+    #[case("<<<<>>><<>")]
+    #[case(">")]
+    // Rocks fall up to 146 rows into this tower
+    #[case("<>><><><><>>>><")]
+    fn test_cycle_skipping_matches_simulating_every_rock(#[case] jets_str: &str) {
+        let jets = parse_input(jets_str).unwrap();
+        let mut chamber = Chamber::new(&jets);
+        for rounds in 1..=1500 {
+            chamber.drop_rock();
+            assert_eq!(simulation(&jets, rounds), chamber.height() as u64);
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use std::fs;
+pub use aoc_common::{read_day_input, read_day_input_if_present, read_test_day_input};
 
 pub mod day_01;
 pub mod day_02;
@@ -16,9 +16,21 @@ pub mod day_13;
 pub mod day_14;
 pub mod day_15;
 pub mod day_16;
-// pub mod day_17;
+pub mod day_17;
 
-pub fn read_day_input(day: &str) -> String {
-    let file_path = format!("inputs/{day}.txt");
-    fs::read_to_string(&file_path).unwrap_or_else(|_| panic!("Could not load: {file_path}"))
+aoc_common::days! {
+    day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11, day_12,
+    day_13, day_14, day_15, day_16, day_17,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_in_day_order() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.day as usize, i + 1);
+        }
+    }
 }
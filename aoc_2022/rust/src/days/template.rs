@@ -1,22 +1,54 @@
+use aoc_common::{Day, Solution};
+
 pub fn solve(input: &str) -> (u32, u32) {
     let part_1 = solve_part_1(input);
     let part_2 = solve_part_2(input);
     (part_1, part_2)
 }
 
-fn solve_part_1(input_str: &str) -> u32 {
+pub const DAY: Day = Day::new(0, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u32 {
+        solve_part_1(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u32 {
+        solve_part_2(input)
+    }
+}
+
+fn solve_part_1(_input_str: &str) -> u32 {
     0
 }
 
-fn solve_part_2(input_str: &str) -> u32 {
+fn solve_part_2(_input_str: &str) -> u32 {
     0
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::days::read_test_day_input;
     use rstest::*;
 
+    #[fixture]
+    fn example_input_str() -> String {
+        read_test_day_input("00")
+    }
+
     #[rstest]
-    fn test_parse_input() {}
+    fn test_solve_part_1(example_input_str: String) {
+        assert_eq!(solve_part_1(&example_input_str), 0)
+    }
 }
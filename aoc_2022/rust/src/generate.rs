@@ -0,0 +1,104 @@
+//! Random inputs for stress testing days beyond their official input.
+
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub static GENERATORS: &[Generator] = &[Generator {
+    day: 15,
+    generate: day_15,
+    unit: "sensors",
+    sizes: &[4, 8, 16, 32],
+}];
+
+/// `size` sensors, at least four, leaving exactly one position in the
+/// 0..=4000000 square where the distress beacon can be.
+///
+/// Unlike in the real puzzle a sensor's beacon may be closer to another
+/// sensor than that sensor's own beacon, which the solution doesn't rely on.
+pub fn day_15(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    sensors(size, 4_000_000, &mut rng)
+        .iter()
+        .map(|((x, y), (beacon_x, beacon_y))| {
+            format!("Sensor at x={x}, y={y}: closest beacon is at x={beacon_x}, y={beacon_y}\n")
+        })
+        .collect()
+}
+
+type Pos = (i64, i64);
+
+fn sensors(size: usize, max: i64, rng: &mut impl Rng) -> Vec<(Pos, Pos)> {
+    let gap = (
+        rng.gen_range(max / 4..=3 * max / 4),
+        rng.gen_range(max / 4..=3 * max / 4),
+    );
+    let extent = [gap.0, max - gap.0, gap.1, max - gap.1]
+        .into_iter()
+        .max()
+        .unwrap();
+
+    // Four sensors diagonally around the gap, each just not reaching it,
+    // together cover everything up to `extent` away from it.
+    let offset = extent / 2 + 1;
+    let range = 2 * offset - 1;
+    let mut sensors: Vec<(Pos, Pos)> = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+        .into_iter()
+        .map(|(dx, dy)| {
+            let loc = (gap.0 + dx * offset, gap.1 + dy * offset);
+            (loc, (loc.0 + dx * range, loc.1))
+        })
+        .collect();
+
+    // The rest only need to stay clear of the gap
+    while sensors.len() < size {
+        let loc = (rng.gen_range(0..=max), rng.gen_range(0..=max));
+        let to_gap = distance(loc, gap);
+        if to_gap < 2 {
+            continue;
+        }
+        let range = rng.gen_range(1..to_gap);
+        let along = rng.gen_range(0..range);
+        let beacon = match rng.gen_range(0..4) {
+            0 => (loc.0 + along, loc.1 + range - along),
+            1 => (loc.0 + range - along, loc.1 - along),
+            2 => (loc.0 - along, loc.1 - range + along),
+            _ => (loc.0 - range + along, loc.1 + along),
+        };
+        sensors.push((loc, beacon));
+    }
+    sensors
+}
+
+fn distance(left: Pos, right: Pos) -> i64 {
+    (left.0 - right.0).abs() + (left.1 - right.1).abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_day_15_has_a_single_gap() {
+        let max = 40;
+        for seed in 0..20 {
+            let sensors = sensors(10, max, &mut StdRng::seed_from_u64(seed));
+            let gaps: Vec<Pos> = (0..=max)
+                .flat_map(|x| (0..=max).map(move |y| (x, y)))
+                .filter(|&pos| {
+                    sensors
+                        .iter()
+                        .all(|&(loc, beacon)| distance(loc, pos) > distance(loc, beacon))
+                })
+                .collect();
+            assert_eq!(gaps.len(), 1, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn test_generators_are_deterministic() {
+        for generator in GENERATORS {
+            assert_eq!(generator.generate(20, 7), generator.generate(20, 7));
+        }
+    }
+}
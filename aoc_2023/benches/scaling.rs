@@ -0,0 +1 @@
+aoc_common::scaling_main!(&aoc_2023::days::YEAR, aoc_2023::generate::GENERATORS);
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use lazy_static::lazy_static;
 use phf::phf_map;
 
@@ -24,6 +25,28 @@ pub fn solve_day(input: &str) -> (u64, u64) {
     (part_a(input), part_b(input))
 }
 
+pub const DAY: Day = Day::new(1, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u64 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u64 {
+        part_b(input)
+    }
+}
+
 pub fn part_a(input: &str) -> u64 {
     input.trim().split('\n').map(decode_line).sum()
 }
@@ -55,8 +78,7 @@ fn decode_line(input: &str) -> u64 {
         .into();
     let last_number: u64 = input
         .chars()
-        .filter(|c| c.is_ascii_digit())
-        .next_back()
+        .rfind(|c| c.is_ascii_digit())
         .unwrap_or_else(|| panic!("Invalid input: {input}"))
         .to_digit(10)
         .unwrap()
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use aoc_common::{Day, Solution};
 use lazy_static::lazy_static;
 use std::str::FromStr;
 
@@ -21,6 +22,28 @@ pub fn solve_day(input: &str) -> (u64, u64) {
     (part_a(input), part_b(input))
 }
 
+pub const DAY: Day = Day::new(2, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u64 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u64 {
+        part_b(input)
+    }
+}
+
 impl Game {
     fn is_possible(&self) -> bool {
         self.revealed.iter().all(|s| s.is_possible())
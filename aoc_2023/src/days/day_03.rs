@@ -1,3 +1,4 @@
+use aoc_common::{Coord, Day, Solution};
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -9,26 +10,33 @@ pub fn solve_day(input: &str) -> (u64, u64) {
     )
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-struct Location {
-    x: u8,
-    y: u8,
-}
+pub const DAY: Day = Day::new(3, &Solver);
 
-impl From<(u8, u8)> for Location {
-    fn from(value: (u8, u8)) -> Self {
-        Location {
-            x: value.0,
-            y: value.1,
-        }
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = (Vec<PartNumber>, Vec<Vec<Option<Symbol>>>);
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a((part_numbers, symbols): &Self::Parsed<'_>) -> u64 {
+        part_a(part_numbers, symbols)
+    }
+
+    fn part_b((part_numbers, symbols): &Self::Parsed<'_>) -> u64 {
+        part_b(part_numbers, symbols)
     }
 }
 
 #[derive(Debug, PartialEq, Eq)]
 struct PartNumber {
     value: u64,
-    start_loc: Location,
-    end_loc: Location,
+    start_loc: Coord<u8>,
+    end_loc: Coord<u8>,
 }
 
 impl PartNumber {
@@ -56,34 +64,17 @@ impl PartNumber {
         false
     }
 
-    fn is_neighbor_of_location(&self, loc: &Location) -> bool {
-        if (self.start_loc.x.max(1) - 1) <= loc.x
-            && loc.x <= (self.end_loc.x + 1)
-            && (self.start_loc.y.max(1) - 1) <= loc.y
-            && loc.y <= (self.end_loc.y + 1)
-        {
-            return true;
-        }
-        false
+    fn is_neighbor_of_location(&self, loc: &Coord<u8>) -> bool {
+        self.digits().any(|digit| digit.chebyshev(*loc) <= 1)
     }
 
-    fn get_neighbores(&self) -> Vec<Location> {
+    fn get_neighbores(&self) -> Vec<Coord<u8>> {
+        self.digits().flat_map(Coord::<u8>::neighbours8).collect()
+    }
+
+    fn digits(&self) -> impl Iterator<Item = Coord<u8>> {
         let y = self.start_loc.y;
-        ((self.start_loc.x.max(1) - 1)..=(self.end_loc.x + 1))
-            .flat_map(|x| {
-                [
-                    Location {
-                        x,
-                        y: (y.max(1) - 1).max(0),
-                    },
-                    Location { x, y },
-                    Location {
-                        x,
-                        y: (y + 1).max(0),
-                    },
-                ]
-            })
-            .collect()
+        (self.start_loc.x..=self.end_loc.x).map(move |x| Coord::new(x, y))
     }
 }
 
@@ -107,11 +98,11 @@ fn parse_input(input: &str) -> (Vec<PartNumber>, Vec<Vec<Option<Symbol>>>) {
 }
 
 fn parse_line_part_number(line: &'_ str, y_idx: u8) -> impl Iterator<Item = PartNumber> + '_ {
-    return RE_NUMBER.captures_iter(line).map(move |c| PartNumber {
+    RE_NUMBER.captures_iter(line).map(move |c| PartNumber {
         value: c.get(0).unwrap().as_str().parse::<u64>().unwrap(),
         start_loc: (c.get(0).unwrap().range().start as u8, y_idx).into(),
         end_loc: (c.get(0).unwrap().range().end as u8 - 1, y_idx).into(),
-    });
+    })
 }
 
 fn parse_symbols(input: &str) -> Vec<Vec<Option<Symbol>>> {
@@ -147,7 +138,7 @@ impl Gear {
     }
 }
 
-fn get_locs_of_symbol(symbols: &[Vec<Option<Symbol>>], val: char) -> Vec<Location> {
+fn get_locs_of_symbol(symbols: &[Vec<Option<Symbol>>], val: char) -> Vec<Coord<u8>> {
     symbols
         .iter()
         .enumerate()
@@ -161,7 +152,7 @@ fn get_locs_of_symbol(symbols: &[Vec<Option<Symbol>>], val: char) -> Vec<Locatio
                 None
             })
         })
-        .collect::<Vec<Location>>()
+        .collect::<Vec<Coord<u8>>>()
 }
 
 fn part_b(part_numbers: &[PartNumber], symbols: &[Vec<Option<Symbol>>]) -> u64 {
@@ -174,14 +165,14 @@ fn part_b(part_numbers: &[PartNumber], symbols: &[Vec<Option<Symbol>>]) -> u64 {
 
     possbile_gear_locs
         .iter()
-        .filter_map(|gear_loc: &Location| {
+        .filter_map(|gear_loc: &Coord<u8>| {
             let neighbors = &possible_gears
                 .iter()
                 .filter(|p| p.is_neighbor_of_location(gear_loc))
                 .collect::<Vec<&&PartNumber>>();
             if neighbors.len() == 2 {
                 Some(Gear {
-                    p1: neighbors.get(0).unwrap().value,
+                    p1: neighbors.first().unwrap().value,
                     p2: neighbors.get(1).unwrap().value,
                 })
             } else {
@@ -205,7 +196,7 @@ mod test {
     #[rstest]
     fn test_is_part_number(example_input: &str) {
         let (part_numbers, symbols) = parse_input(example_input);
-        let first_part = part_numbers.get(0).unwrap();
+        let first_part = part_numbers.first().unwrap();
         assert_eq!(first_part.start_loc, (0, 0).into());
         assert_eq!(first_part.end_loc, (2, 0).into());
 
@@ -218,7 +209,7 @@ mod test {
                 .expect("Value is None"),
             Symbol { value: '*' }
         );
-        assert!(part_numbers.get(0).unwrap().neighbores_symbol(&symbols));
+        assert!(part_numbers.first().unwrap().neighbores_symbol(&symbols));
         assert!(!part_numbers.get(1).unwrap().neighbores_symbol(&symbols));
         assert!(part_numbers.get(2).unwrap().neighbores_symbol(&symbols));
         assert!(part_numbers.get(3).unwrap().neighbores_symbol(&symbols));
@@ -234,7 +225,7 @@ mod test {
     #[rstest]
     fn test_is_possible_gear(example_input: &str) {
         let (part_numbers, symbols) = parse_input(example_input);
-        assert!(part_numbers.get(0).unwrap().is_possible_gear(&symbols));
+        assert!(part_numbers.first().unwrap().is_possible_gear(&symbols));
         assert!(!part_numbers.get(1).unwrap().is_possible_gear(&symbols));
         assert!(part_numbers.get(2).unwrap().is_possible_gear(&symbols));
         assert!(!part_numbers.get(3).unwrap().is_possible_gear(&symbols));
@@ -262,9 +253,9 @@ mod test {
     fn test_symbol_locations(example_input: &str) {
         let (_part_numbers, symbols) = parse_input(example_input);
         let locations = get_locs_of_symbol(&symbols, '*');
-        assert!(locations.contains(&Location { x: 3, y: 1 }));
-        assert!(locations.contains(&Location { x: 3, y: 4 }));
-        assert!(locations.contains(&Location { x: 5, y: 8 }));
+        assert!(locations.contains(&Coord { x: 3, y: 1 }));
+        assert!(locations.contains(&Coord { x: 3, y: 4 }));
+        assert!(locations.contains(&Coord { x: 5, y: 8 }));
         assert_eq!(locations.len(), 3);
     }
 
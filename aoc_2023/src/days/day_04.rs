@@ -1,6 +1,5 @@
 use anyhow::Result;
-use lazy_static::lazy_static;
-use regex::Regex;
+use aoc_common::{extract_digits, Day, Solution};
 use std::str::FromStr;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -26,53 +25,18 @@ impl Card {
     }
 }
 
-lazy_static! {
-    static ref RE_DIGITS: Regex = Regex::new(r"(\d+)").unwrap();
-}
-
 impl FromStr for Card {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
         if let Some((game_id, numbers)) = s.split_once(':') {
-            let game_id = RE_DIGITS
-                .captures_iter(game_id)
+            let game_id = extract_digits(game_id)
                 .next()
-                .expect("Card id should be present")
-                .get(1)
-                .unwrap()
-                .as_str()
-                .parse::<u64>()
-                .unwrap_or_else(|_| panic!("Could not parse card id: {}", game_id));
+                .expect("Card id should be present");
 
             if let Some((winnig_numbers, numbers)) = numbers.split_once('|') {
-                let winning_numbers: Vec<u64> = RE_DIGITS
-                    .captures_iter(winnig_numbers)
-                    .map(|x| {
-                        x.get(0)
-                            .unwrap()
-                            .as_str()
-                            .parse::<u64>()
-                            .unwrap_or_else(|_| {
-                                panic!(
-                                    "Could not parse winning numbers: {}",
-                                    x.get(0).unwrap().as_str()
-                                )
-                            })
-                    })
-                    .collect();
-                let numbers: Vec<u64> = RE_DIGITS
-                    .captures_iter(numbers)
-                    .map(|x| {
-                        x.get(0)
-                            .unwrap()
-                            .as_str()
-                            .parse::<u64>()
-                            .unwrap_or_else(|_| {
-                                panic!("Could not parse numbers: {}", x.get(0).unwrap().as_str())
-                            })
-                    })
-                    .collect();
+                let winning_numbers: Vec<u64> = extract_digits(winnig_numbers).collect();
+                let numbers: Vec<u64> = extract_digits(numbers).collect();
                 return Ok(Card {
                     game_id,
                     winning_numbers,
@@ -95,6 +59,28 @@ pub fn solve_day(input: &str) -> (u64, u64) {
     (part_a(&cards), part_b(&cards))
 }
 
+pub const DAY: Day = Day::new(4, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Card>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a(cards: &Self::Parsed<'_>) -> u64 {
+        part_a(cards)
+    }
+
+    fn part_b(cards: &Self::Parsed<'_>) -> u64 {
+        part_b(cards)
+    }
+}
+
 fn parse_input(input: &str) -> Vec<Card> {
     input
         .trim()
@@ -107,7 +93,7 @@ fn part_a(cards: &[Card]) -> u64 {
     cards.iter().map(|card| card.points()).sum()
 }
 
-fn part_b(cards: &Vec<Card>) -> u64 {
+fn part_b(cards: &[Card]) -> u64 {
     let mut weights = vec![1; cards.len()];
 
     for i in 0..cards.len() {
@@ -1,22 +1,38 @@
-use lazy_static::lazy_static;
-use regex::Regex;
+use aoc_common::{extract_digits, Day, IntervalSet, Solution};
+use std::ops::Range;
 
 pub fn solve_day(input: &str) -> (u64, u64) {
     let (seeds, maps) = parse_input(input);
     (part_a(&seeds, &maps), part_b(&seeds, &maps))
 }
 
+pub const DAY: Day = Day::new(5, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = (Seeds, Vec<Map>);
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a((seeds, maps): &Self::Parsed<'_>) -> u64 {
+        part_a(seeds, maps)
+    }
+
+    fn part_b((seeds, maps): &Self::Parsed<'_>) -> u64 {
+        part_b(seeds, maps)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 struct Seeds {
     seeds: Vec<u64>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
-struct SeedRange {
-    start: u64,
-    length: u64,
-}
-
 #[derive(Debug, PartialEq, Eq)]
 struct Map {
     converters: Vec<Converter>,
@@ -32,18 +48,12 @@ impl Map {
         x
     }
 
-    fn convert_range(&self, ranges: Vec<SeedRange>) -> Vec<SeedRange> {
-        let not_converted = ranges;
-        let (mut converted, not_converted) = self.converters.iter().fold(
-            (vec![], not_converted),
-            |(mut converted, not_converted), converter| {
-                let (c, not_converted) = converter.convert_ranges(not_converted);
-                converted.extend(c);
-                (converted, not_converted)
-            },
-        );
-        converted.extend(not_converted);
-        converted
+    fn convert_range(&self, ranges: &IntervalSet<u64>) -> IntervalSet<u64> {
+        ranges.translate(
+            self.converters
+                .iter()
+                .map(|converter| (converter.source(), converter.dst_start)),
+        )
     }
 }
 
@@ -64,53 +74,8 @@ impl Converter {
         (self.dst_start + x) - self.src_start
     }
 
-    fn convert_ranges(&self, ranges: Vec<SeedRange>) -> (Vec<SeedRange>, Vec<SeedRange>) {
-        let mut converted = Vec::with_capacity(ranges.len());
-        let mut not_converted = Vec::with_capacity(ranges.len());
-
-        for mut range in ranges {
-            let left_bound = range.start;
-            let right_bound = range.start + (range.length - 1);
-            if self.left_bound() > right_bound || self.right_bound() < left_bound {
-                not_converted.push(range);
-                continue;
-            }
-            // Split up the range in 3 (or less) parts
-            if left_bound < self.left_bound() {
-                not_converted.push(SeedRange {
-                    start: range.start,
-                    length: self.left_bound() - range.start,
-                });
-                range = SeedRange {
-                    start: self.left_bound(),
-                    length: range.length - (self.left_bound() - range.start),
-                }
-            }
-            if right_bound > self.right_bound() {
-                not_converted.push(SeedRange {
-                    start: self.right_bound(),
-                    length: right_bound - self.right_bound(),
-                });
-
-                range = SeedRange {
-                    start: range.start,
-                    length: range.length - (right_bound - self.right_bound()),
-                }
-            }
-            converted.push(SeedRange {
-                start: self.dst_start + (range.start - self.src_start),
-                length: range.length,
-            });
-        }
-        (converted, not_converted)
-    }
-
-    fn left_bound(&self) -> u64 {
-        self.src_start
-    }
-
-    fn right_bound(&self) -> u64 {
-        self.src_start + self.length
+    fn source(&self) -> Range<u64> {
+        self.src_start..self.src_start + self.length
     }
 }
 
@@ -124,24 +89,8 @@ impl From<[u64; 3]> for Converter {
     }
 }
 
-impl From<[u64; 2]> for SeedRange {
-    fn from(value: [u64; 2]) -> SeedRange {
-        SeedRange {
-            start: value[0],
-            length: value[1],
-        }
-    }
-}
-
-lazy_static! {
-    static ref RE_DIGITS: Regex = Regex::new(r"(\d+)").unwrap();
-}
-
 fn extract_digits_from_line(line: &str) -> Vec<u64> {
-    RE_DIGITS
-        .captures_iter(line)
-        .map(|x| x.get(0).unwrap().as_str().parse::<u64>().unwrap())
-        .collect()
+    extract_digits(line).collect()
 }
 
 fn parse_input(input: &str) -> (Seeds, Vec<Map>) {
@@ -169,9 +118,9 @@ fn full_convert(maps: &[Map], seed: u64) -> u64 {
     maps.iter().fold(seed, |val, map| map.convert(val))
 }
 
-fn full_convert_range(maps: &[Map], seedranges: Vec<SeedRange>) -> Vec<SeedRange> {
+fn full_convert_range(maps: &[Map], seeds: IntervalSet<u64>) -> IntervalSet<u64> {
     maps.iter()
-        .fold(seedranges, |ranges, map| map.convert_range(ranges))
+        .fold(seeds, |ranges, map| map.convert_range(&ranges))
 }
 
 fn part_a(seeds: &Seeds, maps: &[Map]) -> u64 {
@@ -183,25 +132,14 @@ fn part_a(seeds: &Seeds, maps: &[Map]) -> u64 {
         .unwrap()
 }
 
-fn parse_seeds_part_2(seeds: &Seeds) -> Vec<SeedRange> {
-    seeds
-        .seeds
-        .chunks(2)
-        .map(|x| SeedRange {
-            start: x[0],
-            length: x[1],
-        })
-        .collect()
+fn parse_seeds_part_2(seeds: &Seeds) -> IntervalSet<u64> {
+    seeds.seeds.chunks(2).map(|x| x[0]..x[0] + x[1]).collect()
 }
 
 fn part_b(seeds: &Seeds, maps: &[Map]) -> u64 {
     let seeds = parse_seeds_part_2(seeds);
 
-    full_convert_range(maps, seeds)
-        .iter()
-        .map(|s| s.start)
-        .min()
-        .unwrap() as u64
+    full_convert_range(maps, seeds).first().unwrap()
 }
 
 #[cfg(test)]
@@ -212,7 +150,7 @@ mod test {
 
     #[fixture]
     fn example_input() -> &'static str {
-        read_test_day_input("day_05").leak()
+        read_test_day_input("05").leak()
     }
 
     #[rstest]
@@ -311,68 +249,62 @@ mod test {
         assert_eq!(converter.convert(99), 51);
     }
 
+    /// Seed ranges given as start and length, like the input does.
+    fn seed_ranges(ranges: &[[u64; 2]]) -> IntervalSet<u64> {
+        ranges
+            .iter()
+            .map(|&[start, length]| start..start + length)
+            .collect()
+    }
+
     #[rstest]
     fn test_parse_seeds_part_2(seeds_part_1: Seeds) {
         assert_eq!(
             parse_seeds_part_2(&seeds_part_1),
-            vec![
-                SeedRange {
-                    start: 79,
-                    length: 14
-                },
-                SeedRange {
-                    start: 55,
-                    length: 13
-                }
-            ]
+            seed_ranges(&[[79, 14], [55, 13]])
         )
     }
 
-    #[fixture]
-    fn seeds_part_2(seeds_part_1: Seeds) -> Vec<SeedRange> {
-        parse_seeds_part_2(&seeds_part_1)
-    }
-
     #[rstest]
-    #[case(vec![SeedRange::from([0, 50])], vec![SeedRange::from([0, 50])])]
-    #[case(vec![SeedRange::from([0, 51])], vec![SeedRange::from([0, 50]), SeedRange::from([52, 1])])]
-    #[case(vec![SeedRange::from([0, 100])], vec![SeedRange::from([0, 50]), SeedRange::from([52, 48]), SeedRange::from([50, 2])])]
-    #[case(vec![SeedRange::from([82, 1])], vec![SeedRange::from([84, 1])])]
+    #[case(&[[0, 50]], &[[0, 50]])]
+    #[case(&[[0, 51]], &[[0, 50], [52, 1]])]
+    #[case(&[[0, 100]], &[[0, 50], [52, 48], [50, 2]])]
+    #[case(&[[82, 1]], &[[84, 1]])]
     fn test_convert_range(
         first_map: Map,
-        #[case] input_range: Vec<SeedRange>,
-        #[case] mut expected_range: Vec<SeedRange>,
+        #[case] input_range: &[[u64; 2]],
+        #[case] expected_range: &[[u64; 2]],
     ) {
-        let mut calculated = first_map.convert_range(input_range);
-        calculated.sort();
-        expected_range.sort();
-        assert_eq!(calculated, expected_range)
+        assert_eq!(
+            first_map.convert_range(&seed_ranges(input_range)),
+            seed_ranges(expected_range)
+        )
     }
 
     #[rstest]
-    #[case(vec![SeedRange::from([50, 10])], vec![SeedRange::from([100, 10])])]
-    #[case(vec![SeedRange::from([40, 20])], vec![SeedRange::from([100, 10]), SeedRange::from([40, 10])])]
-    #[case(vec![SeedRange::from([50, 20])], vec![SeedRange::from([100, 11]), SeedRange::from([60, 9])])]
-    #[case(vec![SeedRange::from([40, 30])], vec![SeedRange::from([100, 11]), SeedRange::from([60, 9]), SeedRange::from([40, 10])])]
-    #[case(vec![SeedRange::from([51, 1])], vec![SeedRange::from([101, 1])])]
-    #[case(vec![SeedRange::from([52, 1])], vec![SeedRange::from([102, 1])])]
+    #[case(&[[50, 10]], &[[100, 10]])]
+    #[case(&[[40, 20]], &[[100, 10], [40, 10]])]
+    #[case(&[[50, 20]], &[[100, 10], [60, 10]])]
+    #[case(&[[40, 30]], &[[100, 10], [60, 10], [40, 10]])]
+    #[case(&[[51, 1]], &[[101, 1]])]
+    #[case(&[[52, 1]], &[[102, 1]])]
     fn test_converter_convert_range(
-        #[case] input_ranges: Vec<SeedRange>,
-        #[case] mut expected_range: Vec<SeedRange>,
+        #[case] input_ranges: &[[u64; 2]],
+        #[case] expected_range: &[[u64; 2]],
     ) {
-        let converter = Converter::from([100, 50, 10]);
-        let (mut c, n) = converter.convert_ranges(input_ranges);
-        c.extend(n);
-        c.sort();
-        expected_range.sort();
-        assert_eq!(c, expected_range)
+        let map = Map {
+            converters: vec![Converter::from([100, 50, 10])],
+        };
+        assert_eq!(
+            map.convert_range(&seed_ranges(input_ranges)),
+            seed_ranges(expected_range)
+        )
     }
 
     #[rstest]
     fn test_part_b_extra(all_maps: Vec<Map>) {
-        let seeds: Vec<SeedRange> = vec![SeedRange::from([82, 1])];
-        let seeds = all_maps[0].convert_range(seeds);
-        assert_eq!(seeds, vec![SeedRange::from([84, 1])]);
+        let seeds = all_maps[0].convert_range(&seed_ranges(&[[82, 1]]));
+        assert_eq!(seeds, seed_ranges(&[[84, 1]]));
     }
 
     #[rstest]
@@ -1,9 +1,31 @@
-use crate::days::RE_DIGITS;
+use aoc_common::{extract_digits, Day, Solution};
 
 pub fn solve_day(input: &str) -> (u64, u64) {
     (part_a(input), part_b(input))
 }
 
+pub const DAY: Day = Day::new(6, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u64 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u64 {
+        part_b(input)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 struct Race {
     time: u64,
@@ -12,14 +34,8 @@ struct Race {
 
 fn parse_input(input: &str) -> Vec<Race> {
     let (times, records) = input.split_once('\n').unwrap();
-    let times: Vec<u64> = RE_DIGITS
-        .captures_iter(times)
-        .map(|s| s.get(0).unwrap().as_str().parse().unwrap())
-        .collect();
-    let records: Vec<u64> = RE_DIGITS
-        .captures_iter(records)
-        .map(|s| s.get(0).unwrap().as_str().parse().unwrap())
-        .collect();
+    let times: Vec<u64> = extract_digits(times).collect();
+    let records: Vec<u64> = extract_digits(records).collect();
     times
         .into_iter()
         .zip(records)
@@ -1,9 +1,32 @@
+use aoc_common::{Day, Solution};
 use std::str::FromStr;
 
 pub fn solve_day(input: &str) -> (u64, u64) {
     (part_a(input), part_b(input))
 }
 
+pub const DAY: Day = Day::new(7, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u64 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u64 {
+        part_b(input)
+    }
+}
+
 fn part_a(input: &str) -> u64 {
     let mut hands: Vec<Hand> = input
         .trim()
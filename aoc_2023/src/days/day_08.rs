@@ -1,7 +1,31 @@
+use aoc_common::{Day, Solution};
+
 pub fn solve_day(input: &str) -> (u64, u64) {
     (part_a(input), part_b(input))
 }
 
+pub const DAY: Day = Day::new(8, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u64 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u64 {
+        part_b(input)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 
 struct QuickGraph {
@@ -201,7 +225,7 @@ fn part_b(input: &str) -> u64 {
 mod test {
     use std::time::Duration;
 
-    use crate::days::read_day_input;
+    use crate::days::read_day_input_if_present;
 
     use super::*;
     use rstest::{fixture, rstest};
@@ -211,11 +235,6 @@ mod test {
         return "RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\nEEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)";
     }
 
-    #[fixture]
-    fn input() -> &'static str {
-        return read_day_input("day_08").leak();
-    }
-
     #[rstest]
     #[timeout(Duration::from_secs(1))]
     fn test_part_a(example_input: &str) {
@@ -225,8 +244,11 @@ mod test {
 
     #[rstest]
     #[timeout(Duration::from_secs(1))]
-    fn test_part_a_full(input: &str) {
-        let (directions, graph) = parse_input(input);
+    fn test_part_a_full() {
+        let Some(input) = read_day_input_if_present("08") else {
+            return;
+        };
+        let (directions, graph) = parse_input(&input);
         assert_eq!(
             graph.follow_path("AAA".into(), "ZZZ".into(), directions),
             14257
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::{iter::Sum, str::FromStr};
@@ -11,6 +12,28 @@ pub fn solve_day(input: &str) -> (u64, u64) {
     )
 }
 
+pub const DAY: Day = Day::new(9, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Sequence>;
+    type AnswerA = i64;
+    type AnswerB = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a(sequences: &Self::Parsed<'_>) -> i64 {
+        part_a(sequences)
+    }
+
+    fn part_b(sequences: &Self::Parsed<'_>) -> i64 {
+        part_b(sequences)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 struct Prediction {
     part_a: i64,
@@ -117,13 +140,10 @@ fn parse_input(input: &str) -> Vec<Sequence> {
         .collect()
 }
 
-#[allow(dead_code)]
 fn part_a(sequences: &[Sequence]) -> i64 {
     sequences.iter().map(|s| s.predict()).sum()
 }
 
-#[allow(dead_code)]
-
 fn part_b(sequences: &[Sequence]) -> i64 {
     sequences.iter().map(|s| s.predict_back()).sum()
 }
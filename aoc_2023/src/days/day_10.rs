@@ -1,9 +1,7 @@
+use aoc_common::{bfs, Day, Direction, Solution};
 use std::{
-    cmp::Ordering,
-    collections::{BinaryHeap, HashSet},
     fmt::{Display, Write},
     str::FromStr,
-    usize,
 };
 
 pub fn solve_day(input: &str) -> (u64, u64) {
@@ -11,6 +9,28 @@ pub fn solve_day(input: &str) -> (u64, u64) {
     (part_a(&network), part_b(&network))
 }
 
+pub const DAY: Day = Day::new(10, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = PipeNetwork;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a(network: &Self::Parsed<'_>) -> u64 {
+        part_a(network)
+    }
+
+    fn part_b(network: &Self::Parsed<'_>) -> u64 {
+        part_b(network)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 struct PipeNetwork {
     nodes: Vec<Vec<PipeShape>>,
@@ -25,7 +45,7 @@ enum Corners {
 }
 
 impl Corners {
-    fn as_offset(&self) -> (i32, i32) {
+    fn as_offset(&self) -> (isize, isize) {
         match self {
             Corners::TL => (0, 0),
             Corners::TR => (0, -1),
@@ -34,14 +54,14 @@ impl Corners {
         }
     }
 
-    fn relative_to(&self, other: &Self) -> (i32, i32) {
+    fn relative_to(&self, other: &Self) -> (isize, isize) {
         (
             other._relative_coord().0 - self._relative_coord().0,
             other._relative_coord().1 - self._relative_coord().1,
         )
     }
 
-    fn _relative_coord(&self) -> (i32, i32) {
+    fn _relative_coord(&self) -> (isize, isize) {
         match self {
             Corners::TL => (0, 0),
             Corners::TR => (0, 1),
@@ -51,34 +71,6 @@ impl Corners {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Loc {
-    Inside,
-    Outside,
-}
-
-#[derive(Eq, PartialEq)]
-struct State {
-    position: (usize, usize),
-    cost: u64,
-}
-
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other
-            .cost
-            .cmp(&self.cost)
-            .then_with(|| self.position.cmp(&other.position))
-    }
-}
-
-// `PartialOrd` needs to be implemented as well.
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 impl PipeNetwork {
     fn get_start_pos(&self) -> (usize, usize) {
         for (i, row) in self.nodes.iter().enumerate() {
@@ -92,32 +84,10 @@ impl PipeNetwork {
     }
 
     fn create_dist_map(&self, start: (usize, usize)) -> Vec<Vec<u64>> {
+        let search = bfs([start], |&position| self.get_connected(position), |_| false);
         let mut dist = vec![vec![u64::MAX; self.nodes[0].len()]; self.nodes.len()];
-        dist[start.0][start.1] = 0;
-
-        let mut heap = BinaryHeap::new();
-
-        heap.push(State {
-            cost: 0,
-            position: start,
-        });
-
-        while let Some(State { cost, position }) = heap.pop() {
-            if cost > dist[position.0][position.1] {
-                continue;
-            }
-
-            for connected in self.get_connected(position) {
-                let next = State {
-                    cost: (cost + 1),
-                    position: connected,
-                };
-
-                if next.cost < dist[connected.0][connected.1] {
-                    dist[connected.0][connected.1] = next.cost;
-                    heap.push(next);
-                }
-            }
+        for (&(x, y), &steps) in search.costs() {
+            dist[x][y] = steps as u64;
         }
         dist
     }
@@ -148,7 +118,9 @@ impl PipeNetwork {
             .offsets()
             .iter()
             .filter_map(|direction| {
-                let pos = add_offset(position, &direction.as_offset(), &(max_x, max_y))?;
+                // Positions here are (row, column), the other way around from the offset.
+                let (dx, dy) = direction.offset();
+                let pos = add_offset(position, &(dy, dx), &(max_x, max_y))?;
                 if self.nodes[pos.0][pos.1].is_connectable_from(direction) {
                     return Some(pos);
                 }
@@ -158,7 +130,7 @@ impl PipeNetwork {
     }
 
     fn get_connected_corners(
-        loop_nodes: &Vec<Vec<PipeShape>>,
+        loop_nodes: &[Vec<PipeShape>],
         corner_pos: (usize, usize),
     ) -> Vec<(usize, usize)> {
         let mut corners = Vec::with_capacity(8);
@@ -212,46 +184,33 @@ impl PipeNetwork {
         let dist: Vec<Vec<u64>> = self.get_dist_map();
         let loop_nodes: Vec<Vec<PipeShape>> = self.get_loop_nodes(dist);
 
-        let x_corner_max = self.nodes.len() + 1;
-        let y_corner_max = self.nodes[0].len() + 1;
-        let mut corners = vec![vec![Loc::Inside; y_corner_max]; x_corner_max];
-
-        corners[0][0] = Loc::Outside;
-        let mut to_be_visited = BinaryHeap::from([(0, 0)]);
-
-        let mut visited = HashSet::new();
-
-        while let Some(position) = to_be_visited.pop() {
-            for connected_corner in Self::get_connected_corners(&loop_nodes, position) {
-                if !visited.contains(&connected_corner) {
-                    visited.insert(connected_corner);
-                    to_be_visited.push(connected_corner);
-                    corners[connected_corner.0][connected_corner.1] = Loc::Outside;
-                }
-            }
-        }
-
-        let mut sum = 0;
-        for i in 0..loop_nodes.len() {
-            for j in 0..loop_nodes[0].len() {
-                if corners[i][j] == Loc::Inside && loop_nodes[i][j] == PipeShape::Ground {
-                    sum += 1;
-                }
-            }
-        }
+        let outside = bfs(
+            [(0, 0)],
+            |&position| Self::get_connected_corners(&loop_nodes, position),
+            |_| false,
+        );
 
-        sum
+        // A tile is enclosed when the corner at its top left can't be reached
+        // from outside.
+        loop_nodes
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, pipe)| ((i, j), pipe)))
+            .filter(|(corner, pipe)| {
+                **pipe == PipeShape::Ground && !outside.costs().contains_key(corner)
+            })
+            .count() as u64
     }
 }
 
 fn add_offset(
     position: (usize, usize),
-    (x, y): &(i32, i32),
+    (x, y): &(isize, isize),
     (max_x, max_y): &(usize, usize),
 ) -> Option<(usize, usize)> {
     let p = (
-        usize::try_from(isize::try_from(position.0).ok()? + *x as isize).ok()?,
-        usize::try_from(isize::try_from(position.1).ok()? + *y as isize).ok()?,
+        position.0.checked_add_signed(*x)?,
+        position.1.checked_add_signed(*y)?,
     );
     if &p.0 < max_x && &p.1 < max_y {
         return Some(p);
@@ -272,34 +231,6 @@ impl FromStr for PipeNetwork {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-impl Direction {
-    fn as_offset(&self) -> (i32, i32) {
-        match self {
-            Direction::North => (-1, 0),
-            Direction::East => (0, 1),
-            Direction::South => (1, 0),
-            Direction::West => (0, -1),
-        }
-    }
-
-    fn complement(&self) -> Direction {
-        match self {
-            Direction::North => Direction::South,
-            Direction::East => Direction::West,
-            Direction::South => Direction::North,
-            Direction::West => Direction::East,
-        }
-    }
-}
-
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum PipeShape {
     NorthSouth,
@@ -329,25 +260,25 @@ impl PipeShape {
     fn offsets(&self) -> Vec<Direction> {
         match self {
             PipeShape::NorthSouth => {
-                vec![Direction::North, Direction::South]
+                vec![Direction::Up, Direction::Down]
             }
-            PipeShape::EastWest => vec![Direction::East, Direction::West],
-            PipeShape::NorthEast => vec![Direction::North, Direction::East],
-            PipeShape::NorthWest => vec![Direction::North, Direction::West],
-            PipeShape::SouthWest => vec![Direction::South, Direction::West],
-            PipeShape::SouthEast => vec![Direction::South, Direction::East],
+            PipeShape::EastWest => vec![Direction::Right, Direction::Left],
+            PipeShape::NorthEast => vec![Direction::Up, Direction::Right],
+            PipeShape::NorthWest => vec![Direction::Up, Direction::Left],
+            PipeShape::SouthWest => vec![Direction::Down, Direction::Left],
+            PipeShape::SouthEast => vec![Direction::Down, Direction::Right],
             PipeShape::Start => vec![
-                Direction::North,
-                Direction::South,
-                Direction::East,
-                Direction::West,
+                Direction::Up,
+                Direction::Down,
+                Direction::Right,
+                Direction::Left,
             ],
             PipeShape::Ground => vec![],
         }
     }
 
     fn is_connectable_from(&self, dir: &Direction) -> bool {
-        self.offsets().contains(&dir.complement())
+        self.offsets().contains(&dir.reverse())
     }
 
     fn reachable_corners_from_corner(&self, corner: &Corners) -> Vec<Corners> {
@@ -557,7 +488,7 @@ mod test {
         #[case] network: &str,
         #[case] mut connected_corners: Vec<(usize, usize)>,
     ) {
-        let loop_nodes = network
+        let loop_nodes: Vec<Vec<PipeShape>> = network
             .trim()
             .split('\n')
             .map(|line| line.chars().map(PipeShape::from).collect())
@@ -1,3 +1,4 @@
+use aoc_common::{Day, ParseError, Solution};
 use std::{collections::HashMap, hash::Hash, str::FromStr};
 
 use nom::Slice;
@@ -6,17 +7,48 @@ use tqdm::Iter;
 type State<'a> = (Spring, &'a [Spring], &'a [u64], u64);
 
 pub fn solve_day(input: &str) -> (u64, u64) {
+    let (lines, lines_2) = parse_input(input).unwrap();
+    let mut cache = HashMap::new();
+    (part_a(&lines, &mut cache), part_b(&lines_2, &mut cache))
+}
+
+pub const DAY: Day = Day::checked(12, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = (Vec<Line>, Vec<Line>);
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a((lines, _): &Self::Parsed<'_>) -> u64 {
+        part_a(lines, &mut HashMap::new())
+    }
+
+    fn part_b((_, lines_2): &Self::Parsed<'_>) -> u64 {
+        part_b(lines_2, &mut HashMap::new())
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Line>, Vec<Line>), ParseError> {
     let lines = input
         .trim()
         .split('\n')
-        .map(|l| Line::from_str(l).unwrap().compress())
-        .collect::<Vec<Line>>();
-    let mut cache = HashMap::new();
+        .map(|l| Line::from_str(l).map(Line::compress))
+        .collect::<Result<Vec<Line>, _>>()?;
     let lines_2 = lines
         .iter()
         .map(|l| l.clone().convert_to_part_2().compress())
         .collect::<Vec<Line>>();
-    (part_a(&lines, &mut cache), part_b(&lines_2, &mut cache))
+    Ok((lines, lines_2))
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
@@ -26,13 +58,15 @@ enum Spring {
     Good,
 }
 
-impl From<char> for Spring {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Spring {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, ParseError> {
         match value {
-            '#' => Self::Broken,
-            '?' => Self::Unkown,
-            '.' => Self::Good,
-            _ => unreachable!("Unknown spring character."),
+            '#' => Ok(Self::Broken),
+            '?' => Ok(Self::Unkown),
+            '.' => Ok(Self::Good),
+            _ => Err(ParseError::unexpected_char(value)),
         }
     }
 }
@@ -44,13 +78,24 @@ struct Line {
 }
 
 impl FromStr for Line {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> anyhow::Result<Self> {
-        let (springs, pattern) = s.split_once(' ').unwrap();
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let (springs, pattern) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new("expected springs and a pattern"))?;
+        if springs.is_empty() {
+            return Err(ParseError::new("expected at least one spring"));
+        }
         Ok(Line::new(
-            springs.chars().map(Spring::from).collect(),
-            pattern.split(',').map(|c| c.parse().unwrap()).collect(),
+            springs
+                .chars()
+                .map(Spring::try_from)
+                .collect::<Result<_, _>>()?,
+            pattern
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<_, _>>()?,
         ))
     }
 }
@@ -138,7 +183,7 @@ fn slice_is_valid(springs: &[Spring], pattern: &[u64], cur_group_size: u64) -> O
     let mut pattern_idx = 0;
     let mut cur_broken_streak = cur_group_size;
 
-    let min_length_required = pattern.iter().fold(0, |sum, p| (sum + 1 + p));
+    let min_length_required = pattern.iter().fold(0, |sum, p| sum + 1 + p);
     let remaining_locations = cur_broken_streak + springs.len() as u64 + 1;
     if min_length_required > remaining_locations {
         return Some(false);
@@ -1,3 +1,4 @@
+use aoc_common::{Day, ParseError, Solution};
 use std::{
     fmt::{Display, Write},
     str::FromStr,
@@ -6,7 +7,38 @@ use std::{
 use itertools::Itertools;
 
 pub fn solve_day(input: &str) -> (u64, u64) {
-    (part_a(input), part_b(input))
+    let fields = parse_input(input).unwrap();
+    (part_a(&fields), part_b(&fields))
+}
+
+pub const DAY: Day = Day::checked(13, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Field>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(fields: &Self::Parsed<'_>) -> u64 {
+        part_a(fields)
+    }
+
+    fn part_b(fields: &Self::Parsed<'_>) -> u64 {
+        part_b(fields)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Field>, ParseError> {
+    input.trim().split("\n\n").map(Field::from_str).collect()
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -40,12 +72,14 @@ impl Display for Tile {
     }
 }
 
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, ParseError> {
         match c {
-            '#' => Tile::Rock,
-            '.' => Tile::Ash,
-            _ => panic!("Invalid char :{c}"),
+            '#' => Ok(Tile::Rock),
+            '.' => Ok(Tile::Ash),
+            _ => Err(ParseError::unexpected_char(c)),
         }
     }
 }
@@ -80,11 +114,11 @@ impl Line {
 }
 
 impl FromStr for Line {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Line {
-            tiles: s.chars().map(Tile::from).collect(),
+            tiles: s.chars().map(Tile::try_from).try_collect()?,
         })
     }
 }
@@ -94,13 +128,25 @@ struct Field {
     lines: Vec<Line>,
 }
 
+impl Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
 impl FromStr for Field {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Field {
-            lines: s.trim().split('\n').map(Line::from_str).try_collect()?,
-        })
+        let lines: Vec<Line> = s.trim().split('\n').map(Line::from_str).try_collect()?;
+        let width = lines[0].tiles.len();
+        if width == 0 || lines.iter().any(|line| line.tiles.len() != width) {
+            return Err(ParseError::new("a field should be a non-empty rectangle"));
+        }
+        Ok(Field { lines })
     }
 }
 
@@ -194,25 +240,21 @@ impl Field {
     }
 }
 
-fn part_a(input: &str) -> u64 {
-    input
-        .trim()
-        .split("\n\n")
-        .map(|field| Field::from_str(field).unwrap().find_spot().as_u64())
-        .sum()
+fn part_a(fields: &[Field]) -> u64 {
+    fields.iter().map(|field| field.find_spot().as_u64()).sum()
 }
 
-fn part_b(input: &str) -> u64 {
-    input
-        .trim()
-        .split("\n\n")
-        .map(|field| Field::from_str(field).unwrap().find_spot_smudge().as_u64())
+fn part_b(fields: &[Field]) -> u64 {
+    fields
+        .iter()
+        .map(|field| field.find_spot_smudge().as_u64())
         .sum()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::prelude::*;
     use rstest::{fixture, rstest};
 
     #[fixture]
@@ -286,11 +328,32 @@ mod test {
 
     #[rstest]
     fn test_part_a(example_input: &str) {
-        assert_eq!(part_a(example_input), 405)
+        assert_eq!(part_a(&parse_input(example_input).unwrap()), 405)
     }
 
     #[rstest]
     fn test_part_b(example_input: &str) {
-        assert_eq!(part_b(example_input), 400)
+        assert_eq!(part_b(&parse_input(example_input).unwrap()), 400)
+    }
+
+    proptest! {
+        #[test]
+        fn test_field_display_round_trip(
+            rows in prop::collection::vec(prop::collection::vec(prop::bool::ANY, 1..12), 1..12)
+        ) {
+            let width = rows[0].len();
+            let input: String = rows
+                .iter()
+                .map(|row| {
+                    let line: String = (0..width)
+                        .map(|i| if row.get(i).copied().unwrap_or(false) { '#' } else { '.' })
+                        .collect();
+                    line + "\n"
+                })
+                .collect();
+            let field = Field::from_str(&input).unwrap();
+            prop_assert_eq!(field.to_string(), input);
+            prop_assert_eq!(Field::from_str(&field.to_string()).unwrap(), field);
+        }
     }
 }
@@ -1,20 +1,46 @@
+use aoc_common::{state_after, Day, Grid, ParseError, Solution};
 use std::{
-    collections::HashMap,
     fmt::{Display, Write},
     str::FromStr,
 };
 
 pub fn solve_day(input: &str) -> (u64, u64) {
-    let grid = Grid::from_str(input).unwrap();
+    let grid = Platform::from_str(input).unwrap();
     (part_a(grid.clone()), part_b(grid))
 }
 
-fn part_a(mut grid: Grid) -> u64 {
+pub const DAY: Day = Day::checked(14, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Platform;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Platform::from_str(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Platform::from_str(input)
+    }
+
+    fn part_a(grid: &Self::Parsed<'_>) -> u64 {
+        part_a(grid.clone())
+    }
+
+    fn part_b(grid: &Self::Parsed<'_>) -> u64 {
+        part_b(grid.clone())
+    }
+}
+
+fn part_a(mut grid: Platform) -> u64 {
     grid.tilt_north();
     grid.count_weight()
 }
 
-fn part_b(mut grid: Grid) -> u64 {
+fn part_b(mut grid: Platform) -> u64 {
     grid.run_n_cycles(1_000_000_000);
     grid.count_weight()
 }
@@ -26,59 +52,57 @@ enum Tile {
     Empty,
 }
 
-impl From<char> for Tile {
-    fn from(s: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = ParseError;
+
+    fn try_from(s: char) -> Result<Self, ParseError> {
         match s {
-            'O' => Tile::Round,
-            '#' => Tile::Square,
-            '.' => Tile::Empty,
-            _ => panic!("Unkown input: {s}"),
+            'O' => Ok(Tile::Round),
+            '#' => Ok(Tile::Square),
+            '.' => Ok(Tile::Empty),
+            _ => Err(ParseError::unexpected_char(s)),
         }
     }
 }
 
-fn parse_row(input: &str) -> Vec<Tile> {
-    input.chars().map(Tile::from).collect()
-}
-
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
-struct Grid {
-    tiles: Vec<Vec<Tile>>,
+struct Platform {
+    tiles: Grid<Tile>,
 }
 
-impl FromStr for Grid {
-    type Err = anyhow::Error;
+impl FromStr for Platform {
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Grid {
-            tiles: s.trim().split('\n').map(parse_row).collect(),
+        Ok(Platform {
+            tiles: s.trim().parse()?,
         })
     }
 }
 
-impl Display for Grid {
+impl Display for Tile {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.tiles {
-            for tile in row {
-                match tile {
-                    Tile::Round => f.write_char('O')?,
-                    Tile::Square => f.write_char('#')?,
-                    Tile::Empty => f.write_char('.')?,
-                }
-            }
-            f.write_char('\n')?;
+        match self {
+            Tile::Round => f.write_char('O'),
+            Tile::Square => f.write_char('#'),
+            Tile::Empty => f.write_char('.'),
         }
-        Ok(())
     }
 }
 
-impl Grid {
+impl Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.tiles.fmt(f)
+    }
+}
+
+impl Platform {
     fn count_weight(&self) -> u64 {
         let mut sum = 0;
-        let height = self.tiles.len();
-        for col in 0..self.tiles[0].len() {
-            for row in 0..self.tiles.len() {
-                match self.tiles[row][col] {
+        let height = self.tiles.height();
+        for col in 0..self.tiles.width() {
+            for row in 0..self.tiles.height() {
+                match self.tiles[(col, row)] {
                     Tile::Round => sum += height - row,
                     Tile::Square => continue,
                     Tile::Empty => continue,
@@ -89,14 +113,14 @@ impl Grid {
     }
 
     fn tilt_north(&mut self) {
-        for col in 0..self.tiles[0].len() {
+        for col in 0..self.tiles.width() {
             let mut blocker = 0;
-            for row in 0..self.tiles.len() {
-                match self.tiles[row][col] {
+            for row in 0..self.tiles.height() {
+                match self.tiles[(col, row)] {
                     Tile::Round => {
                         // Move to blocker
-                        self.tiles[row][col] = Tile::Empty;
-                        self.tiles[blocker][col] = Tile::Round;
+                        self.tiles[(col, row)] = Tile::Empty;
+                        self.tiles[(col, blocker)] = Tile::Round;
                         // New blocker is incremented
                         blocker += 1
                     }
@@ -108,14 +132,14 @@ impl Grid {
     }
 
     fn tilt_west(&mut self) {
-        for row in 0..self.tiles.len() {
+        for row in 0..self.tiles.height() {
             let mut blocker = 0;
-            for col in 0..self.tiles[0].len() {
-                match self.tiles[row][col] {
+            for col in 0..self.tiles.width() {
+                match self.tiles[(col, row)] {
                     Tile::Round => {
                         // Move to blocker
-                        self.tiles[row][col] = Tile::Empty;
-                        self.tiles[row][blocker] = Tile::Round;
+                        self.tiles[(col, row)] = Tile::Empty;
+                        self.tiles[(blocker, row)] = Tile::Round;
                         // New blocker is incremented
                         blocker += 1
                     }
@@ -127,14 +151,14 @@ impl Grid {
     }
 
     fn tilt_east(&mut self) {
-        for row in 0..self.tiles.len() {
-            let mut blocker = self.tiles[0].len() - 1;
-            for col in (0..self.tiles[0].len()).rev() {
-                match self.tiles[row][col] {
+        for row in 0..self.tiles.height() {
+            let mut blocker = self.tiles.width() - 1;
+            for col in (0..self.tiles.width()).rev() {
+                match self.tiles[(col, row)] {
                     Tile::Round => {
                         // Move to blocker
-                        self.tiles[row][col] = Tile::Empty;
-                        self.tiles[row][blocker] = Tile::Round;
+                        self.tiles[(col, row)] = Tile::Empty;
+                        self.tiles[(blocker, row)] = Tile::Round;
                         // New blocker is incremented
                         blocker = blocker.saturating_sub(1)
                     }
@@ -146,14 +170,14 @@ impl Grid {
     }
 
     fn tilt_south(&mut self) {
-        for col in 0..self.tiles[0].len() {
-            let mut blocker = self.tiles.len() - 1;
-            for row in (0..self.tiles.len()).rev() {
-                match self.tiles[row][col] {
+        for col in 0..self.tiles.width() {
+            let mut blocker = self.tiles.height() - 1;
+            for row in (0..self.tiles.height()).rev() {
+                match self.tiles[(col, row)] {
                     Tile::Round => {
                         // Move to blocker
-                        self.tiles[row][col] = Tile::Empty;
-                        self.tiles[blocker][col] = Tile::Round;
+                        self.tiles[(col, row)] = Tile::Empty;
+                        self.tiles[(col, blocker)] = Tile::Round;
                         // New blocker is incremented
                         blocker = blocker.saturating_sub(1)
                     }
@@ -171,30 +195,19 @@ impl Grid {
         self.tilt_east();
     }
 
-    fn run_n_cycles(&mut self, mut n: u64) {
-        let mut history = HashMap::with_capacity(100);
-        // let mut history_vec = vec![];
-        let mut i = 0;
-        while i < n {
-            if let Some(prev) = history.get(self) {
-                // Todo return the self from history
-                let cycle_length = i - prev;
-                let cycles_left = (n - i) / cycle_length;
-                let cycles_todo = (n - i) - cycle_length * cycles_left;
-                n = i + cycles_todo;
-            } else {
-                history.insert(self.clone(), i);
-            }
-            self.run_cycle();
-
-            i += 1;
-        }
+    fn run_n_cycles(&mut self, n: u64) {
+        *self = state_after(self.clone(), n as usize, |platform| {
+            let mut platform = platform.clone();
+            platform.run_cycle();
+            platform
+        });
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::prelude::*;
     use rstest::{fixture, rstest};
 
     #[fixture]
@@ -202,16 +215,20 @@ mod test {
         return "O....#....\nO.OO#....#\n.....##...\nOO.#O....O\n.O.....O#.\nO.#..O.#.#\n..O..#O..O\n.......O..\n#....###..\n#OO..#....\n";
     }
 
+    fn parse_row(input: &str) -> Vec<Tile> {
+        input.chars().map(|c| Tile::try_from(c).unwrap()).collect()
+    }
+
     #[rstest]
     #[case("O.#", vec![Tile::Round, Tile::Empty, Tile::Square])]
     fn test_parse_row(#[case] input: &str, #[case] expected: Vec<Tile>) {
-        assert_eq!(parse_row(input), expected)
+        assert_eq!(Platform::from_str(input).unwrap().tiles.row(0), expected)
     }
 
     #[rstest]
     fn test_parse_example(example_input: &str) {
-        let expected = Grid {
-            tiles: vec![
+        let expected = Platform {
+            tiles: Grid::from_rows(vec![
                 parse_row("O....#...."),
                 parse_row("O.OO#....#"),
                 parse_row(".....##..."),
@@ -222,30 +239,31 @@ mod test {
                 parse_row(".......O.."),
                 parse_row("#....###.."),
                 parse_row("#OO..#...."),
-            ],
+            ])
+            .unwrap(),
         };
-        assert_eq!(Grid::from_str(example_input).unwrap(), expected)
+        assert_eq!(Platform::from_str(example_input).unwrap(), expected)
     }
 
     #[fixture]
-    fn example_grid(example_input: &str) -> Grid {
-        Grid::from_str(example_input).unwrap()
+    fn example_grid(example_input: &str) -> Platform {
+        Platform::from_str(example_input).unwrap()
     }
 
     #[rstest]
-    fn tilt_north(mut example_grid: Grid) {
+    fn tilt_north(mut example_grid: Platform) {
         println!("{example_grid}");
         println!("...");
         example_grid.tilt_north();
         println!("{example_grid}");
         assert_eq!(
             example_grid,
-            Grid::from_str("OOOO.#.O..\nOO..#....#\nOO..O##..O\nO..#.OO...\n........#.\n..#....#.#\n..O..#.O.O\n..O.......\n#....###..\n#....#....").unwrap()
+            Platform::from_str("OOOO.#.O..\nOO..#....#\nOO..O##..O\nO..#.OO...\n........#.\n..#....#.#\n..O..#.O.O\n..O.......\n#....###..\n#....#....").unwrap()
         )
     }
 
     #[rstest]
-    fn test_count_weight(mut example_grid: Grid) {
+    fn test_count_weight(mut example_grid: Platform) {
         example_grid.tilt_north();
         assert_eq!(example_grid.count_weight(), 136)
     }
@@ -261,7 +279,7 @@ mod test {
     #[case("O.#", "O.#")]
     #[case("O.O#", "OO.#")]
     #[case("OOOO.#.O..", "OOOO.#O...")]
-    fn test_tilt_west(#[case] mut pre_tilt: Grid, #[case] expected: Grid) {
+    fn test_tilt_west(#[case] mut pre_tilt: Platform, #[case] expected: Platform) {
         println!("pre_tilt:\n{pre_tilt}");
         pre_tilt.tilt_west();
         // Ensure order does not matter
@@ -284,7 +302,7 @@ mod test {
     #[case("#O..O###..", "#..OO###..")]
     #[case("#O..O#O.O.", "#..OO#..OO")]
     // #[case(".....#....\n....#.O..#\n.....##...\n..O#......\nO.O....O#.\nO.#..O.#.#\nO....#O...\nO.....OO..\n#O..O###..\n#O.OO#..O.\n", "")]
-    fn test_tilt_east(#[case] mut pre_cycle: Grid, #[case] expected: Grid) {
+    fn test_tilt_east(#[case] mut pre_cycle: Platform, #[case] expected: Platform) {
         println!("pre_cycle:\n{pre_cycle}");
         pre_cycle.tilt_east();
         // Ensure order does not matter
@@ -300,7 +318,7 @@ mod test {
     #[case("#O#\nO#O\nOOO", "#O#\nO#O\nOOO")]
     #[case("#\nO\nO", "#\nO\nO")]
     #[case("#\nO\n#", "#\nO\n#")]
-    fn test_tilt_south(#[case] mut pre_tilt: Grid, #[case] expected: Grid) {
+    fn test_tilt_south(#[case] mut pre_tilt: Platform, #[case] expected: Platform) {
         println!("pre_tilt:\n{pre_tilt}");
         pre_tilt.tilt_south();
         // Ensure order does not matter
@@ -313,7 +331,7 @@ mod test {
     #[case("O....#....\nO.OO#....#\n.....##...\nOO.#O....O\n.O.....O#.\nO.#..O.#.#\n..O..#O..O\n.......O..\n#....###..\n#OO..#....", ".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n.O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#....")]
     #[case(".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n.O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#....", ".....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n.O#...O#.#\n....O#...O\n.......OOO\n#..OO###..\n#.OOO#...O")]
     #[case(".....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n.O#...O#.#\n....O#...O\n.......OOO\n#..OO###..\n#.OOO#...O", ".....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n.O#...O#.#\n....O#...O\n.......OOO\n#...O###.O\n#.OOO#...O")]
-    fn test_cycle(#[case] mut before: Grid, #[case] after: Grid) {
+    fn test_cycle(#[case] mut before: Platform, #[case] after: Platform) {
         before.run_cycle();
 
         println!("Before:\n{before}");
@@ -322,12 +340,79 @@ mod test {
     }
 
     #[rstest]
-    fn test_part_a(example_grid: Grid) {
+    fn test_part_a(example_grid: Platform) {
         assert_eq!(part_a(example_grid), 136);
     }
 
     #[rstest]
-    fn test_part_b(example_grid: Grid) {
+    fn test_part_b(example_grid: Platform) {
         assert_eq!(part_b(example_grid), 64);
     }
+
+    fn grid_strategy() -> impl Strategy<Value = Platform> {
+        (1..10usize, 1..10usize).prop_flat_map(|(height, width)| {
+            let row = prop::collection::vec(
+                prop::sample::select(vec![Tile::Round, Tile::Square, Tile::Empty]),
+                width,
+            );
+            prop::collection::vec(row, height).prop_map(|tiles| Platform {
+                tiles: Grid::from_rows(tiles).unwrap(),
+            })
+        })
+    }
+
+    fn count(grid: &Platform, tile: Tile) -> usize {
+        grid.tiles.cells().iter().filter(|t| **t == tile).count()
+    }
+
+    proptest! {
+        #[test]
+        fn test_grid_display_round_trip(grid in grid_strategy()) {
+            prop_assert_eq!(Platform::from_str(&grid.to_string()).unwrap(), grid);
+        }
+
+        #[test]
+        fn test_tilts_only_move_round_rocks(grid in grid_strategy()) {
+            let tilts: [fn(&mut Platform); 4] =
+                [Platform::tilt_north, Platform::tilt_west, Platform::tilt_south, Platform::tilt_east];
+            for tilt in tilts {
+                let mut tilted = grid.clone();
+                tilt(&mut tilted);
+                prop_assert_eq!(count(&tilted, Tile::Round), count(&grid, Tile::Round));
+                for (row, tilted_row) in grid.tiles.rows().zip(tilted.tiles.rows()) {
+                    for (tile, tilted_tile) in row.iter().zip(tilted_row) {
+                        prop_assert_eq!(*tile == Tile::Square, *tilted_tile == Tile::Square);
+                    }
+                }
+
+                let mut twice = tilted.clone();
+                tilt(&mut twice);
+                prop_assert_eq!(twice, tilted);
+            }
+        }
+
+        #[test]
+        fn test_tilt_north_never_loses_weight(grid in grid_strategy()) {
+            let mut tilted = grid.clone();
+            tilted.tilt_north();
+            prop_assert!(tilted.count_weight() >= grid.count_weight());
+        }
+
+        #[test]
+        fn test_run_n_cycles_matches_single_cycles(grid in grid_strategy(), n in 0..40u64) {
+            let mut expected = grid.clone();
+            for _ in 0..n {
+                expected.run_cycle();
+            }
+            let mut fast = grid;
+            fast.run_n_cycles(n);
+            prop_assert_eq!(fast, expected);
+        }
+
+        #[test]
+        fn test_solve_day_matches_slow(grid in grid_strategy()) {
+            let input = grid.to_string();
+            prop_assert_eq!(solve_day(&input), super::super::day_14_slow::solve_day(&input));
+        }
+    }
 }
@@ -5,7 +5,6 @@ use std::{
 };
 
 use itertools::Itertools;
-use tqdm::Iter;
 
 pub fn solve_day(input: &str) -> (u64, u64) {
     let mut plane = Plane::from_str(input).unwrap();
@@ -73,8 +72,7 @@ impl Display for Plane {
                 if let Some(rock) = self
                     .rocks
                     .iter()
-                    .filter(|r| r.loc() == &mut Location { x, y })
-                    .next()
+                    .find(|r| r.loc() == &mut Location { x, y })
                 {
                     match rock {
                         Rock::Round(_) => f.write_char('O')?,
@@ -99,6 +97,7 @@ impl Plane {
         }
     }
 
+    #[cfg(test)]
     fn sort(&mut self) {
         self.rocks.sort();
     }
@@ -116,7 +115,7 @@ impl Plane {
                         return Some(loc.y);
                     }
                 }
-                return None;
+                None
             })
             .collect()
     }
@@ -130,7 +129,7 @@ impl Plane {
                         return Some(loc.x);
                     }
                 }
-                return None;
+                None
             })
             .collect()
     }
@@ -144,21 +143,7 @@ impl Plane {
                         return Some(loc.y);
                     }
                 }
-                return None;
-            })
-            .collect()
-    }
-
-    fn get_col_static(&self, col_idx: usize) -> Vec<&Rock> {
-        self.rocks
-            .iter()
-            .filter_map(|r| {
-                if let Rock::Square(loc) = r {
-                    if loc.x == col_idx {
-                        return Some(r);
-                    }
-                }
-                return None;
+                None
             })
             .collect()
     }
@@ -172,21 +157,7 @@ impl Plane {
                         return Some(r);
                     }
                 }
-                return None;
-            })
-            .collect()
-    }
-
-    fn get_row_static(&self, row_idx: usize) -> Vec<&Rock> {
-        self.rocks
-            .iter()
-            .filter_map(|r| {
-                if let Rock::Square(loc) = r {
-                    if loc.x == row_idx {
-                        return Some(r);
-                    }
-                }
-                return None;
+                None
             })
             .collect()
     }
@@ -200,7 +171,7 @@ impl Plane {
                         return Some(r);
                     }
                 }
-                return None;
+                None
             })
             .collect()
     }
@@ -212,7 +183,7 @@ impl Plane {
         let (_, _, tot_weight) = rounds
             .iter()
             // The round rocks cannot be ontop of square rocks
-            .map(|y| (&squares).binary_search(y).err().unwrap())
+            .map(|y| squares.binary_search(y).err().unwrap())
             .fold(
                 (self.height, 0, 0),
                 |(mut h, prev_idx, mut tot_weight), idx| {
@@ -220,7 +191,7 @@ impl Plane {
                         tot_weight += h;
                         h -= 1;
                     } else {
-                        h = self.height - &squares[idx - 1] - 1;
+                        h = self.height - squares[idx - 1] - 1;
                         tot_weight += h;
                         h -= 1;
                     }
@@ -255,7 +226,7 @@ impl Plane {
         blocked.sort();
         for round in rounds {
             let loc = round.loc_mut();
-            let idx = match (&blocked).binary_search(&loc.y) {
+            let idx = match blocked.binary_search(&loc.y) {
                 // The current location of this rock is overtaken by anoter round rock
                 // => We need to buble it up on top of the cur stack
                 Ok(mut idx) => {
@@ -296,7 +267,7 @@ impl Plane {
         blocked.sort();
         for round in rounds {
             let loc = round.loc_mut();
-            let idx = match (&blocked).binary_search(&loc.x) {
+            let idx = match blocked.binary_search(&loc.x) {
                 // The current location of this rock is overtaken by anoter round rock
                 // => We need to buble it up on top of the cur stack
                 Ok(mut idx) => {
@@ -338,7 +309,7 @@ impl Plane {
         blocked.sort();
         for round in rounds {
             let loc = round.loc_mut();
-            let idx = match (&blocked).binary_search(&loc.y) {
+            let idx = match blocked.binary_search(&loc.y) {
                 // The current location of this rock is overtaken by anoter round rock
                 // => We need to buble it up on top of the cur stack
                 Ok(mut idx) => {
@@ -377,7 +348,7 @@ impl Plane {
         for round in rounds {
             let loc = round.loc_mut();
 
-            let idx = match (&blocked).binary_search(&loc.x) {
+            let idx = match blocked.binary_search(&loc.x) {
                 // The current location of this rock is overtaken by anoter round rock
                 // => We need to buble it up on top of the cur stack
                 Ok(mut idx) => {
@@ -419,7 +390,9 @@ impl Plane {
                 let cycles_left = (n - i) / cycle_length;
                 let cycles_todo = (n - i) - cycle_length * cycles_left;
                 n = i + cycles_todo;
-                println!("Cycle detected!")
+                if i == n {
+                    break;
+                }
             } else {
                 history.insert(self.clone(), i);
             }
@@ -428,7 +401,6 @@ impl Plane {
             if before_count != after {
                 panic!();
             }
-            print!("Finished: {i}\r");
             i += 1;
         }
     }
@@ -452,11 +424,7 @@ impl FromStr for Plane {
                 .flat_map(|(y, row)| parse_row(row, y))
                 .collect(),
             s.trim().split('\n').count(),
-            s.split_once('\n')
-                .unwrap_or_else(|| (s, ""))
-                .0
-                .chars()
-                .count(),
+            s.split_once('\n').unwrap_or((s, "")).0.chars().count(),
         ))
     }
 }
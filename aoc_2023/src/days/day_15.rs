@@ -1,9 +1,32 @@
+use aoc_common::{Day, Solution};
 use std::{fmt::Debug, rc::Rc};
 
 pub fn solve_day(input: &str) -> (u32, u32) {
     (part_a(input), part_b(input))
 }
 
+pub const DAY: Day = Day::new(15, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u32 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u32 {
+        part_b(input)
+    }
+}
+
 fn part_a(input: &str) -> u32 {
     input.trim().split(',').map(hash).sum()
 }
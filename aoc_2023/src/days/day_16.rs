@@ -1,22 +1,47 @@
+use aoc_common::{Day, Direction, Grid, Pos, Solution};
+
 pub fn solve_day(input: &str) -> (u64, u64) {
-    let grid = Grid::from(input);
+    let grid = Contraption::from(input);
     (part_a(grid.clone()), part_b(grid))
 }
 
-fn part_a(mut grid: Grid) -> u64 {
+pub const DAY: Day = Day::new(16, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Contraption;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Contraption::from(input)
+    }
+
+    fn part_a(grid: &Self::Parsed<'_>) -> u64 {
+        part_a(grid.clone())
+    }
+
+    fn part_b(grid: &Self::Parsed<'_>) -> u64 {
+        part_b(grid.clone())
+    }
+}
+
+fn part_a(mut grid: Contraption) -> u64 {
     let beam = LightBeam {
-        dir: Direction::East,
+        dir: Direction::Right,
         location: (0, 0),
     };
     grid.shine_light(beam);
     grid.count_energized()
 }
 
-fn part_b(grid: Grid) -> u64 {
-    let top_beams: u64 = (0..grid.grid[0].len())
-        .map(|y| LightBeam {
-            dir: Direction::North,
-            location: (0, y),
+fn part_b(grid: Contraption) -> u64 {
+    let (width, height) = (grid.tiles.width(), grid.tiles.height());
+    let top_beams: u64 = (0..width)
+        .map(|x| LightBeam {
+            dir: Direction::Down,
+            location: (x, 0),
         })
         .map(|beam| {
             let mut g = grid.clone();
@@ -25,10 +50,10 @@ fn part_b(grid: Grid) -> u64 {
         })
         .max()
         .unwrap();
-    let bot_beams = (0..grid.grid[0].len())
-        .map(|y| LightBeam {
-            dir: Direction::South,
-            location: (grid.grid.len() - 1, y),
+    let bot_beams = (0..width)
+        .map(|x| LightBeam {
+            dir: Direction::Up,
+            location: (x, height - 1),
         })
         .map(|beam| {
             let mut g = grid.clone();
@@ -37,10 +62,10 @@ fn part_b(grid: Grid) -> u64 {
         })
         .max()
         .unwrap();
-    let left_beams = (0..grid.grid.len())
-        .map(|x| LightBeam {
-            dir: Direction::East,
-            location: (x, 0),
+    let left_beams = (0..height)
+        .map(|y| LightBeam {
+            dir: Direction::Right,
+            location: (0, y),
         })
         .map(|beam| {
             let mut g = grid.clone();
@@ -49,10 +74,10 @@ fn part_b(grid: Grid) -> u64 {
         })
         .max()
         .unwrap();
-    let right_beams = (0..grid.grid.len())
-        .map(|x| LightBeam {
-            dir: Direction::West,
-            location: (x, grid.grid[0].len() - 1),
+    let right_beams = (0..height)
+        .map(|y| LightBeam {
+            dir: Direction::Left,
+            location: (width - 1, y),
         })
         .map(|beam| {
             let mut g = grid.clone();
@@ -103,24 +128,24 @@ impl Mirror {
         match self {
             Mirror::Empty => vec![direction],
             Mirror::Left => match direction {
-                Direction::North => vec![Direction::East],
-                Direction::East => vec![Direction::North],
-                Direction::South => vec![Direction::West],
-                Direction::West => vec![Direction::South],
+                Direction::Down => vec![Direction::Right],
+                Direction::Right => vec![Direction::Down],
+                Direction::Up => vec![Direction::Left],
+                Direction::Left => vec![Direction::Up],
             },
             Mirror::Right => match direction {
-                Direction::North => vec![Direction::West],
-                Direction::East => vec![Direction::South],
-                Direction::South => vec![Direction::East],
-                Direction::West => vec![Direction::North],
+                Direction::Down => vec![Direction::Left],
+                Direction::Right => vec![Direction::Up],
+                Direction::Up => vec![Direction::Right],
+                Direction::Left => vec![Direction::Down],
             },
             Mirror::Horizontal => match direction {
-                Direction::North | Direction::South => vec![Direction::East, Direction::West],
-                Direction::East | Direction::West => vec![direction],
+                Direction::Down | Direction::Up => vec![Direction::Right, Direction::Left],
+                Direction::Right | Direction::Left => vec![direction],
             },
             Mirror::Vertical => match direction {
-                Direction::North | Direction::South => vec![direction],
-                Direction::East | Direction::West => vec![Direction::North, Direction::South],
+                Direction::Down | Direction::Up => vec![direction],
+                Direction::Right | Direction::Left => vec![Direction::Down, Direction::Up],
             },
         }
     }
@@ -129,74 +154,34 @@ impl Mirror {
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 struct Tile {
     mirror: Mirror,
-    north_light: bool,
-    west_light: bool,
-    south_light: bool,
-    east_light: bool,
+    /// Whether a beam went through, for each direction.
+    lit: [bool; 4],
 }
 
 impl Tile {
     fn new(mirror: Mirror) -> Self {
         Tile {
             mirror,
-            north_light: false,
-            west_light: false,
-            south_light: false,
-            east_light: false,
+            lit: [false; 4],
         }
     }
 
     fn is_energized(&self) -> u64 {
-        if self.north_light | self.west_light | self.south_light | self.east_light {
-            return 1;
-        }
-        0
+        self.lit.contains(&true) as u64
     }
 
     fn shine_light(&mut self, beam: LightBeam) -> Vec<LightBeam> {
-        match beam.dir {
-            Direction::North => {
-                if self.north_light {
-                    return vec![];
-                } else {
-                    self.north_light = true;
-                }
-            }
-            Direction::East => {
-                if self.east_light {
-                    return vec![];
-                } else {
-                    self.east_light = true;
-                }
-            }
-            Direction::South => {
-                if self.south_light {
-                    return vec![];
-                } else {
-                    self.south_light = true;
-                }
-            }
-            Direction::West => {
-                if self.west_light {
-                    return vec![];
-                } else {
-                    self.west_light = true;
-                }
-            }
+        if std::mem::replace(&mut self.lit[beam.dir as usize], true) {
+            return vec![];
         }
 
         self.mirror
             .scatter(beam.dir)
-            .iter()
-            .map(|d| (d, d.as_offset()))
-            .filter_map(|(dir, (x, y))| {
-                let location = (
-                    (beam.location.0 as isize + x).try_into().ok()?,
-                    (beam.location.1 as isize + y).try_into().ok()?,
-                );
+            .into_iter()
+            .filter_map(|dir| {
                 Some(LightBeam {
-                    dir: *dir,
-                    location,
+                    dir,
+                    location: dir.step(beam.location)?,
                 })
             })
             .collect()
@@ -210,77 +195,46 @@ impl From<char> for Tile {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Grid {
-    grid: Vec<Vec<Tile>>,
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-impl Direction {
-    fn as_offset(&self) -> (isize, isize) {
-        match self {
-            Direction::North => (1, 0),
-            Direction::East => (0, 1),
-            Direction::South => (-1, 0),
-            Direction::West => (0, -1),
-        }
-    }
+struct Contraption {
+    tiles: Grid<Tile>,
 }
 
 #[derive(Debug)]
 struct LightBeam {
     dir: Direction,
-    location: (usize, usize),
+    location: Pos,
 }
 
-impl Grid {
+impl Contraption {
     fn shine_light(&mut self, start_beam: LightBeam) {
         let mut stack = vec![start_beam];
 
         while let Some(beam) = stack.pop() {
-            let loc = beam.location;
-            if let Some(row) = self.grid.get_mut(loc.0) {
-                if let Some(tile) = row.get_mut(loc.1) {
-                    let beams = tile.shine_light(beam);
-                    for b in beams {
-                        stack.insert(0, b);
-                    }
+            if let Some(tile) = self.tiles.get_mut(beam.location) {
+                let beams = tile.shine_light(beam);
+                for b in beams {
+                    stack.insert(0, b);
                 }
             }
 
-            if stack.len() > self.grid.len() * self.grid[0].len() {
+            if stack.len() > self.tiles.width() * self.tiles.height() {
                 panic!()
             }
         }
     }
 
     fn count_energized(&self) -> u64 {
-        let x = self
-            .grid
-            .iter()
-            .map(|r| r.iter().map(|t| t.is_energized()).sum::<u64>())
-            .sum();
-        x
+        self.tiles.cells().iter().map(|t| t.is_energized()).sum()
     }
 }
 
-impl From<&str> for Grid {
+impl From<&str> for Contraption {
     fn from(value: &str) -> Self {
-        let grid = value.trim().split('\n').map(parse_row).collect();
-        Grid { grid }
+        let tiles = Grid::parse_with(value.trim(), |c| Ok(Tile::from(c))).unwrap();
+        Contraption { tiles }
     }
 }
 
-fn parse_row(row: &str) -> Vec<Tile> {
-    row.chars().map(Tile::from).collect()
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -292,20 +246,24 @@ mod test {
     }
 
     #[fixture]
-    fn example_grid(example_input: &str) -> Grid {
-        Grid::from(example_input)
+    fn example_grid(example_input: &str) -> Contraption {
+        Contraption::from(example_input)
+    }
+
+    fn parse_row(row: &str) -> Vec<Tile> {
+        row.chars().map(Tile::from).collect()
     }
 
     #[rstest]
     #[case(".\\/-|", vec![Tile::new(Mirror::Empty), Tile::new(Mirror::Left), Tile::new(Mirror::Right), Tile::new(Mirror::Horizontal), Tile::new(Mirror::Vertical)])]
     fn test_parse_row(#[case] row_str: &str, #[case] expected: Vec<Tile>) {
-        assert_eq!(parse_row(row_str), expected)
+        assert_eq!(Contraption::from(row_str).tiles.row(0), expected)
     }
 
     #[rstest]
     fn test_parse_grid(example_input: &str) {
-        let expected = Grid {
-            grid: vec![
+        let expected = Contraption {
+            tiles: Grid::from_rows(vec![
                 parse_row(".|...\\...."),
                 parse_row("|.-.\\....."),
                 parse_row(".....|-..."),
@@ -316,15 +274,16 @@ mod test {
                 parse_row(".-.-/..|.."),
                 parse_row(".|....-|.\\"),
                 parse_row("..//.|...."),
-            ],
+            ])
+            .unwrap(),
         };
-        assert_eq!(Grid::from(example_input), expected)
+        assert_eq!(Contraption::from(example_input), expected)
     }
 
     #[rstest]
-    fn test_energize(mut example_grid: Grid) {
+    fn test_energize(mut example_grid: Contraption) {
         let beam = LightBeam {
-            dir: Direction::East,
+            dir: Direction::Right,
             location: (0, 0),
         };
         example_grid.shine_light(beam);
@@ -333,12 +292,12 @@ mod test {
     }
 
     #[rstest]
-    fn test_part_a(example_grid: Grid) {
+    fn test_part_a(example_grid: Contraption) {
         assert_eq!(part_a(example_grid), 46);
     }
 
     #[rstest]
-    fn test_part_b(example_grid: Grid) {
+    fn test_part_b(example_grid: Contraption) {
         assert_eq!(part_b(example_grid), 51);
     }
 }
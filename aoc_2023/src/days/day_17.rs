@@ -1,98 +1,50 @@
-use std::{
-    collections::{BinaryHeap, HashMap},
-    str::FromStr,
-};
+use aoc_common::{astar, Coord, Day, Direction, Grid, Pos, Solution};
+use std::str::FromStr;
 
 pub fn solve_day(input: &str) -> (u64, u64) {
     let city_blocks = CityBlocks::from(input);
     (part_a(&city_blocks), part_b(&city_blocks))
 }
 
-fn part_a(city_blocks: &CityBlocks) -> u64 {
-    let x = city_blocks.blocks.len() - 1;
-    let y = city_blocks.blocks[0].len() - 1;
-    city_blocks.a_star_part_1((0, 0), (x, y))
-}
-
-fn part_b(city_blocks: &CityBlocks) -> u64 {
-    let x = city_blocks.blocks.len() - 1;
-    let y = city_blocks.blocks[0].len() - 1;
-    city_blocks.a_star_part_2((0, 0), (x, y))
-}
-
-fn parse_row(input: &str) -> Vec<CityBlock> {
-    input.trim().chars().map(CityBlock::from).collect()
-}
-
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-struct State {
-    cost: u64,
-    node: Node,
-}
-
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-struct Node {
-    straight_streak: u8,
-    loc: (usize, usize),
-    dir: Direction,
-}
+pub const DAY: Day = Day::new(17, &Solver);
 
-fn add_dir_to_loc(loc: (usize, usize), dir: Direction) -> anyhow::Result<(usize, usize)> {
-    let offset = dir.as_offset();
+struct Solver;
 
-    Ok((
-        (loc.0 as isize + offset.0).try_into()?,
-        (loc.1 as isize + offset.1).try_into()?,
-    ))
-}
+impl Solution for Solver {
+    type Parsed<'a> = CityBlocks;
+    type AnswerA = u64;
+    type AnswerB = u64;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum Direction {
-    North,
-    West,
-    South,
-    East,
-}
-
-impl Direction {
-    fn left(&self) -> Self {
-        match self {
-            Direction::North => Direction::West,
-            Direction::West => Direction::South,
-            Direction::South => Direction::East,
-            Direction::East => Direction::North,
-        }
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        CityBlocks::from(input)
     }
 
-    fn right(&self) -> Self {
-        match self {
-            Direction::North => Direction::East,
-            Direction::West => Direction::North,
-            Direction::South => Direction::West,
-            Direction::East => Direction::South,
-        }
+    fn part_a(city_blocks: &Self::Parsed<'_>) -> u64 {
+        part_a(city_blocks)
     }
 
-    fn as_offset(&self) -> (isize, isize) {
-        match self {
-            Direction::North => (-1, 0),
-            Direction::East => (0, 1),
-            Direction::South => (1, 0),
-            Direction::West => (0, -1),
-        }
+    fn part_b(city_blocks: &Self::Parsed<'_>) -> u64 {
+        part_b(city_blocks)
     }
 }
 
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        other.cost.cmp(&self.cost)
-    }
+fn part_a(city_blocks: &CityBlocks) -> u64 {
+    let x = city_blocks.blocks.width() - 1;
+    let y = city_blocks.blocks.height() - 1;
+    city_blocks.a_star_part_1((0, 0), (x, y))
 }
 
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
+fn part_b(city_blocks: &CityBlocks) -> u64 {
+    let x = city_blocks.blocks.width() - 1;
+    let y = city_blocks.blocks.height() - 1;
+    city_blocks.a_star_part_2((0, 0), (x, y))
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Node {
+    straight_streak: u8,
+    loc: Pos,
+    dir: Direction,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -110,13 +62,13 @@ impl From<char> for CityBlock {
 
 #[derive(Debug, PartialEq, Eq)]
 struct CityBlocks {
-    blocks: Vec<Vec<CityBlock>>,
+    blocks: Grid<CityBlock>,
 }
 
 impl From<&str> for CityBlocks {
     fn from(value: &str) -> Self {
         CityBlocks {
-            blocks: value.trim().split('\n').map(parse_row).collect(),
+            blocks: Grid::parse_with(value.trim(), |c| Ok(CityBlock::from(c))).unwrap(),
         }
     }
 }
@@ -130,149 +82,87 @@ impl FromStr for CityBlocks {
 }
 
 impl CityBlocks {
-    fn a_star_part_2(&self, _start: (usize, usize), goal: (usize, usize)) -> u64 {
-        let node = Node {
-            dir: Direction::East,
-            straight_streak: 0,
-            loc: (0, 0),
-        };
-        let mut heap = BinaryHeap::from([State { cost: 0, node }]);
-        let mut visited = HashMap::from([(node, 0)]);
-        let mut came_from = HashMap::new();
-
-        while let Some(current) = heap.pop() {
-            let node = current.node;
-            if node.loc == goal
-                && node.dir == Direction::East
-                && (node.straight_streak > 3 || node.straight_streak == 0)
-            {
-                // print_path(&came_from, &current);
-                return current.cost;
-            }
-
-            for neighbor in self.get_neighbors_p2(current) {
-                let prev_cost = visited.get(&neighbor.node).unwrap_or(&u64::MAX);
-                if prev_cost > &neighbor.cost {
-                    heap.push(neighbor);
-                    came_from.insert(neighbor, current);
-                    visited.insert(neighbor.node, neighbor.cost);
+    fn a_star_part_2(&self, start: Pos, goal: Pos) -> u64 {
+        self.least_heat_loss(
+            start,
+            goal,
+            |node| {
+                let mut dirs = Vec::with_capacity(3);
+                if node.straight_streak < 10 {
+                    dirs.push(node.dir);
                 }
-            }
-        }
-        unreachable!()
-    }
-
-    fn get_neighbors_p2(&self, state: State) -> Vec<State> {
-        let mut neighbors = Vec::with_capacity(3);
-        let dir = state.node.dir;
-
-        if state.node.straight_streak < 9 {
-            if let Some(s) = self.create_state(&state, dir) {
-                neighbors.push(s)
-            }
-        }
-
-        if state.node.straight_streak > 2 {
-            if let Some(s) = self.create_state(&state, dir.left()) {
-                neighbors.push(s)
-            }
-            if let Some(s) = self.create_state(&state, dir.right()) {
-                neighbors.push(s)
-            }
-        }
-
-        neighbors
-    }
-
-    fn a_star_part_1(&self, _start: (usize, usize), goal: (usize, usize)) -> u64 {
-        let node = Node {
-            dir: Direction::East,
-            straight_streak: 0,
-            loc: (0, 0),
-        };
-        let mut heap = BinaryHeap::from([State { cost: 0, node }]);
-        let mut visited = HashMap::from([(node, 0)]);
-
-        while let Some(current) = heap.pop() {
-            let node = current.node;
-            if node.loc == goal && node.dir == Direction::East {
-                return current.cost;
-            }
-
-            for neighbor in self.get_neighbors(current) {
-                let prev_cost = visited.get(&neighbor.node).unwrap_or(&u64::MAX);
-                if prev_cost > &neighbor.cost {
-                    heap.push(neighbor);
-                    visited.insert(neighbor.node, neighbor.cost);
+                if node.straight_streak >= 4 {
+                    dirs.extend([node.dir.turn_left(), node.dir.turn_right()]);
                 }
-            }
-        }
-        unreachable!()
-    }
-
-    fn get_neighbors(&self, state: State) -> Vec<State> {
-        let mut neighbors = Vec::with_capacity(3);
-        let dir = state.node.dir;
-        // Turn left
-        if let Some(state) = self.create_state(&state, dir.left()) {
-            neighbors.push(state)
-        }
-
-        // Turn right
-        if let Some(s) = self.create_state(&state, dir.right()) {
-            neighbors.push(s)
-        }
-
-        if state.node.straight_streak < 3 {
-            // Go straight
-            if let Some(s) = self.create_state(&state, dir) {
-                neighbors.push(s)
-            }
-        }
-        neighbors
-    }
-
-    fn create_state(&self, prev_state: &State, dir: Direction) -> Option<State> {
-        let node = prev_state.node;
-        let loc = add_dir_to_loc(node.loc, dir).ok()?;
-        let cost = self.get_cost(loc)?;
-        let straight_streak;
-        if prev_state.node.dir == dir {
-            straight_streak = prev_state.node.straight_streak;
-        } else {
-            straight_streak = 0;
-        }
-
-        return Some(State {
-            cost: prev_state.cost + cost,
-            node: Node {
-                straight_streak: straight_streak + 1,
-                loc,
-                dir,
+                dirs
             },
+            |node| node.straight_streak >= 4,
+        )
+    }
+
+    fn a_star_part_1(&self, start: Pos, goal: Pos) -> u64 {
+        self.least_heat_loss(
+            start,
+            goal,
+            |node| {
+                let mut dirs = vec![node.dir.turn_left(), node.dir.turn_right()];
+                if node.straight_streak < 3 {
+                    dirs.push(node.dir);
+                }
+                dirs
+            },
+            |_| true,
+        )
+    }
+
+    /// A* from `start` to `goal`, where `turns` gives the directions the
+    /// crucible may go in next and `can_stop` whether it may stop at the goal.
+    fn least_heat_loss(
+        &self,
+        start: Pos,
+        goal: Pos,
+        turns: impl Fn(&Node) -> Vec<Direction>,
+        can_stop: impl Fn(&Node) -> bool,
+    ) -> u64 {
+        // The crucible may set off to the right or down.
+        let starts = [Direction::Right, Direction::Down].map(|dir| Node {
+            dir,
+            straight_streak: 0,
+            loc: start,
         });
+        let search = astar(
+            starts,
+            |node| {
+                turns(node)
+                    .into_iter()
+                    .filter_map(|dir| self.step(node, dir))
+                    .collect::<Vec<_>>()
+            },
+            |node| Coord::from(node.loc).manhattan(Coord::from(goal)) as u64,
+            |node| node.loc == goal && can_stop(node),
+        );
+        search.goal_cost().unwrap()
     }
 
-    fn get_cost(&self, loc: (usize, usize)) -> Option<u64> {
-        if let Some(row) = self.blocks.get(loc.0) {
-            if let Some(block) = row.get(loc.1) {
-                return Some(block.heat_loss);
-            }
-        }
-        None
-    }
-}
-
-fn print_path(came_from: &HashMap<State, State>, state: &State) {
-    if let Some(prev_state) = came_from.get(state) {
-        print_path(came_from, prev_state);
+    fn step(&self, node: &Node, dir: Direction) -> Option<(Node, u64)> {
+        let loc = self.blocks.neighbour(node.loc, dir.offset())?;
+        let straight_streak = if node.dir == dir {
+            node.straight_streak
+        } else {
+            0
+        };
+        let next = Node {
+            straight_streak: straight_streak + 1,
+            loc,
+            dir,
+        };
+        Some((next, self.blocks[loc].heat_loss))
     }
-    println!("{:?}", state);
 }
 
 #[cfg(test)]
 mod test {
-    use crate::days::read_day_input;
+    use crate::days::read_day_input_if_present;
 
     use super::*;
     use rstest::{fixture, rstest};
@@ -296,16 +186,20 @@ mod test {
         CityBlocks::from(example_input)
     }
 
+    fn parse_row(input: &str) -> Vec<CityBlock> {
+        input.trim().chars().map(CityBlock::from).collect()
+    }
+
     #[rstest]
     #[case("1234", vec![CityBlock::from('1'), CityBlock::from('2'), CityBlock::from('3'), CityBlock::from('4')])]
     fn test_parse_row(#[case] input: &str, #[case] expected: Vec<CityBlock>) {
-        assert_eq!(parse_row(input), expected)
+        assert_eq!(CityBlocks::from(input).blocks.row(0), expected)
     }
 
     #[rstest]
     fn test_parse_city(example_city: CityBlocks) {
         let expected = CityBlocks {
-            blocks: vec![
+            blocks: Grid::from_rows(vec![
                 parse_row("2413432311323"),
                 parse_row("3215453535623"),
                 parse_row("3255245654254"),
@@ -319,7 +213,8 @@ mod test {
                 parse_row("1224686865563"),
                 parse_row("2546548887735"),
                 parse_row("4322674655533"),
-            ],
+            ])
+            .unwrap(),
         };
         assert_eq!(example_city, expected)
     }
@@ -341,8 +236,11 @@ mod test {
     }
 
     #[rstest]
-    #[case(read_day_input("day_17"), 988, 1084)]
-    fn test_part_b_real(#[case] city_str: String, #[case] min_: u64, #[case] max_: u64) {
+    #[case("17", 988, 1084)]
+    fn test_part_b_real(#[case] day: &str, #[case] min_: u64, #[case] max_: u64) {
+        let Some(city_str) = read_day_input_if_present(day) else {
+            return;
+        };
         let city = CityBlocks::from_str(&city_str).unwrap();
         let x = part_b(&city);
         assert!(x > min_);
@@ -1,6 +1,5 @@
-use lazy_static::lazy_static;
-use regex::Regex;
-use std::fs;
+pub use aoc_common::{read_day_input, read_day_input_if_present, read_test_day_input};
+use aoc_common::{DayOutcome, RunConfig, Year};
 
 pub mod day_01;
 pub mod day_02;
@@ -20,46 +19,34 @@ pub mod day_15;
 pub mod day_16;
 pub mod day_17;
 
-pub fn solve_all() -> Vec<(u128, u128)> {
-    vec![
-        convert(day_01::solve_day(&read_day_input("day_01"))),
-        convert(day_02::solve_day(&read_day_input("day_02"))),
-        convert(day_03::solve_day(&read_day_input("day_03"))),
-        convert(day_04::solve_day(&read_day_input("day_04"))),
-        convert(day_05::solve_day(&read_day_input("day_05"))),
-        convert(day_06::solve_day(&read_day_input("day_06"))),
-        convert(day_07::solve_day(&read_day_input("day_07"))),
-        convert(day_08::solve_day(&read_day_input("day_08"))),
-        convert(day_09::solve_day(&read_day_input("day_09"))),
-        convert(day_10::solve_day(&read_day_input("day_10"))),
-        convert(day_11::solve_day(&read_day_input("day_11"))),
-        // convert(day_12::solve_day(&read_day_input("day_12"))), // Uncomment as it is verry slow
-        convert(day_13::solve_day(&read_day_input("day_13"))),
-        convert(day_14::solve_day(&read_day_input("day_14"))),
-        convert(day_15::solve_day(&read_day_input("day_15"))),
-        convert(day_16::solve_day(&read_day_input("day_16"))),
-        convert(day_17::solve_day(&read_day_input("day_17"))),
-    ]
+aoc_common::days! {
+    day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11, day_12,
+    day_13, day_14, day_15, day_16, day_17,
 }
 
-fn convert<A, B>(solution: (A, B)) -> (u128, u128)
-where
-    u128: From<A>,
-    u128: From<B>,
-{
-    (solution.0.into(), solution.1.into())
-}
+/// The first, much slower, take on day 14. Not registered, but kept as an
+/// oracle for testing `day_14` against.
+pub mod day_14_slow;
 
-pub fn read_day_input(day: &str) -> String {
-    let file_path = format!("inputs/{day}.txt");
-    fs::read_to_string(&file_path).unwrap_or_else(|_| panic!("Could not load: {file_path}"))
-}
+pub static YEAR: Year = Year {
+    year: 2023,
+    days: DAYS,
+    input_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    baseline: concat!(env!("CARGO_MANIFEST_DIR"), "/benches/baseline.toml"),
+};
 
-pub fn read_test_day_input(day: &str) -> String {
-    let file_path = format!("test_inputs/test_{day}.txt");
-    fs::read_to_string(&file_path).unwrap_or_else(|_| panic!("Could not load: {file_path}"))
+pub fn solve_days() -> Vec<DayOutcome> {
+    aoc_common::run_year(&YEAR, &RunConfig::from_env())
 }
 
-lazy_static! {
-    static ref RE_DIGITS: Regex = Regex::new(r"(\d+)").unwrap();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_in_day_order() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.day as usize, i + 1);
+        }
+    }
 }
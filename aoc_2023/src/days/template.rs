@@ -1,22 +1,52 @@
+use aoc_common::{Day, Solution};
+
 pub fn solve_day(input: &str) -> (u64, u64) {
-    (part_a(&input), part_b(&input))
+    (part_a(input), part_b(input))
 }
 
-fn part_a(input: &str) -> u64 {
+pub const DAY: Day = Day::new(0, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u64 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u64 {
+        part_b(input)
+    }
+}
+
+fn part_a(_input: &str) -> u64 {
     0
 }
 
-fn part_b(input: &str) -> u64 {
+fn part_b(_input: &str) -> u64 {
     0
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::days::read_test_day_input;
     use rstest::{fixture, rstest};
 
     #[fixture]
-    fn example_input() -> &'static str {
-        return "";
+    fn example_input() -> String {
+        read_test_day_input("00")
+    }
+
+    #[rstest]
+    fn test_part_a(example_input: String) {
+        assert_eq!(part_a(&example_input), 0)
     }
 }
@@ -0,0 +1,62 @@
+//! Random inputs for stress testing days beyond their official input.
+
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub static GENERATORS: &[Generator] = &[Generator {
+    day: 12,
+    generate: day_12,
+    unit: "records",
+    sizes: &[250, 500, 1000, 2000, 4000],
+}];
+
+/// `size` condition records of up to 20 springs. Each is made from a known
+/// arrangement, so it always has at least one solution.
+pub fn day_12(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::new();
+    for _ in 0..size {
+        let len = rng.gen_range(4..=20);
+        let mut springs: Vec<bool> = (0..len).map(|_| rng.gen_bool(0.5)).collect();
+        springs[rng.gen_range(0..len)] = true;
+
+        let groups: Vec<String> = springs
+            .split(|&broken| !broken)
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        let record: String = springs
+            .iter()
+            .map(|&broken| match (rng.gen_bool(0.5), broken) {
+                (true, _) => '?',
+                (false, true) => '#',
+                (false, false) => '.',
+            })
+            .collect();
+        input += &format!("{record} {}\n", groups.join(","));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::YEAR;
+
+    #[test]
+    fn test_generated_day_12_is_solvable() {
+        let input = day_12(50, 1);
+        assert_eq!(input.lines().count(), 50);
+        let (part_a, _) = YEAR.day(12).unwrap().solution.solve(&input);
+        // Every record has at least the arrangement it was made from
+        assert!(part_a.parse::<usize>().unwrap() >= 50);
+    }
+
+    #[test]
+    fn test_generators_are_deterministic() {
+        for generator in GENERATORS {
+            assert_eq!(generator.generate(20, 7), generator.generate(20, 7));
+        }
+    }
+}
@@ -1 +1,3 @@
 pub mod days;
+#[cfg(feature = "generate")]
+pub mod generate;
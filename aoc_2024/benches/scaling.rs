@@ -0,0 +1 @@
+aoc_common::scaling_main!(&aoc_2024::days::YEAR, aoc_2024::generate::GENERATORS);
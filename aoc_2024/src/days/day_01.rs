@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::collections::HashMap;
 
 pub fn solve_day(input_file: &str) -> (u32, u32) {
@@ -6,6 +7,28 @@ pub fn solve_day(input_file: &str) -> (u32, u32) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(1, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u32 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u32 {
+        part_b(input)
+    }
+}
+
 pub fn part_a(input_file: &str) -> u32 {
     let (mut left, mut right) = parse(input_file);
     left.sort();
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use lazy_static::lazy_static;
 use regex::{Captures, Regex};
 
@@ -8,6 +9,28 @@ pub fn solve_day(input_file: &str) -> (u32, u32) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(3, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Instruction>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_instructions(input)
+    }
+
+    fn part_a(instructions: &Self::Parsed<'_>) -> u32 {
+        part_a(instructions)
+    }
+
+    fn part_b(instructions: &Self::Parsed<'_>) -> u32 {
+        part_b(instructions)
+    }
+}
+
 lazy_static! {
     static ref RE_INST: Regex =
         Regex::new(r"((mul)\((\d{1,3}),(\d{1,3})\)|don't|do)").expect("regex should be valid");
@@ -1,3 +1,5 @@
+use aoc_common::{Day, Solution};
+
 pub fn solve_day(input_file: &str) -> (u32, u32) {
     let puzzle = parse_input(input_file);
     let a = part_a(&puzzle);
@@ -5,6 +7,28 @@ pub fn solve_day(input_file: &str) -> (u32, u32) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(4, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Vec<char>>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a(puzzle: &Self::Parsed<'_>) -> u32 {
+        part_a(puzzle)
+    }
+
+    fn part_b(puzzle: &Self::Parsed<'_>) -> u32 {
+        part_b(puzzle)
+    }
+}
+
 fn parse_input(input_file: &str) -> Vec<Vec<char>> {
     input_file
         .trim()
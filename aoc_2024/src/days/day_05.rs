@@ -1,9 +1,33 @@
+use aoc_common::{Day, Solution};
+
 pub fn solve_day(input_file: &str) -> (u32, u32) {
     let a = part_a(input_file);
     let b = part_b(input_file);
     (a, b)
 }
 
+pub const DAY: Day = Day::new(5, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u32 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u32 {
+        part_b(input)
+    }
+}
+
 fn part_a(input_file: &str) -> u32 {
     let (rules, orders) = input_file.split_once("\n\n").unwrap();
     let rules = Rule::parse_multiple(rules);
@@ -1,7 +1,5 @@
-use std::{
-    collections::{BTreeSet, HashSet},
-    str::FromStr,
-};
+use aoc_common::{brent, Day, Direction, Grid, ParseError, Pos, Solution};
+use std::{collections::HashSet, str::FromStr};
 
 pub fn solve_day(input_file: &str) -> (u32, u32) {
     let map = Map::from_str(input_file).unwrap();
@@ -10,6 +8,32 @@ pub fn solve_day(input_file: &str) -> (u32, u32) {
     (a, b)
 }
 
+pub const DAY: Day = Day::checked(6, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Map;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Map::from_str(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Map::from_str(input)
+    }
+
+    fn part_a(map: &Self::Parsed<'_>) -> u32 {
+        part_a(map)
+    }
+
+    fn part_b(map: &Self::Parsed<'_>) -> u32 {
+        part_b(map)
+    }
+}
+
 fn part_a(map: &Map) -> u32 {
     // Find current guard spot
     let visited = map.get_visited();
@@ -31,9 +55,8 @@ fn part_b(map: &Map) -> u32 {
 
 #[derive(Debug, PartialEq, Eq)]
 struct Map {
-    map: Vec<Vec<MapPart>>,
+    map: Grid<MapPart>,
     guard: Guard,
-    oob: MapPart,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -43,119 +66,35 @@ enum MapPart {
     Out,
 }
 
-impl FromStr for MapPart {
-    type Err = ParseError;
+impl TryFrom<char> for MapPart {
+    type Error = ParseError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let char = s.chars().next();
-        match char {
-            Some('.') => Ok(MapPart::Empty),
-            Some('#') => Ok(MapPart::Obstacle),
-            Some('^') => Ok(MapPart::Empty),
-            Some(_) => todo!(),
-            None => todo!(),
-        }
-    }
-}
-
-impl MapPart {
-    fn from_char(char: &char) -> Result<Self, ParseError> {
-        match char {
-            '.' => Ok(MapPart::Empty),
+    fn try_from(c: char) -> Result<Self, ParseError> {
+        match c {
+            '.' | '^' => Ok(MapPart::Empty),
             '#' => Ok(MapPart::Obstacle),
-            '^' => Ok(MapPart::Empty),
-            _ => todo!(),
+            _ => Err(ParseError::unexpected_char(c)),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
-struct Point {
-    x: usize,
-    y: usize,
-}
+/// Sorted by x, then y, which keeps the obstacles in `EfficientMap` in order.
+type Point = Pos;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 struct Guard {
     pos: Point,
-    dir: Dir,
+    dir: Direction,
 }
 impl Guard {
     fn next_pos(&self) -> Option<Point> {
-        self.pos.add(&self.dir)
-    }
-}
-
-impl From<(usize, usize)> for Point {
-    fn from(value: (usize, usize)) -> Self {
-        Point {
-            x: value.0,
-            y: value.1,
-        }
-    }
-}
-
-impl Point {
-    fn add(self, dir: &Dir) -> Option<Self> {
-        match dir {
-            Dir::Up => {
-                if self.y > 0 {
-                    Some(Point::from((self.x, self.y - 1)))
-                } else {
-                    None
-                }
-            }
-            Dir::Down => Some(Point::from((self.x, self.y + 1))),
-            Dir::Left => {
-                if self.x > 0 {
-                    Some(Point::from((self.x - 1, self.y)))
-                } else {
-                    None
-                }
-            }
-            Dir::Right => Some(Point::from((self.x + 1, self.y))),
-        }
-    }
-
-    fn sub(&self, dir: &Dir) -> Self {
-        let Point { mut x, mut y } = self;
-        match dir {
-            Dir::Up => y += 1,
-            Dir::Down => y -= 1,
-            Dir::Left => x += 1,
-            Dir::Right => x -= 1,
-        };
-        Point { x, y }
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
-enum Dir {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Dir {
-    fn next(self) -> Self {
-        match self {
-            Dir::Up => Dir::Right,
-            Dir::Down => Dir::Left,
-            Dir::Left => Dir::Up,
-            Dir::Right => Dir::Down,
-        }
+        self.dir.step(self.pos)
     }
 }
 
 impl Map {
     fn get(&self, point: Point) -> &MapPart {
-        if let Some(row) = self.map.get(point.y) {
-            if let Some(spot) = row.get(point.x) {
-                return spot;
-            }
-        }
-        &MapPart::Out
+        self.map.get(point).unwrap_or(&MapPart::Out)
     }
 
     fn get_some(&self, point: Option<Point>) -> &MapPart {
@@ -166,9 +105,7 @@ impl Map {
     }
 
     fn get_size(&self) -> (usize, usize) {
-        let y = self.map.len();
-        let x = self.map[0].len();
-        (x, y)
+        (self.map.width(), self.map.height())
     }
 
     fn get_visited(&self) -> HashSet<Point> {
@@ -178,7 +115,7 @@ impl Map {
         loop {
             match self.get_some(guard.next_pos()) {
                 MapPart::Obstacle => {
-                    guard.dir = guard.dir.next();
+                    guard.dir = guard.dir.turn_right();
                 }
                 MapPart::Empty => {
                     if let Some(next_pos) = guard.next_pos() {
@@ -194,40 +131,26 @@ impl Map {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct ParseError;
-
 impl FromStr for Map {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let map = s
-            .trim()
-            .split("\n")
-            .map(|line| {
-                line.chars()
-                    .map(|s| MapPart::from_char(&s).unwrap())
-                    .collect()
-            })
-            .collect();
-
-        let mut guard_opt = None;
-        for (y, line) in s.trim().split("\n").enumerate() {
-            for (x, c) in line.char_indices() {
-                if c == '^' {
-                    guard_opt = Some(Guard {
-                        pos: Point { x, y },
-                        dir: Dir::Up,
-                    });
-                }
+        // Cells come row by row, so the guard's index gives its position
+        let mut cells = 0;
+        let mut guard = None;
+        let map: Grid<MapPart> = Grid::parse_with(s.trim(), |c| {
+            if c == '^' {
+                guard.get_or_insert(cells);
             }
-        }
-
-        Ok(Map {
-            map,
-            guard: guard_opt.unwrap(),
-            oob: MapPart::Out,
-        })
+            cells += 1;
+            MapPart::try_from(c)
+        })?;
+        let guard = guard.ok_or(ParseError::new("no guard"))?;
+        let guard = Guard {
+            pos: (guard % map.width(), guard / map.width()),
+            dir: Direction::Up,
+        };
+        Ok(Map { map, guard })
     }
 }
 
@@ -246,20 +169,16 @@ impl EfficientMap {
 
         let mut e_map = EfficientMap { obs_xy, obs_yx };
 
-        for (y_i, row) in map.map.iter().enumerate() {
-            for (x_i, part) in row.iter().enumerate() {
-                if part == &MapPart::Obstacle {
-                    let (x, y) = (x_i, y_i);
-                    let obstacle = Point { x, y };
-                    e_map.insert_point(obstacle);
-                }
+        for ((x, y), part) in map.map.iter() {
+            if part == &MapPart::Obstacle {
+                e_map.insert_point((x, y));
             }
         }
         e_map
     }
 
     fn insert_point(&mut self, point: Point) {
-        let (x, y): (usize, usize) = (point.x, point.y);
+        let (x, y) = point;
         if let Err(idx) = self.obs_xy[x].binary_search(&point) {
             self.obs_xy[x].insert(idx, point);
         }
@@ -268,42 +187,43 @@ impl EfficientMap {
         }
     }
 
+    /// Follows the guard from obstacle to obstacle. Leaving the map is a
+    /// cycle too, one that stays at `None`.
     fn check_loop(&self, guard: &Guard, obstacle: &Point) -> bool {
-        let mut visited = BTreeSet::new();
-        let mut guard = *guard;
-        let mut is_loop = false;
-
-        while let Some(obstacle) = self.get_next_obs_with_extra_obs(&guard, obstacle) {
-            guard.pos = obstacle.sub(&guard.dir);
-            guard.dir = guard.dir.next();
-            if !visited.insert(guard) {
-                is_loop = true;
-                break;
-            }
-        }
-
-        is_loop
+        let (_, state) = brent(Some(*guard), |guard| {
+            let guard = (*guard)?;
+            let obstacle = self.get_next_obs_with_extra_obs(&guard, obstacle)?;
+            Some(Guard {
+                pos: guard
+                    .dir
+                    .reverse()
+                    .step(obstacle)
+                    .expect("the guard came from there"),
+                dir: guard.dir.turn_right(),
+            })
+        });
+        state.is_some()
     }
 
     fn get_next_obs(&self, guard: &Guard) -> Option<Point> {
-        let (x, y): (usize, usize) = (guard.pos.x, guard.pos.y);
+        let (x, y) = guard.pos;
         let point = match guard.dir {
-            Dir::Up => {
+            Direction::Up => {
                 let all_obs = &self.obs_xy[x];
                 let idx = all_obs.binary_search(&guard.pos).err().unwrap();
                 all_obs.get(idx.wrapping_sub(1))
             }
-            Dir::Down => {
+            Direction::Down => {
                 let all_obs = &self.obs_xy[x];
                 let idx = all_obs.binary_search(&guard.pos).err().unwrap();
                 all_obs.get(idx)
             }
-            Dir::Left => {
+            Direction::Left => {
                 let all_obs = &self.obs_yx[y];
                 let idx = all_obs.binary_search(&guard.pos).err().unwrap();
                 all_obs.get(idx.wrapping_sub(1))
             }
-            Dir::Right => {
+            Direction::Right => {
                 let all_obs = &self.obs_yx[y];
                 let idx = all_obs.binary_search(&guard.pos).err().unwrap();
                 all_obs.get(idx)
@@ -315,29 +235,29 @@ impl EfficientMap {
     fn get_next_obs_with_extra_obs(&self, guard: &Guard, extra_obs: &Point) -> Option<Point> {
         if let Some(obs) = self.get_next_obs(guard) {
             match guard.dir {
-                Dir::Up => {
-                    if extra_obs.x == obs.x && extra_obs.y > obs.y && extra_obs.y < guard.pos.y {
+                Direction::Up => {
+                    if extra_obs.0 == obs.0 && extra_obs.1 > obs.1 && extra_obs.1 < guard.pos.1 {
                         Some(*extra_obs)
                     } else {
                         Some(obs)
                     }
                 }
-                Dir::Down => {
-                    if extra_obs.x == obs.x && extra_obs.y < obs.y && extra_obs.y > guard.pos.y {
+                Direction::Down => {
+                    if extra_obs.0 == obs.0 && extra_obs.1 < obs.1 && extra_obs.1 > guard.pos.1 {
                         Some(*extra_obs)
                     } else {
                         Some(obs)
                     }
                 }
-                Dir::Left => {
-                    if extra_obs.y == obs.y && extra_obs.x > obs.x && extra_obs.x < guard.pos.x {
+                Direction::Left => {
+                    if extra_obs.1 == obs.1 && extra_obs.0 > obs.0 && extra_obs.0 < guard.pos.0 {
                         Some(*extra_obs)
                     } else {
                         Some(obs)
                     }
                 }
-                Dir::Right => {
-                    if extra_obs.y == obs.y && extra_obs.x < obs.x && extra_obs.x > guard.pos.x {
+                Direction::Right => {
+                    if extra_obs.1 == obs.1 && extra_obs.0 < obs.0 && extra_obs.0 > guard.pos.0 {
                         Some(*extra_obs)
                     } else {
                         Some(obs)
@@ -346,29 +266,29 @@ impl EfficientMap {
             }
         } else {
             match guard.dir {
-                Dir::Up => {
-                    if extra_obs.x == guard.pos.x && extra_obs.y < guard.pos.y {
+                Direction::Up => {
+                    if extra_obs.0 == guard.pos.0 && extra_obs.1 < guard.pos.1 {
                         Some(*extra_obs)
                     } else {
                         None
                     }
                 }
-                Dir::Down => {
-                    if extra_obs.x == guard.pos.x && extra_obs.y > guard.pos.y {
+                Direction::Down => {
+                    if extra_obs.0 == guard.pos.0 && extra_obs.1 > guard.pos.1 {
                         Some(*extra_obs)
                     } else {
                         None
                     }
                 }
-                Dir::Left => {
-                    if extra_obs.y == guard.pos.y && extra_obs.x < guard.pos.x {
+                Direction::Left => {
+                    if extra_obs.1 == guard.pos.1 && extra_obs.0 < guard.pos.0 {
                         Some(*extra_obs)
                     } else {
                         None
                     }
                 }
-                Dir::Right => {
-                    if extra_obs.y == guard.pos.y && extra_obs.x > guard.pos.x {
+                Direction::Right => {
+                    if extra_obs.1 == guard.pos.1 && extra_obs.0 > guard.pos.0 {
                         Some(*extra_obs)
                     } else {
                         None
@@ -395,7 +315,7 @@ mod tests {
 .#..^.....
 ........#.
 #.........
-......#...", Map { map: vec![
+......#...", Map { map: Grid::from_rows(vec![
 vec![MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Obstacle,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty],
 vec![MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Obstacle],
 vec![MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty],
@@ -406,7 +326,8 @@ vec![MapPart::Empty,MapPart::Obstacle,MapPart::Empty,MapPart::Empty,MapPart::Emp
 vec![MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Obstacle,MapPart::Empty],
 vec![MapPart::Obstacle,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty],
 vec![MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Obstacle,MapPart::Empty,MapPart::Empty,MapPart::Empty],
-], guard: Guard { pos: Point {x: 4, y: 6}, dir: Dir::Up }, oob: MapPart::Out,}
+
+]).unwrap(), guard: Guard { pos: (4, 6), dir: Direction::Up } }
     )]
     fn test_parse(#[case] input_file: &str, #[case] map: Map) {
         assert_eq!(Map::from_str(input_file).unwrap(), map)
@@ -419,9 +340,9 @@ vec![MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,
     }
 
     #[rstest]
-    #[case(Point {x: 0, y: 0}, MapPart::Empty)]
-    #[case(Point {x: 4, y: 0}, MapPart::Obstacle)]
-    #[case(Point {x: 0, y: 4}, MapPart::Empty)]
+    #[case((0, 0), MapPart::Empty)]
+    #[case((4, 0), MapPart::Obstacle)]
+    #[case((0, 4), MapPart::Empty)]
     fn test_map_get(example_map: Map, #[case] pos: Point, #[case] expected_part: MapPart) {
         assert_eq!(example_map.get(pos), &expected_part);
     }
@@ -432,9 +353,9 @@ vec![MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,
     }
 
     #[rstest]
-    #[case(Point {x: 0, y: 0}, false)]
-    #[case(Point {x: 3, y: 6}, true)]
-    #[case(Point {x: 6, y: 7}, true)]
+    #[case((0, 0), false)]
+    #[case((3, 6), true)]
+    #[case((6, 7), true)]
     fn test_check_loop_efficient(
         example_map: Map,
         #[case] obstacle_point: Point,
@@ -450,14 +371,14 @@ vec![MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,
     }
 
     #[rstest]
-    #[case( Guard { pos: Point {x: 4, y: 6}, dir: Dir::Up }, Some(Point { x: 4, y: 0 }))]
-    #[case( Guard { pos: Point {x: 8, y: 6}, dir: Dir::Up }, None)]
-    #[case( Guard { pos: Point {x: 8, y: 6}, dir: Dir::Down }, Some(Point { x: 8, y: 7 }))]
-    #[case( Guard { pos: Point {x: 4, y: 6}, dir: Dir::Down }, None)]
-    #[case( Guard { pos: Point {x: 8, y: 0}, dir: Dir::Left }, Some(Point { x: 4, y: 0 }))]
-    #[case( Guard { pos: Point {x: 3, y: 0}, dir: Dir::Left }, None)]
-    #[case( Guard { pos: Point {x: 3, y: 0}, dir: Dir::Right }, Some(Point { x: 4, y: 0 }))]
-    #[case( Guard { pos: Point {x: 8, y: 0}, dir: Dir::Right }, None)]
+    #[case( Guard { pos: (4, 6), dir: Direction::Up }, Some((4, 0)))]
+    #[case( Guard { pos: (8, 6), dir: Direction::Up }, None)]
+    #[case( Guard { pos: (8, 6), dir: Direction::Down }, Some((8, 7)))]
+    #[case( Guard { pos: (4, 6), dir: Direction::Down }, None)]
+    #[case( Guard { pos: (8, 0), dir: Direction::Left }, Some((4, 0)))]
+    #[case( Guard { pos: (3, 0), dir: Direction::Left }, None)]
+    #[case( Guard { pos: (3, 0), dir: Direction::Right }, Some((4, 0)))]
+    #[case( Guard { pos: (8, 0), dir: Direction::Right }, None)]
     fn test_efficient_next_pos(
         example_map: Map,
         #[case] guard: Guard,
@@ -468,10 +389,10 @@ vec![MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,
     }
 
     #[rstest]
-    #[case(vec![Point {x: 1, y: 0}, Point {x: 0, y: 0}], vec![Point {x: 0, y: 0}, Point {x: 1, y: 0}])]
-    #[case(vec![Point {x: 0, y: 0}, Point {x: 1, y: 0}], vec![Point {x: 0, y: 0}, Point {x: 1, y: 0}])]
-    #[case(vec![Point {x: 0, y: 1}, Point {x: 0, y: 0}], vec![Point {x: 0, y: 0}, Point {x: 0, y: 1}])]
-    #[case(vec![Point {x: 0, y: 0}, Point {x: 0, y: 1}], vec![Point {x: 0, y: 0}, Point {x: 0, y: 1}])]
+    #[case(vec![(1, 0), (0, 0)], vec![(0, 0), (1, 0)])]
+    #[case(vec![(0, 0), (1, 0)], vec![(0, 0), (1, 0)])]
+    #[case(vec![(0, 1), (0, 0)], vec![(0, 0), (0, 1)])]
+    #[case(vec![(0, 0), (0, 1)], vec![(0, 0), (0, 1)])]
     fn test_point_sorting(#[case] mut unsorted: Vec<Point>, #[case] sorted: Vec<Point>) {
         unsorted.sort();
         assert_eq!(unsorted, sorted)
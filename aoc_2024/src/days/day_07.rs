@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::str::FromStr;
 
 pub fn solve_day(input_file: &str) -> (u64, u64) {
@@ -7,6 +8,28 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(7, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Equation>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a(equations: &Self::Parsed<'_>) -> u64 {
+        part_a(equations)
+    }
+
+    fn part_b(equations: &Self::Parsed<'_>) -> u64 {
+        part_b(equations)
+    }
+}
+
 fn parse_input(input_file: &str) -> Vec<Equation> {
     input_file
         .trim()
@@ -82,8 +105,10 @@ impl Operator {
     fn possible_rev(&self, lhs: u64, rhs: u64) -> bool {
         match self {
             Operator::Sum => lhs > rhs,
-            Operator::Mul => lhs % rhs == 0,
-            Operator::Concat => lhs > rhs && (lhs - rhs) % 10u64.pow(rhs.ilog10() + 1) == 0,
+            Operator::Mul => lhs.is_multiple_of(rhs),
+            Operator::Concat => {
+                lhs > rhs && (lhs - rhs).is_multiple_of(10u64.pow(rhs.ilog10() + 1))
+            }
         }
     }
 }
@@ -136,6 +161,7 @@ fn recursive_solve_back(lhs: u64, rhs_rem: &[u64], rhs_init: u64, operators: &[O
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
     use rstest::rstest;
 
     #[rstest]
@@ -219,4 +245,30 @@ mod tests {
         let input = &parse_input(input);
         assert_eq!(part_b(input), 11387)
     }
+
+    const OPERATORS: [Operator; 3] = [Operator::Sum, Operator::Mul, Operator::Concat];
+
+    /// Equations that are solvable about half of the time, by sometimes
+    /// building the left hand side from randomly chosen operators.
+    fn equation_strategy() -> impl Strategy<Value = Equation> {
+        prop::collection::vec((1..1000u64, 0..3usize), 2..=6).prop_flat_map(|terms| {
+            let rhs: Vec<u64> = terms.iter().map(|&(n, _)| n).collect();
+            let solved = terms[1..]
+                .iter()
+                .fold(rhs[0], |acc, &(n, op)| OPERATORS[op].perform(acc, n));
+            (prop_oneof![Just(solved), 1..=solved], Just(rhs))
+                .prop_map(|(lhs, rhs)| Equation { lhs, rhs })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_is_solvable_backwards_matches_is_solvable(
+            eq in equation_strategy(),
+            n_operators in 2..=3usize,
+        ) {
+            let operators = &OPERATORS[..n_operators];
+            prop_assert_eq!(eq.is_solvable_backwards(operators), eq.is_solvable(operators));
+        }
+    }
 }
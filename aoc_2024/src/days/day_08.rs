@@ -1,3 +1,4 @@
+use aoc_common::{Coord, Day, Solution};
 use std::{
     collections::{HashMap, HashSet},
     vec,
@@ -9,6 +10,28 @@ pub fn solve_day(input_file: &str) -> (u32, u32) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(8, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u32 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u32 {
+        part_b(input)
+    }
+}
+
 fn part_a(input_file: &str) -> u32 {
     let (all_antennas, map_size) = parse_input(input_file);
 
@@ -43,28 +66,18 @@ fn part_b(input_file: &str) -> u32 {
     antinodes.len() as u32
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
-struct Point {
-    x: isize,
-    y: isize,
-}
+type Point = Coord<isize>;
 
-impl Point {
-    fn is_divisible_by(&self, n: isize) -> bool {
-        self.x.rem_euclid(n) == 0 && self.y.rem_euclid(n) == 0
-    }
+fn is_divisible_by(vector: Point, n: isize) -> bool {
+    vector.x.rem_euclid(n) == 0 && vector.y.rem_euclid(n) == 0
+}
 
-    fn is_on_map(&self, x: isize, y: isize) -> bool {
-        self.x >= 0 && self.y >= 0 && self.x <= x && self.y <= y
-    }
+fn is_on_map(point: Point, (x, y): (isize, isize)) -> bool {
+    point.x >= 0 && point.y >= 0 && point.x <= x && point.y <= y
+}
 
-    fn smallest_vector(self) -> Self {
-        let divider = gcd(self.x.unsigned_abs(), self.y.unsigned_abs()) as isize;
-        Point {
-            x: self.x / divider,
-            y: self.y / divider,
-        }
-    }
+fn smallest_vector(vector: Point) -> Point {
+    vector / gcd(vector.x.unsigned_abs(), vector.y.unsigned_abs()) as isize
 }
 
 fn gcd(x: usize, y: usize) -> usize {
@@ -97,13 +110,7 @@ fn parse_input(input_file: &str) -> (HashMap<char, Vec<Point>>, (isize, isize))
                         max_x = max_x.max(x as isize);
                         match char {
                             '.' => None,
-                            _ => Some((
-                                char,
-                                Point {
-                                    x: x as isize,
-                                    y: y as isize,
-                                },
-                            )),
+                            _ => Some((char, Point::new(x as isize, y as isize))),
                         }
                     })
                     .collect::<Vec<(char, Point)>>()
@@ -124,35 +131,20 @@ fn find_antinodes_a(antenna_1: &Point, antenna_2: &Point, map_size: (isize, isiz
     // a1 - x/3 (if integer)
     // a2 + x/3 (if integer)
     // a2 - x   (if on map)
-    let vector = Point {
-        x: antenna_1.x - antenna_2.x,
-        y: antenna_1.y - antenna_2.y,
-    };
+    let vector = *antenna_1 - *antenna_2;
     let mut antinodes = Vec::with_capacity(4);
-    if vector.is_divisible_by(3) {
-        antinodes.push(Point {
-            x: antenna_1.x - vector.x / 3,
-            y: antenna_1.y - vector.y / 3,
-        });
-        antinodes.push(Point {
-            x: antenna_2.x + vector.x / 3,
-            y: antenna_2.y + vector.y / 3,
-        });
+    if is_divisible_by(vector, 3) {
+        antinodes.push(*antenna_1 - vector / 3);
+        antinodes.push(*antenna_2 + vector / 3);
     }
 
-    let antinode_1 = Point {
-        x: antenna_1.x + vector.x,
-        y: antenna_1.y + vector.y,
-    };
-    if antinode_1.is_on_map(map_size.0, map_size.1) {
+    let antinode_1 = *antenna_1 + vector;
+    if is_on_map(antinode_1, map_size) {
         antinodes.push(antinode_1);
     };
 
-    let antinode_4 = Point {
-        x: antenna_2.x - vector.x,
-        y: antenna_2.y - vector.y,
-    };
-    if antinode_4.is_on_map(map_size.0, map_size.1) {
+    let antinode_4 = *antenna_2 - vector;
+    if is_on_map(antinode_4, map_size) {
         antinodes.push(antinode_4);
     };
     antinodes
@@ -161,19 +153,12 @@ fn find_antinodes_a(antenna_1: &Point, antenna_2: &Point, map_size: (isize, isiz
 fn find_antinodes_b(antenna_1: &Point, antenna_2: &Point, map_size: (isize, isize)) -> Vec<Point> {
     let mut antinodes = vec![*antenna_1];
 
-    let vector = Point {
-        x: antenna_1.x - antenna_2.x,
-        y: antenna_1.y - antenna_2.y,
-    }
-    .smallest_vector();
+    let vector = smallest_vector(*antenna_1 - *antenna_2);
 
     let mut multiplier = 1;
     loop {
-        let antinode = Point {
-            x: antenna_1.x - multiplier * vector.x,
-            y: antenna_1.y - multiplier * vector.y,
-        };
-        if !antinode.is_on_map(map_size.0, map_size.1) {
+        let antinode = *antenna_1 - vector * multiplier;
+        if !is_on_map(antinode, map_size) {
             break;
         }
 
@@ -182,11 +167,8 @@ fn find_antinodes_b(antenna_1: &Point, antenna_2: &Point, map_size: (isize, isiz
     }
     let mut multiplier = 1;
     loop {
-        let antinode = Point {
-            x: antenna_1.x + multiplier * vector.x,
-            y: antenna_1.y + multiplier * vector.y,
-        };
-        if !antinode.is_on_map(map_size.0, map_size.1) {
+        let antinode = *antenna_1 + vector * multiplier;
+        if !is_on_map(antinode, map_size) {
             break;
         }
 
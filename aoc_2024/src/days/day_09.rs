@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::{collections::VecDeque, fmt::Display, str::FromStr};
 
 pub fn solve_day(input_file: &str) -> (usize, usize) {
@@ -6,6 +7,28 @@ pub fn solve_day(input_file: &str) -> (usize, usize) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(9, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> usize {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> usize {
+        part_b(input)
+    }
+}
+
 fn part_a(input_file: &str) -> usize {
     let fs = FileSystem::from_str(input_file.trim()).unwrap();
     fs.checksum_a()
@@ -232,9 +255,10 @@ impl Display for File {
 
 #[cfg(test)]
 mod tests {
-    use crate::days::read_day_input;
+    use crate::days::read_day_input_if_present;
 
     use super::*;
+    use proptest::prelude::*;
     use rstest::rstest;
 
     // Debug visualizationcode
@@ -272,7 +296,9 @@ mod tests {
 
     #[rstest]
     fn test_actual_a() {
-        let input = read_day_input("09");
+        let Some(input) = read_day_input_if_present("09") else {
+            return;
+        };
         let result = part_a(&input);
         assert_eq!(result, 6432869891895)
     }
@@ -284,8 +310,31 @@ mod tests {
 
     #[rstest]
     fn test_actual_b() {
-        let input = read_day_input("09");
+        let Some(input) = read_day_input_if_present("09") else {
+            return;
+        };
         let result = part_b(&input);
         assert_eq!(result, 6467290479134)
     }
+
+    proptest! {
+        #[test]
+        fn test_file_system_display(disk_map in "[0-9]{1,40}") {
+            let fs = FileSystem::from_str(&disk_map).unwrap();
+            prop_assert_eq!(fs.files.len(), disk_map.len());
+
+            let blocks: String = disk_map
+                .chars()
+                .enumerate()
+                .map(|(i, length)| {
+                    let length = length.to_digit(10).unwrap() as usize;
+                    match i % 2 {
+                        0 => (i / 2).to_string().repeat(length),
+                        _ => ".".repeat(length),
+                    }
+                })
+                .collect();
+            prop_assert_eq!(fs.to_string(), blocks);
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Grid, ParseError, Pos, Solution};
 use std::{collections::HashSet, str::FromStr};
 
 pub fn solve_day(input_file: &str) -> (u32, u32) {
@@ -6,6 +7,28 @@ pub fn solve_day(input_file: &str) -> (u32, u32) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(10, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_a(input: &Self::Parsed<'_>) -> u32 {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>) -> u32 {
+        part_b(input)
+    }
+}
+
 fn part_a(input_file: &str) -> u32 {
     let map = Map::from_str(input_file).unwrap();
     map.count_all_arrow_heads_unique()
@@ -16,106 +39,45 @@ fn part_b(input_file: &str) -> u32 {
     map.count_all_arrow_heads_distinct()
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct ParseError;
 impl FromStr for Map {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let map: Vec<Vec<u32>> = s
-            .trim()
-            .split("\n")
-            .map(|row| row.chars().map(|c| c.to_digit(10).unwrap_or(16)).collect())
-            .collect();
-        let x = map[0].len();
-        let y = map.len();
-        let size = Coord { x, y };
-        Ok(Map { map, size })
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
-
-impl From<(usize, usize)> for Coord {
-    fn from(value: (usize, usize)) -> Self {
-        Coord {
-            x: value.0,
-            y: value.1,
-        }
-    }
-}
-
-impl Coord {
-    fn neighbours(&self, size: &Coord) -> [Option<Coord>; 4] {
-        let left = {
-            if self.x > 0 {
-                Some(Coord::from((self.x - 1, self.y)))
-            } else {
-                None
-            }
-        };
-        let right = {
-            if self.x < (size.x - 1) {
-                Some(Coord::from((self.x + 1, self.y)))
-            } else {
-                None
-            }
-        };
-        let up = {
-            if self.y > 0 {
-                Some(Coord::from((self.x, self.y - 1)))
-            } else {
-                None
-            }
-        };
-        let down = {
-            if self.y < (size.y - 1) {
-                Some(Coord::from((self.x, self.y + 1)))
-            } else {
-                None
-            }
-        };
-        [left, up, right, down]
+        // Impassable tiles get a height no path can step onto
+        let map = Grid::parse_with(s.trim(), |c| Ok(c.to_digit(10).unwrap_or(16)))?;
+        Ok(Map { map })
     }
 }
 
 #[derive(Debug, PartialEq, Eq)]
 struct Map {
-    map: Vec<Vec<u32>>,
-    size: Coord,
+    map: Grid<u32>,
 }
 
 impl Map {
     fn count_all_arrow_heads_unique(&self) -> u32 {
-        let mut sum = 0;
-        for x in 0..self.size.x {
-            for y in 0..self.size.y {
-                sum += self.count_arrow_heads_unique_at((x, y).into());
-            }
-        }
-        sum
+        self.map
+            .positions()
+            .map(|pos| self.count_arrow_heads_unique_at(pos))
+            .sum()
     }
 
-    fn count_arrow_heads_unique_at(&self, coord: Coord) -> u32 {
-        if self.get_coord(&coord) != 0 {
+    fn count_arrow_heads_unique_at(&self, pos: Pos) -> u32 {
+        if self.map[pos] != 0 {
             return 0;
         }
-        self.recursive_find_paths(&coord, 0).len() as u32
+        self.recursive_find_paths(pos, 0).len() as u32
     }
 
-    fn recursive_find_paths(&self, coord: &Coord, cur_height: u32) -> HashSet<Coord> {
+    fn recursive_find_paths(&self, pos: Pos, cur_height: u32) -> HashSet<Pos> {
         let mut unique = HashSet::new();
 
-        for next in coord.neighbours(&self.size).into_iter().flatten() {
-            if self.get_coord(&next) == cur_height + 1 {
+        for next in self.map.neighbours4(pos) {
+            if self.map[next] == cur_height + 1 {
                 if cur_height + 1 == 9 {
                     unique.insert(next);
                 } else {
-                    unique.extend(&self.recursive_find_paths(&next, cur_height + 1));
+                    unique.extend(&self.recursive_find_paths(next, cur_height + 1));
                 }
             }
         }
@@ -123,40 +85,33 @@ impl Map {
     }
 
     fn count_all_arrow_heads_distinct(&self) -> u32 {
-        let mut sum = 0;
-        for x in 0..self.size.x {
-            for y in 0..self.size.y {
-                sum += self.count_arrow_heads_distinct_at((x, y).into());
-            }
-        }
-        sum
+        self.map
+            .positions()
+            .map(|pos| self.count_arrow_heads_distinct_at(pos))
+            .sum()
     }
 
-    fn count_arrow_heads_distinct_at(&self, coord: Coord) -> u32 {
-        if self.get_coord(&coord) != 0 {
+    fn count_arrow_heads_distinct_at(&self, pos: Pos) -> u32 {
+        if self.map[pos] != 0 {
             return 0;
         }
-        self.recursive_find_distinct_paths(&coord, 0)
+        self.recursive_find_distinct_paths(pos, 0)
     }
 
-    fn recursive_find_distinct_paths(&self, coord: &Coord, cur_height: u32) -> u32 {
+    fn recursive_find_distinct_paths(&self, pos: Pos, cur_height: u32) -> u32 {
         let mut sum = 0;
 
-        for next in coord.neighbours(&self.size).into_iter().flatten() {
-            if self.get_coord(&next) == cur_height + 1 {
+        for next in self.map.neighbours4(pos) {
+            if self.map[next] == cur_height + 1 {
                 if cur_height + 1 == 9 {
                     sum += 1;
                 } else {
-                    sum += self.recursive_find_distinct_paths(&next, cur_height + 1);
+                    sum += self.recursive_find_distinct_paths(next, cur_height + 1);
                 }
             }
         }
         sum
     }
-
-    fn get_coord(&self, coord: &Coord) -> u32 {
-        self.map[coord.y][coord.x]
-    }
 }
 
 #[cfg(test)]
@@ -165,12 +120,12 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case("0123\n1234\n8765\n9876", Map { map: vec![
+    #[case("0123\n1234\n8765\n9876", Map { map: Grid::from_rows(vec![
         vec![0,1,2,3,],
         vec![1,2,3,4,],
         vec![8,7,6,5,],
         vec![9,8,7,6,],
-    ], size: Coord { x: 4, y: 4 }})]
+    ]).unwrap() })]
     fn test_parse(#[case] input_file: &str, #[case] map: Map) {
         assert_eq!(Map::from_str(input_file), Ok(map))
     }
@@ -184,7 +139,7 @@ mod tests {
         #[case] point: (usize, usize),
         #[case] num_heads: u32,
     ) {
-        assert_eq!(map.count_arrow_heads_unique_at(point.into()), num_heads)
+        assert_eq!(map.count_arrow_heads_unique_at(point), num_heads)
     }
 
     #[rstest]
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::{collections::HashMap, str::FromStr};
 
 pub fn solve_day(input_file: &str) -> (u64, u64) {
@@ -7,6 +8,28 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(11, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Stones;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Stones::from_str(input).expect("Invalid input")
+    }
+
+    fn part_a(stones: &Self::Parsed<'_>) -> u64 {
+        part_a(&mut stones.clone())
+    }
+
+    fn part_b(stones: &Self::Parsed<'_>) -> u64 {
+        part_b(&mut stones.clone())
+    }
+}
+
 fn part_a(stones: &mut Stones) -> u64 {
     blink_counter(&stones.stones, 25) as u64
 }
@@ -60,7 +83,7 @@ fn _blink_recursive_cached(
         1
     } else if stone == 0 {
         _blink_recursive_cached(cache, 1, n_blinks - 1)
-    } else if ((stone).ilog10() + 1) % 2 == 0 {
+    } else if ((stone).ilog10() + 1).is_multiple_of(2) {
         let (left_stone, right_stone) = split_stone(&stone);
         _blink_recursive_cached(cache, left_stone, n_blinks - 1)
             + _blink_recursive_cached(cache, right_stone, n_blinks - 1)
@@ -73,9 +96,7 @@ fn _blink_recursive_cached(
 
 fn blink_counter(stones: &[u64], n_blinks: u64) -> usize {
     let mut counter = stones.iter().fold(HashMap::new(), |mut c, stone| {
-        c.entry(*stone)
-            .and_modify(|c| *c += 1)
-            .or_insert(1_usize);
+        c.entry(*stone).and_modify(|c| *c += 1).or_insert(1_usize);
         c
     });
 
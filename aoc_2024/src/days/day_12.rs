@@ -1,3 +1,4 @@
+use aoc_common::{Coord, Day, Direction, Grid, ParseError, Pos, Solution, ORTHOGONAL};
 use std::{collections::BTreeSet, str::FromStr};
 
 pub fn solve_day(input_file: &str) -> (u32, u32) {
@@ -7,6 +8,28 @@ pub fn solve_day(input_file: &str) -> (u32, u32) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(12, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Map;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.parse().expect("Invalid input")
+    }
+
+    fn part_a(map: &Self::Parsed<'_>) -> u32 {
+        part_a(map)
+    }
+
+    fn part_b(map: &Self::Parsed<'_>) -> u32 {
+        part_b(map)
+    }
+}
+
 fn part_a(map: &Map) -> u32 {
     map.calculate_cost_a()
 }
@@ -17,21 +40,14 @@ fn part_b(map: &Map) -> u32 {
 
 #[derive(Debug, PartialEq, Eq)]
 struct Map {
-    map: Vec<Vec<char>>,
+    map: Grid<char>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct ParseError;
-
 impl FromStr for Map {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let map = s
-            .trim()
-            .lines()
-            .map(|line| line.chars().collect())
-            .collect();
+        let map = s.trim().parse()?;
         Ok(Map { map })
     }
 }
@@ -42,40 +58,25 @@ impl Map {
     }
 
     fn get_regions_a(&self) -> Vec<Region> {
-        let mut unassigned: BTreeSet<Coord> = self
-            .map
-            .iter()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.iter()
-                    .enumerate()
-                    .map(|(x, _)| Coord { x, y })
-                    .collect::<Vec<Coord>>()
-            })
-            .collect();
-        let size = (self.map[0].len(), self.map.len()).into();
+        let mut unassigned: BTreeSet<Pos> = self.map.positions().collect();
 
         let mut regions = vec![];
-        while let Some(coord) = unassigned.pop_first() {
-            let mut stack = vec![coord];
-            let cur_char = self.get(&coord);
+        while let Some(pos) = unassigned.pop_first() {
+            let mut stack = vec![pos];
+            let cur_char = self.map[pos];
             let mut area = 1;
             let mut perimeter = 0;
 
-            while let Some(coord) = stack.pop() {
-                for n in coord.neighbours(&size) {
-                    match n {
-                        Some(n) => {
-                            if self.get(&n) == cur_char {
-                                if unassigned.remove(&n) {
-                                    stack.push(n);
-                                    area += 1;
-                                };
-                            } else {
-                                perimeter += 1;
-                            }
+            while let Some(pos) = stack.pop() {
+                for offset in ORTHOGONAL {
+                    match self.map.neighbour(pos, offset) {
+                        Some(n) if self.map[n] == cur_char => {
+                            if unassigned.remove(&n) {
+                                stack.push(n);
+                                area += 1;
+                            };
                         }
-                        None => perimeter += 1,
+                        _ => perimeter += 1,
                     }
                 }
             }
@@ -94,35 +95,24 @@ impl Map {
     }
 
     fn get_regions_b(&self) -> Vec<Region> {
-        let mut unassigned: BTreeSet<Coord> = self
-            .map
-            .iter()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.iter()
-                    .enumerate()
-                    .map(|(x, _)| Coord { x, y })
-                    .collect::<Vec<Coord>>()
-            })
-            .collect();
-        let size = (self.map[0].len(), self.map.len()).into();
+        let mut unassigned: BTreeSet<Pos> = self.map.positions().collect();
 
         let mut regions = vec![];
-        while let Some(coord) = unassigned.pop_first() {
-            let mut stack = vec![coord];
-            let cur_char = self.get(&coord);
+        while let Some(pos) = unassigned.pop_first() {
+            let mut stack = vec![pos];
+            let cur_char = self.map[pos];
             let mut area = 1;
 
             let mut num_corners = 0;
 
-            while let Some(coord) = stack.pop() {
-                for n in coord.neighbours(&size).into_iter().flatten() {
-                    if self.get(&n) == cur_char && unassigned.remove(&n) {
+            while let Some(pos) = stack.pop() {
+                for n in self.map.neighbours4(pos) {
+                    if self.map[n] == cur_char && unassigned.remove(&n) {
                         stack.push(n);
                         area += 1;
                     }
                 }
-                num_corners += self.count_corners(&coord, cur_char, &size);
+                num_corners += self.count_corners(pos, cur_char);
             }
 
             regions.push(Region {
@@ -134,44 +124,25 @@ impl Map {
         regions
     }
 
-    fn count_corners(&self, coord: &Coord, char: char, size: &Coord) -> u32 {
-        // fn corners(
-        // grid: &[Vec<char>],
-        // row: usize,
-        // col: usize,
-        // x: char,
-        // width: usize,
-        // height: usize,
-        // ) -> u8 {
-        let check_up = coord.up().is_none_or(|c| self.get(&c) != char);
-        let check_left = coord.left().is_none_or(|c| self.get(&c) != char);
-        let check_right = coord.right(size).is_none_or(|c| self.get(&c) != char);
-        let check_down = coord.down(size).is_none_or(|c| self.get(&c) != char);
-        let check_up_left = coord
-            .up()
-            .is_none_or(|c| c.left().is_none_or(|c| self.get(&c) != char));
-        let check_up_right = coord
-            .up()
-            .is_none_or(|c| c.right(size).is_none_or(|c| self.get(&c) != char));
-        let check_down_left = coord
-            .down(size)
-            .is_none_or(|c| c.left().is_none_or(|c| self.get(&c) != char));
-        let check_down_right = coord
-            .down(size)
-            .is_none_or(|c| c.right(size).is_none_or(|c| self.get(&c) != char));
-
-        (check_up && check_left) as u32
-            + (check_up && check_right) as u32
-            + (check_down && check_left) as u32
-            + (check_down && check_right) as u32
-            + (!check_up && !check_left && check_up_left) as u32
-            + (!check_up && !check_right && check_up_right) as u32
-            + (!check_down && !check_left && check_down_left) as u32
-            + (!check_down && !check_right && check_down_right) as u32
-    }
-
-    fn get(&self, coord: &Coord) -> char {
-        self.map[coord.y][coord.x]
+    /// A region has as many sides as corners.
+    fn count_corners(&self, pos: Pos, char: char) -> u32 {
+        let differs = |offset: Coord<isize>| {
+            self.map
+                .neighbour(pos, offset.into())
+                .is_none_or(|n| self.map[n] != char)
+        };
+        // Each corner is between a direction and the one clockwise from it.
+        Direction::ALL
+            .into_iter()
+            .filter(|&dir| {
+                let (ahead, side) = (dir.into(), dir.turn_right().into());
+                match (differs(ahead), differs(side)) {
+                    (true, true) => true,
+                    (false, false) => differs(ahead + side),
+                    _ => false,
+                }
+            })
+            .count() as u32
     }
 }
 
@@ -198,62 +169,6 @@ impl From<&(u32, u32, char)> for Region {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
-
-impl From<(usize, usize)> for Coord {
-    fn from(value: (usize, usize)) -> Self {
-        Coord {
-            x: value.0,
-            y: value.1,
-        }
-    }
-}
-
-impl Coord {
-    fn up(&self) -> Option<Coord> {
-        if self.y > 0 {
-            Some(Coord::from((self.x, self.y - 1)))
-        } else {
-            None
-        }
-    }
-
-    fn left(&self) -> Option<Coord> {
-        if self.x > 0 {
-            Some(Coord::from((self.x - 1, self.y)))
-        } else {
-            None
-        }
-    }
-
-    fn right(&self, size: &Coord) -> Option<Coord> {
-        if self.x < (size.x - 1) {
-            Some(Coord::from((self.x + 1, self.y)))
-        } else {
-            None
-        }
-    }
-
-    fn down(&self, size: &Coord) -> Option<Coord> {
-        if self.y < (size.y - 1) {
-            Some(Coord::from((self.x, self.y + 1)))
-        } else {
-            None
-        }
-    }
-
-    fn neighbours(&self, size: &Coord) -> [Option<Coord>; 4] {
-        let left = self.left();
-        let right = self.right(size);
-        let up = self.up();
-        let down = self.down(size);
-        [left, up, right, down]
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,14 +179,13 @@ mod tests {
     fn test_parse() {
         let input = "AAAA\nBBCD\nBBCC\nEEEC";
         let map = Map {
-            map: {
-                vec![
-                    vec!['A', 'A', 'A', 'A'],
-                    vec!['B', 'B', 'C', 'D'],
-                    vec!['B', 'B', 'C', 'C'],
-                    vec!['E', 'E', 'E', 'C'],
-                ]
-            },
+            map: Grid::from_rows(vec![
+                vec!['A', 'A', 'A', 'A'],
+                vec!['B', 'B', 'C', 'D'],
+                vec!['B', 'B', 'C', 'C'],
+                vec!['E', 'E', 'E', 'C'],
+            ])
+            .unwrap(),
         };
         assert_eq!(Map::from_str(input), Ok(map))
     }
@@ -1,7 +1,6 @@
+use aoc_common::{extract_digits, Day, Solution};
 use std::str::FromStr;
 
-use super::RE_DIGITS;
-
 pub fn solve_day(input_file: &str) -> (u64, u64) {
     let machines = parse_input(input_file);
     let a = part_a(&machines);
@@ -9,6 +8,28 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(13, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Machine>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a(machines: &Self::Parsed<'_>) -> u64 {
+        part_a(machines)
+    }
+
+    fn part_b(machines: &Self::Parsed<'_>) -> u64 {
+        part_b(machines)
+    }
+}
+
 fn parse_input(input_file: &str) -> Vec<Machine> {
     input_file
         .trim()
@@ -70,6 +91,12 @@ impl Machine {
     }
 
     fn solve_mathematically(&self, offset: i64) -> Option<u64> {
+        self.presses(offset).map(|(a, b)| a * 3 + b)
+    }
+
+    /// The presses of A and B that reach the prize, as the buttons are never
+    /// parallel there is at most one way.
+    fn presses(&self, offset: i64) -> Option<(u64, u64)> {
         let p_x = self.prize.x + offset;
         let p_y = self.prize.y + offset;
         let nom_b = p_y * self.button_a.x - p_x * self.button_a.y;
@@ -84,8 +111,10 @@ impl Machine {
             return None;
         }
         let a = nom_a / den_a;
-        let cost = (a * 3 + b) as u64;
-        Some(cost)
+        if a < 0 || b < 0 {
+            return None;
+        }
+        Some((a as u64, b as u64))
     }
 }
 
@@ -93,16 +122,7 @@ impl FromStr for Machine {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let digits: Vec<i64> = RE_DIGITS
-            .captures_iter(s)
-            .map(|x| {
-                x.get(0)
-                    .unwrap()
-                    .as_str()
-                    .parse()
-                    .expect("Catches only digits")
-            })
-            .collect();
+        let digits: Vec<i64> = extract_digits(s).collect();
         assert_eq!(digits.len(), 6, "Machine should contain 6 digits");
 
         let button_a: Button = Button {
@@ -128,6 +148,7 @@ impl FromStr for Machine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
     use rstest::rstest;
 
     #[rstest]
@@ -199,4 +220,48 @@ Prize: X=18641, Y=10279
     fn test_solver(#[case] machine: Machine, #[case] cost: Option<u64>) {
         assert_eq!(machine.solve_mathematically(0), cost)
     }
+
+    fn machine_strategy() -> impl Strategy<Value = Machine> {
+        let button = || (1..100i64, 1..100i64).prop_map(|(x, y)| Button { x, y });
+        let miss = prop_oneof![Just((0, 0)), (-2..=2i64, -2..=2i64)];
+        // Negative presses give prizes that can only be reached by going back
+        (button(), button(), -20..=120i64, -20..=120i64, miss)
+            .prop_filter("buttons must not be parallel", |(a, b, ..)| {
+                a.x * b.y != a.y * b.x
+            })
+            .prop_map(|(button_a, button_b, n_a, n_b, (miss_x, miss_y))| {
+                let prize = Coord {
+                    x: n_a * button_a.x + n_b * button_b.x + miss_x,
+                    y: n_a * button_a.y + n_b * button_b.y + miss_y,
+                };
+                Machine {
+                    button_a,
+                    button_b,
+                    prize,
+                }
+            })
+            .prop_filter("prize must be ahead of the claw", |machine| {
+                machine.prize.x >= 0 && machine.prize.y >= 0
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_solve_mathematically_matches_brute_force(machine in machine_strategy()) {
+            let within_limit = machine
+                .presses(0)
+                .filter(|&(a, b)| a <= 100 && b <= 100)
+                .map(|(a, b)| a * 3 + b);
+            prop_assert_eq!(machine.brute_force(), within_limit);
+        }
+
+        #[test]
+        fn test_presses_reach_the_prize(machine in machine_strategy()) {
+            if let Some((a, b)) = machine.presses(0) {
+                let (a, b) = (i64::try_from(a).unwrap(), i64::try_from(b).unwrap());
+                prop_assert_eq!(a * machine.button_a.x + b * machine.button_b.x, machine.prize.x);
+                prop_assert_eq!(a * machine.button_a.y + b * machine.button_b.y, machine.prize.y);
+            }
+        }
+    }
 }
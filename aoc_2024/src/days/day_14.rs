@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::str::FromStr;
 
 pub fn solve_day(input_file: &str) -> (u32, u32) {
@@ -8,6 +9,28 @@ pub fn solve_day(input_file: &str) -> (u32, u32) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(14, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Robot>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_a(robots: &Self::Parsed<'_>) -> u32 {
+        part_a(robots, &(101, 103))
+    }
+
+    fn part_b(robots: &Self::Parsed<'_>) -> u32 {
+        part_b(robots)
+    }
+}
+
 fn parse_input(input_file: &str) -> Vec<Robot> {
     input_file
         .trim()
@@ -1,99 +1,121 @@
+use aoc_common::{Day, Direction, Grid, ParseError, Pos, Solution};
 use std::{
     fmt::{Debug, Display, Write},
     str::FromStr,
 };
 
 pub fn solve_day(input_file: &str) -> (u32, u32) {
-    let a = part_a(input_file);
-    let b = part_b(input_file);
+    let warehouse = parse_input(input_file).unwrap();
+    let a = part_a(&warehouse);
+    let b = part_b(&warehouse);
     (a, b)
 }
 
-fn parse_input(input_file: &str) -> (MapA, Vec<Move>) {
-    let (map_s, move_s) = input_file.split_once("\n\n").expect("Invalid Input");
-    (
-        map_s.parse().expect("Invalid input while parsing map"),
-        parse_moves(move_s),
-    )
+pub const DAY: Day = Day::checked(15, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Warehouse;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(warehouse: &Self::Parsed<'_>) -> u32 {
+        part_a(warehouse)
+    }
+
+    fn part_b(warehouse: &Self::Parsed<'_>) -> u32 {
+        part_b(warehouse)
+    }
 }
 
-fn part_a(input_file: &str) -> u32 {
-    let (map, moves) = parse_input(input_file);
-    let final_map = moves.iter().fold(map, |mut map, move_| {
-        map.apply_move(move_);
-        map
-    });
+/// The map for each part and the robot's moves.
+struct Warehouse {
+    map: MapA,
+    wide_map: MapB,
+    moves: Vec<Direction>,
+}
+
+/// The warehouse, as long as the robot can't leave the map or run into
+/// another robot.
+fn parse_input(input_file: &str) -> Result<Warehouse, ParseError> {
+    let (map_s, move_s) = input_file.split_once("\n\n").ok_or(ParseError::new(
+        "no blank line between the map and the moves",
+    ))?;
+    let map: MapA = map_s.parse()?;
+    let robots = map
+        .map
+        .iter()
+        .filter(|(_, p)| **p == MapPart::Robot)
+        .count();
+    if robots > 1 {
+        return Err(ParseError::new("more than one robot"));
+    }
+    let (width, height) = (map.map.width(), map.map.height());
+    let on_edge = |(x, y): (usize, usize)| x == 0 || y == 0 || x == width - 1 || y == height - 1;
+    if map
+        .map
+        .iter()
+        .any(|(pos, p)| on_edge(pos) && *p != MapPart::Wall)
+    {
+        return Err(ParseError::new("map is not surrounded by walls"));
+    }
+    Ok(Warehouse {
+        map,
+        wide_map: map_s.parse()?,
+        moves: parse_moves(move_s)?,
+    })
+}
+
+fn part_a(warehouse: &Warehouse) -> u32 {
+    let final_map = warehouse
+        .moves
+        .iter()
+        .fold(warehouse.map.clone(), |mut map, move_| {
+            map.apply_move(move_);
+            map
+        });
     final_map.caculate_gps()
 }
 
-fn part_b(input_file: &str) -> u32 {
-    let (map_s, move_s) = input_file.split_once("\n\n").expect("Invalid Input");
-    let (map, moves): (MapB, Vec<Move>) =
-        (map_s.parse().expect("Valid input"), parse_moves(move_s));
-    let final_map = moves.iter().fold(map, |mut map, move_| {
+fn part_b(warehouse: &Warehouse) -> u32 {
+    let map = warehouse.wide_map.clone();
+    let final_map = warehouse.moves.iter().fold(map, |mut map, move_| {
         map.apply_move(move_);
         map
     });
     final_map.caculate_gps()
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct MapA {
-    map: Vec<Vec<MapPart>>,
-    robot_pos: Coord,
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
-
-impl From<(usize, usize)> for Coord {
-    fn from(value: (usize, usize)) -> Self {
-        Coord {
-            x: value.0,
-            y: value.1,
-        }
-    }
-}
-
-impl Coord {
-    fn add_move(&self, move_: &Move) -> Self {
-        // Due to the wall we do not need to take into account over/underflow
-        match move_ {
-            Move::Up => (self.x, self.y - 1).into(),
-            Move::Left => (self.x - 1, self.y).into(),
-            Move::Down => (self.x, self.y + 1).into(),
-            Move::Right => (self.x + 1, self.y).into(),
-        }
-    }
+    map: Grid<MapPart>,
+    robot_pos: Pos,
 }
 
 impl MapA {
     fn caculate_gps(&self) -> u32 {
-        self.map
-            .iter()
-            .enumerate()
-            .map(|(y, row)| {
-                row.iter()
-                    .enumerate()
-                    .map(|(x, s)| if s == &MapPart::Box { y * 100 + x } else { 0 })
-                    .sum::<usize>()
-            })
-            .sum::<usize>() as u32
+        gps(&self.map, MapPart::Box)
     }
 
-    fn apply_move(&mut self, move_: &Move) {
+    fn apply_move(&mut self, move_: &Direction) {
         let pos = self.robot_pos;
         if self.apply_move_if_possible(&pos, move_) {
-            self.robot_pos = self.robot_pos.add_move(move_);
+            self.robot_pos = step(self.robot_pos, *move_);
             self.set(&pos, MapPart::Empty);
         };
     }
 
-    fn apply_move_if_possible(&mut self, coord: &Coord, move_: &Move) -> bool {
-        let new_pos = coord.add_move(move_);
+    fn apply_move_if_possible(&mut self, coord: &Pos, move_: &Direction) -> bool {
+        let new_pos = step(*coord, *move_);
         match self.get(&new_pos) {
             MapPart::Robot => unreachable!("Only one robot is allowed"),
             MapPart::Box => {
@@ -115,100 +137,67 @@ impl MapA {
         }
     }
 
-    fn get(&self, coord: &Coord) -> &MapPart {
-        self.map.get(coord.y).unwrap().get(coord.x).unwrap()
+    fn get(&self, coord: &Pos) -> &MapPart {
+        &self.map[*coord]
     }
 
-    fn set(&mut self, coord: &Coord, part: MapPart) {
-        self.map[coord.y][coord.x] = part;
+    fn set(&mut self, coord: &Pos, part: MapPart) {
+        self.map[*coord] = part;
     }
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 struct MapB {
-    map: Vec<Vec<MapPart>>,
-    robot_pos: Coord,
+    map: Grid<MapPart>,
+    robot_pos: Pos,
 }
 
 impl MapB {
     fn caculate_gps(&self) -> u32 {
-        self.map
-            .iter()
-            .enumerate()
-            .map(|(y, row)| {
-                row.iter()
-                    .enumerate()
-                    .map(|(x, s)| {
-                        if s == &MapPart::LeftBox {
-                            y * 100 + x
-                        } else {
-                            0
-                        }
-                    })
-                    .sum::<usize>()
-            })
-            .sum::<usize>() as u32
+        gps(&self.map, MapPart::LeftBox)
     }
 
     fn _from_debug_str(input_file: &str) -> Self {
-        let mut r_pos = (0, 0);
-
-        let map = input_file
-            .trim()
-            .lines()
-            .enumerate()
-            .map(|(y, row)| {
-                row.char_indices()
-                    .map(|(x, c)| match c {
-                        '#' => MapPart::Wall,
-                        'O' => unreachable!(),
-                        '@' => {
-                            r_pos = (x, y);
-                            MapPart::Robot
-                        }
-                        '.' => MapPart::Empty,
-                        '[' => MapPart::LeftBox,
-                        ']' => MapPart::RightBox,
-                        _ => unreachable!(),
-                    })
-                    .collect()
-            })
-            .collect();
-
-        assert_ne!(r_pos, (0, 0));
+        let map = Grid::parse_with(input_file.trim(), |c| match c {
+            '#' => Ok(MapPart::Wall),
+            '@' => Ok(MapPart::Robot),
+            '.' => Ok(MapPart::Empty),
+            '[' => Ok(MapPart::LeftBox),
+            ']' => Ok(MapPart::RightBox),
+            c => Err(ParseError::unexpected_char(c)),
+        })
+        .unwrap();
+        let robot_pos = map.position(|part| *part == MapPart::Robot).unwrap();
 
-        MapB {
-            map,
-            robot_pos: r_pos.into(),
-        }
+        MapB { map, robot_pos }
     }
 
-    fn apply_move(&mut self, move_: &Move) {
+    fn apply_move(&mut self, move_: &Direction) {
         match move_ {
-            Move::Up | Move::Down => self.apply_move_ver_if_possible(move_),
-            Move::Left | Move::Right => self.apply_move_hor_if_possible(move_),
+            Direction::Up | Direction::Down => self.apply_move_ver_if_possible(move_),
+            Direction::Left | Direction::Right => self.apply_move_hor_if_possible(move_),
         }
     }
 
-    fn apply_move_ver_if_possible(&mut self, move_: &Move) {
+    fn apply_move_ver_if_possible(&mut self, move_: &Direction) {
         let pos = self.robot_pos;
         if self.check_move_if_possible_ver(&pos, move_) {
             self.apply_move_ver(&pos, move_);
-            self.robot_pos = self.robot_pos.add_move(move_);
+            self.robot_pos = step(self.robot_pos, *move_);
             self.set(&pos, MapPart::Empty);
         };
     }
 
-    fn check_move_if_possible_ver(&mut self, coord: &Coord, move_: &Move) -> bool {
+    fn check_move_if_possible_ver(&mut self, coord: &Pos, move_: &Direction) -> bool {
         match self.get(coord) {
             MapPart::Robot => self.check_vertical_can_become_empty(coord, move_),
             MapPart::LeftBox => {
                 self.check_move_if_possible_ver(coord, move_)
-                    && self.check_move_if_possible_ver(&coord.add_move(&Move::Right), move_)
+                    && self.check_move_if_possible_ver(&step(*coord, Direction::Right), move_)
             }
             MapPart::RightBox => {
                 self.check_move_if_possible_ver(coord, move_)
-                    && self.check_move_if_possible_ver(&coord.add_move(&Move::Left), move_)
+                    && self.check_move_if_possible_ver(&step(*coord, Direction::Left), move_)
             }
             MapPart::Empty => true,
             MapPart::Wall => false,
@@ -216,16 +205,16 @@ impl MapB {
         }
     }
 
-    fn check_vertical_can_become_empty(&mut self, coord: &Coord, move_: &Move) -> bool {
-        let new_pos = coord.add_move(move_);
+    fn check_vertical_can_become_empty(&mut self, coord: &Pos, move_: &Direction) -> bool {
+        let new_pos = step(*coord, *move_);
         match self.get(&new_pos) {
             MapPart::LeftBox => {
                 self.check_vertical_can_become_empty(&new_pos, move_)
-                    && self.check_vertical_can_become_empty(&new_pos.add_move(&Move::Right), move_)
+                    && self.check_vertical_can_become_empty(&step(new_pos, Direction::Right), move_)
             }
             MapPart::RightBox => {
                 self.check_vertical_can_become_empty(&new_pos, move_)
-                    && self.check_vertical_can_become_empty(&new_pos.add_move(&Move::Left), move_)
+                    && self.check_vertical_can_become_empty(&step(new_pos, Direction::Left), move_)
             }
             MapPart::Empty => true,
             MapPart::Wall => false,
@@ -233,8 +222,8 @@ impl MapB {
         }
     }
 
-    fn apply_move_ver(&mut self, coord: &Coord, move_: &Move) {
-        let new_pos = &coord.add_move(move_);
+    fn apply_move_ver(&mut self, coord: &Pos, move_: &Direction) {
+        let new_pos = &step(*coord, *move_);
         match self.get(coord) {
             MapPart::Robot => {
                 self.apply_move_ver(new_pos, move_);
@@ -243,19 +232,19 @@ impl MapB {
             }
             MapPart::LeftBox => {
                 self.apply_move_ver(new_pos, move_);
-                self.apply_move_ver(&new_pos.add_move(&Move::Right), move_);
+                self.apply_move_ver(&step(*new_pos, Direction::Right), move_);
                 self.set(new_pos, MapPart::LeftBox);
-                self.set(&new_pos.add_move(&Move::Right), MapPart::RightBox);
+                self.set(&step(*new_pos, Direction::Right), MapPart::RightBox);
                 self.set(coord, MapPart::Empty);
-                self.set(&coord.add_move(&Move::Right), MapPart::Empty);
+                self.set(&step(*coord, Direction::Right), MapPart::Empty);
             }
             MapPart::RightBox => {
                 self.apply_move_ver(new_pos, move_);
-                self.apply_move_ver(&new_pos.add_move(&Move::Left), move_);
+                self.apply_move_ver(&step(*new_pos, Direction::Left), move_);
                 self.set(new_pos, MapPart::RightBox);
-                self.set(&new_pos.add_move(&Move::Left), MapPart::LeftBox);
+                self.set(&step(*new_pos, Direction::Left), MapPart::LeftBox);
                 self.set(coord, MapPart::Empty);
-                self.set(&coord.add_move(&Move::Left), MapPart::Empty);
+                self.set(&step(*coord, Direction::Left), MapPart::Empty);
             }
             MapPart::Empty => (),
             MapPart::Box => unreachable!("No small box allowed"),
@@ -263,17 +252,17 @@ impl MapB {
         }
     }
 
-    fn apply_move_hor_if_possible(&mut self, move_: &Move) {
+    fn apply_move_hor_if_possible(&mut self, move_: &Direction) {
         let pos = self.robot_pos;
         if self.apply_move_if_possible_hor(&pos, move_) {
-            self.robot_pos = self.robot_pos.add_move(move_);
+            self.robot_pos = step(self.robot_pos, *move_);
             self.set(&pos, MapPart::Empty);
         };
     }
 
-    fn apply_move_if_possible_hor(&mut self, coord: &Coord, move_: &Move) -> bool {
+    fn apply_move_if_possible_hor(&mut self, coord: &Pos, move_: &Direction) -> bool {
         // Note the caller is responsible for properly
-        let new_pos = coord.add_move(move_);
+        let new_pos = step(*coord, *move_);
         match self.get(&new_pos) {
             MapPart::Robot => unreachable!("Only one robot is allowed"),
             MapPart::Box => unreachable!("No small boxes allowed"),
@@ -295,12 +284,12 @@ impl MapB {
         }
     }
 
-    fn get(&self, coord: &Coord) -> &MapPart {
-        self.map.get(coord.y).unwrap().get(coord.x).unwrap()
+    fn get(&self, coord: &Pos) -> &MapPart {
+        &self.map[*coord]
     }
 
-    fn set(&mut self, coord: &Coord, part: MapPart) {
-        self.map[coord.y][coord.x] = part;
+    fn set(&mut self, coord: &Pos, part: MapPart) {
+        self.map[*coord] = part;
     }
 }
 
@@ -308,34 +297,26 @@ impl FromStr for MapB {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut r_pos = (0, 0);
-
-        let map = s
+        let rows = s
             .trim()
             .lines()
-            .enumerate()
-            .map(|(y, row)| {
-                row.char_indices()
-                    .flat_map(|(x, c)| match c {
-                        '#' => vec![MapPart::Wall, MapPart::Wall],
-                        'O' => vec![MapPart::LeftBox, MapPart::RightBox],
-                        '@' => {
-                            r_pos = (2 * x, y);
-                            vec![MapPart::Robot, MapPart::Empty]
-                        }
-                        '.' => vec![MapPart::Empty, MapPart::Empty],
-                        c => unreachable!("Encountered unknown value: {c}"),
+            .map(|row| {
+                row.chars()
+                    .map(|c| match c {
+                        '#' => Ok([MapPart::Wall, MapPart::Wall]),
+                        'O' => Ok([MapPart::LeftBox, MapPart::RightBox]),
+                        '@' => Ok([MapPart::Robot, MapPart::Empty]),
+                        '.' => Ok([MapPart::Empty, MapPart::Empty]),
+                        c => Err(ParseError::unexpected_char(c)),
                     })
-                    .collect()
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|parts| parts.concat())
             })
-            .collect();
-
-        assert_ne!(r_pos, (0, 0));
+            .collect::<Result<_, _>>()?;
+        let map = Grid::from_rows(rows)?;
+        let robot_pos = find_robot(&map)?;
 
-        Ok(MapB {
-            map,
-            robot_pos: r_pos.into(),
-        })
+        Ok(MapB { map, robot_pos })
     }
 }
 
@@ -362,69 +343,46 @@ impl MapPart {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct ParseError;
-
 impl FromStr for MapA {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut r_pos = (0, 0);
-
-        let map = s
-            .trim()
-            .lines()
-            .enumerate()
-            .map(|(y, row)| {
-                row.char_indices()
-                    .map(|(x, c)| match c {
-                        '#' => MapPart::Wall,
-                        'O' => MapPart::Box,
-                        '@' => {
-                            r_pos = (x, y);
-                            MapPart::Robot
-                        }
-                        '.' => MapPart::Empty,
-                        _ => unreachable!(),
-                    })
-                    .collect()
-            })
-            .collect();
-
-        assert_ne!(r_pos, (0, 0));
+        let map = Grid::parse_with(s.trim(), |c| match c {
+            '#' => Ok(MapPart::Wall),
+            'O' => Ok(MapPart::Box),
+            '@' => Ok(MapPart::Robot),
+            '.' => Ok(MapPart::Empty),
+            c => Err(ParseError::unexpected_char(c)),
+        })?;
+        let robot_pos = find_robot(&map)?;
 
-        Ok(MapA {
-            map,
-            robot_pos: r_pos.into(),
-        })
+        Ok(MapA { map, robot_pos })
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-enum Move {
-    Up,
-    Left,
-    Down,
-    Right,
+/// One step from `pos`, which the walls around the map keep on it.
+fn step(pos: Pos, direction: Direction) -> Pos {
+    direction.step(pos).expect("the map is walled in")
 }
 
-impl From<char> for Move {
-    fn from(value: char) -> Self {
-        match value {
-            '^' => Move::Up,
-            '<' => Move::Left,
-            'v' => Move::Down,
-            '>' => Move::Right,
-            _ => unreachable!(),
-        }
-    }
+fn find_robot(map: &Grid<MapPart>) -> Result<Pos, ParseError> {
+    map.position(|part| *part == MapPart::Robot)
+        .ok_or(ParseError::new("no robot"))
 }
 
-fn parse_moves(input_file: &str) -> Vec<Move> {
+/// The sum of the GPS coordinates of every `part` on the map.
+fn gps(map: &Grid<MapPart>, part: MapPart) -> u32 {
+    map.iter()
+        .filter(|(_, p)| **p == part)
+        .map(|((x, y), _)| y * 100 + x)
+        .sum::<usize>() as u32
+}
+
+fn parse_moves(input_file: &str) -> Result<Vec<Direction>, ParseError> {
     input_file
         .chars()
         .filter(|c| c != &'\n')
-        .map(|m| m.into())
+        .map(Direction::try_from)
         .collect()
 }
 
@@ -438,15 +396,13 @@ impl Debug for MapB {
 impl Display for MapB {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_char('\n')?;
-        self.map.iter().try_fold(f, |f, row| {
-            let f = row.iter().try_fold(f, |f, part| {
-                f.write_char(part.as_char())?;
-                Ok(f)
-            })?;
-            f.write_char('\n')?;
-            Ok(f)
-        })?;
-        Ok(())
+        write!(f, "{}", self.map)
+    }
+}
+
+impl Display for MapPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.as_char())
     }
 }
 
@@ -456,7 +412,7 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case("########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########", MapA { map: 
+    #[case("########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########", MapA { map: Grid::from_rows(
     vec![
         vec![MapPart::Wall,MapPart::Wall,MapPart::Wall,MapPart::Wall,MapPart::Wall,MapPart::Wall,MapPart::Wall,MapPart::Wall,],
         vec![MapPart::Wall,MapPart::Empty,MapPart::Empty,MapPart::Box,MapPart::Empty,MapPart::Box,MapPart::Empty,MapPart::Wall,],
@@ -466,102 +422,119 @@ mod tests {
         vec![MapPart::Wall,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Box,MapPart::Empty,MapPart::Empty,MapPart::Wall,],
         vec![MapPart::Wall,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Empty,MapPart::Wall,],
         vec![MapPart::Wall,MapPart::Wall,MapPart::Wall,MapPart::Wall,MapPart::Wall,MapPart::Wall,MapPart::Wall,MapPart::Wall,],
-    ], robot_pos: (2, 2).into()
+    ]).unwrap(), robot_pos: (2, 2)
     })]
     fn test_parse(#[case] input: &str, #[case] map: MapA) {
         assert_eq!(input.parse(), Ok(map))
     }
 
     #[rstest]
-    #[case("^", vec![Move::Up])]
-    #[case("^>v<", vec![Move::Up, Move::Right, Move::Down, Move::Left])]
-    #[case("^>v<\n", vec![Move::Up, Move::Right, Move::Down, Move::Left])]
-    fn test_parse_moves(#[case] moves: &str, #[case] expected_moves: Vec<Move>) {
-        assert_eq!(parse_moves(moves), expected_moves)
+    #[case("###\n#@#\n###\n\n<>\n", None)]
+    #[case(
+        "###\n#@#\n###",
+        Some(ParseError::new("no blank line between the map and the moves"))
+    )]
+    #[case("###\n#@#\n###\n\n<x", Some(ParseError::unexpected_char('x')))]
+    #[case("####\n#@@#\n####\n\n<", Some(ParseError::new("more than one robot")))]
+    #[case("###\n#.#\n###\n\n<", Some(ParseError::new("no robot")))]
+    #[case(
+        "###\n.@#\n###\n\n<",
+        Some(ParseError::new("map is not surrounded by walls"))
+    )]
+    fn test_try_parse(#[case] input: &str, #[case] error: Option<ParseError>) {
+        assert_eq!(Solver::try_parse(input).err(), error)
+    }
+
+    #[rstest]
+    #[case("^", vec![Direction::Up])]
+    #[case("^>v<", vec![Direction::Up, Direction::Right, Direction::Down, Direction::Left])]
+    #[case("^>v<\n", vec![Direction::Up, Direction::Right, Direction::Down, Direction::Left])]
+    fn test_parse_moves(#[case] moves: &str, #[case] expected_moves: Vec<Direction>) {
+        assert_eq!(parse_moves(moves), Ok(expected_moves))
     }
 
     #[rstest]
     #[case(
         "########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########",
-        Move::Up,
+        Direction::Up,
         "########\n#.@O.O.#\n##..O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########"
     )]
     #[case(
         "########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########",
-        Move::Left,
+        Direction::Left,
         "########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########"
     )]
     #[case(
         "########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########",
-        Move::Up,
+        Direction::Up,
         "########\n#.@O.O.#\n##..O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########"
     )]
     #[case(
         "########\n#.@O.O.#\n##..O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########",
-        Move::Up,
+        Direction::Up,
         "########\n#.@O.O.#\n##..O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########"
     )]
     #[case(
         "########\n#.@O.O.#\n##..O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########",
-        Move::Right,
+        Direction::Right,
         "########\n#..@OO.#\n##..O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########"
     )]
     #[case(
         "########\n#..@OO.#\n##..O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########",
-        Move::Right,
+        Direction::Right,
         "########\n#...@OO#\n##..O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########"
     )]
     #[case(
         "########\n#...@OO#\n##..O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########",
-        Move::Right,
+        Direction::Right,
         "########\n#...@OO#\n##..O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########"
     )]
     #[case(
         "########\n#...@OO#\n##..O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########",
-        Move::Down,
+        Direction::Down,
         "########\n#....OO#\n##..@..#\n#...O..#\n#.#.O..#\n#...O..#\n#...O..#\n########"
     )]
     #[case(
         "########\n#....OO#\n##..@..#\n#...O..#\n#.#.O..#\n#...O..#\n#...O..#\n########",
-        Move::Down,
+        Direction::Down,
         "########\n#....OO#\n##..@..#\n#...O..#\n#.#.O..#\n#...O..#\n#...O..#\n########"
     )]
     #[case(
         "########\n#....OO#\n##..@..#\n#...O..#\n#.#.O..#\n#...O..#\n#...O..#\n########",
-        Move::Left,
+        Direction::Left,
         "########\n#....OO#\n##.@...#\n#...O..#\n#.#.O..#\n#...O..#\n#...O..#\n########"
     )]
     #[case(
         "########\n#....OO#\n##.@...#\n#...O..#\n#.#.O..#\n#...O..#\n#...O..#\n########",
-        Move::Down,
+        Direction::Down,
         "########\n#....OO#\n##.....#\n#..@O..#\n#.#.O..#\n#...O..#\n#...O..#\n########"
     )]
     #[case(
         "########\n#....OO#\n##.....#\n#..@O..#\n#.#.O..#\n#...O..#\n#...O..#\n########",
-        Move::Right,
+        Direction::Right,
         "########\n#....OO#\n##.....#\n#...@O.#\n#.#.O..#\n#...O..#\n#...O..#\n########"
     )]
     #[case(
         "########\n#....OO#\n##.....#\n#...@O.#\n#.#.O..#\n#...O..#\n#...O..#\n########",
-        Move::Right,
+        Direction::Right,
         "########\n#....OO#\n##.....#\n#....@O#\n#.#.O..#\n#...O..#\n#...O..#\n########"
     )]
     #[case(
         "########\n#....OO#\n##.....#\n#....@O#\n#.#.O..#\n#...O..#\n#...O..#\n########",
-        Move::Down,
+        Direction::Down,
         "########\n#....OO#\n##.....#\n#.....O#\n#.#.O@.#\n#...O..#\n#...O..#\n########"
     )]
     #[case(
         "########\n#....OO#\n##.....#\n#.....O#\n#.#.O@.#\n#...O..#\n#...O..#\n########",
-        Move::Left,
+        Direction::Left,
         "########\n#....OO#\n##.....#\n#.....O#\n#.#O@..#\n#...O..#\n#...O..#\n########"
     )]
     #[case(
         "########\n#....OO#\n##.....#\n#.....O#\n#.#O@..#\n#...O..#\n#...O..#\n########",
-        Move::Left,
+        Direction::Left,
         "########\n#....OO#\n##.....#\n#.....O#\n#.#O@..#\n#...O..#\n#...O..#\n########"
     )]
-    fn test_apply_move(#[case] mut map: MapA, #[case] move_: Move, #[case] map_after: MapA) {
+    fn test_apply_move(#[case] mut map: MapA, #[case] move_: Direction, #[case] map_after: MapA) {
         map.apply_move(&move_);
         assert_eq!(map, map_after)
     }
@@ -582,7 +555,7 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
 "
     )]
     fn test_part_a(#[case] input_file: &str) {
-        let out = part_a(input_file);
+        let out = part_a(&parse_input(input_file).unwrap());
         assert_eq!(out, 10092)
     }
 
@@ -597,7 +570,7 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
     }
 
     #[rstest]
-    #[case("########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########", MapB { map: 
+    #[case("########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########", MapB { map: Grid::from_rows(
         vec![
             vec![MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,],
             vec![MapPart::Wall, MapPart::Wall,MapPart::Empty, MapPart::Empty,MapPart::Empty, MapPart::Empty,MapPart::LeftBox, MapPart::RightBox,MapPart::Empty, MapPart::Empty,MapPart::LeftBox, MapPart::RightBox,MapPart::Empty, MapPart::Empty,MapPart::Wall, MapPart::Wall,],
@@ -607,7 +580,7 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
             vec![MapPart::Wall, MapPart::Wall,MapPart::Empty, MapPart::Empty,MapPart::Empty, MapPart::Empty,MapPart::Empty, MapPart::Empty,MapPart::LeftBox, MapPart::RightBox,MapPart::Empty, MapPart::Empty,MapPart::Empty, MapPart::Empty,MapPart::Wall, MapPart::Wall,],
             vec![MapPart::Wall, MapPart::Wall,MapPart::Empty, MapPart::Empty,MapPart::Empty, MapPart::Empty,MapPart::Empty, MapPart::Empty,MapPart::Empty, MapPart::Empty,MapPart::Empty, MapPart::Empty,MapPart::Empty, MapPart::Empty,MapPart::Wall, MapPart::Wall,],
             vec![MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,MapPart::Wall, MapPart::Wall,],
-        ], robot_pos: (4, 2).into()
+        ]).unwrap(), robot_pos: (4, 2)
         })]
     fn test_parse_map_b(#[case] input_file: &str, #[case] map: MapB) {
         assert_eq!(input_file.parse(), Ok(map))
@@ -616,60 +589,64 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
     #[rstest]
     #[case(
         "##############\n##......##..##\n##..........##\n##....[][]@.##\n##....[]....##\n##..........##\n##############",
-        Move::Left,
+        Direction::Left,
         "##############\n##......##..##\n##..........##\n##...[][]@..##\n##....[]....##\n##..........##\n##############"
     )]
     #[case(
         "##############\n##......##..##\n##..........##\n##...[][]@..##\n##....[]....##\n##..........##\n##############",
-        Move::Down,
+        Direction::Down,
         "##############\n##......##..##\n##..........##\n##...[][]...##\n##....[].@..##\n##..........##\n##############"
     )]
     #[case(
         "##############\n##......##..##\n##..........##\n##...[][]...##\n##....[].@..##\n##..........##\n##############",
-        Move::Down,
+        Direction::Down,
         "##############\n##......##..##\n##..........##\n##...[][]...##\n##....[]....##\n##.......@..##\n##############"
     )]
     #[case(
         "##############\n##......##..##\n##..........##\n##...[][]...##\n##....[]....##\n##.......@..##\n##############",
-        Move::Left,
+        Direction::Left,
         "##############\n##......##..##\n##..........##\n##...[][]...##\n##....[]....##\n##......@...##\n##############"
     )]
     #[case(
         "##############\n##......##..##\n##..........##\n##...[][]...##\n##....[]....##\n##......@...##\n##############",
-        Move::Left,
+        Direction::Left,
         "##############\n##......##..##\n##..........##\n##...[][]...##\n##....[]....##\n##.....@....##\n##############"
     )]
     #[case(
         "##############\n##......##..##\n##..........##\n##...[][]...##\n##....[]....##\n##.....@....##\n##############",
-        Move::Up,
+        Direction::Up,
         "##############\n##......##..##\n##...[][]...##\n##....[]....##\n##.....@....##\n##..........##\n##############"
     )]
     #[case(
         "##############\n##......##..##\n##...[][]...##\n##....[]....##\n##.....@....##\n##..........##\n##############",
-        Move::Up,
+        Direction::Up,
         "##############\n##......##..##\n##...[][]...##\n##....[]....##\n##.....@....##\n##..........##\n##############"
     )]
     #[case(
         "##############\n##......##..##\n##...[][]...##\n##....[]....##\n##.....@....##\n##..........##\n##############",
-        Move::Left,
+        Direction::Left,
         "##############\n##......##..##\n##...[][]...##\n##....[]....##\n##....@.....##\n##..........##\n##############"
     )]
     #[case(
         "##############\n##......##..##\n##...[][]...##\n##....[]....##\n##....@.....##\n##..........##\n##############",
-        Move::Left,
+        Direction::Left,
         "##############\n##......##..##\n##...[][]...##\n##....[]....##\n##...@......##\n##..........##\n##############"
     )]
     #[case(
         "##############\n##......##..##\n##...[][]...##\n##....[]....##\n##...@......##\n##..........##\n##############",
-        Move::Up,
+        Direction::Up,
         "##############\n##......##..##\n##...[][]...##\n##...@[]....##\n##..........##\n##..........##\n##############"
     )]
     #[case(
         "##############\n##......##..##\n##...[][]...##\n##...@[]....##\n##..........##\n##..........##\n##############",
-        Move::Up,
+        Direction::Up,
         "##############\n##...[].##..##\n##...@.[]...##\n##....[]....##\n##..........##\n##..........##\n##############"
     )]
-    fn test_apply_move_b(#[case] init_map: &str, #[case] move_: Move, #[case] map_after: &str) {
+    fn test_apply_move_b(
+        #[case] init_map: &str,
+        #[case] move_: Direction,
+        #[case] map_after: &str,
+    ) {
         dbg!(&move_);
         let mut init_map = MapB::_from_debug_str(init_map);
         let map_after = MapB::_from_debug_str(map_after);
@@ -694,7 +671,7 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
 "
     )]
     fn test_part_b(#[case] input_file: &str) {
-        let out = part_b(input_file);
+        let out = part_b(&parse_input(input_file).unwrap());
         assert_eq!(out, 9021)
     }
 }
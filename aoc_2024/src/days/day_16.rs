@@ -1,7 +1,5 @@
-use std::{
-    collections::{BTreeSet, BinaryHeap, HashMap},
-    str::FromStr,
-};
+use aoc_common::{dijkstra, Day, Direction, Grid, ParseError, Pos, Solution};
+use std::{collections::HashSet, str::FromStr};
 
 pub fn solve_day(input_file: &str) -> (u32, u32) {
     let map: Map = input_file.parse().expect("Valid input");
@@ -10,265 +8,126 @@ pub fn solve_day(input_file: &str) -> (u32, u32) {
     (a, b)
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
+pub const DAY: Day = Day::checked(16, &Solver);
 
-impl From<(usize, usize)> for Coord {
-    fn from(value: (usize, usize)) -> Self {
-        Coord {
-            x: value.0,
-            y: value.1,
-        }
-    }
-}
+struct Solver;
 
-#[derive(Debug, PartialEq, Eq)]
-enum Part {
-    Wall,
-    Empty,
-}
+impl Solution for Solver {
+    type Parsed<'a> = Map;
+    type AnswerA = u32;
+    type AnswerB = u32;
 
-#[derive(Debug, PartialEq, Eq)]
-struct Map {
-    map: Vec<Vec<Part>>,
-    start: Coord,
-    end: Coord,
-}
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.parse().expect("Valid input")
+    }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.parse()
+    }
 
-impl Direction {
-    fn clock(&self) -> Self {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
+    fn part_a(map: &Self::Parsed<'_>) -> u32 {
+        map.solve_map().0
     }
 
-    fn counter(&self) -> Self {
-        match self {
-            Direction::Up => Self::Left,
-            Direction::Right => Self::Up,
-            Direction::Down => Self::Right,
-            Direction::Left => Self::Down,
-        }
+    fn part_b(map: &Self::Parsed<'_>) -> u32 {
+        map.solve_map().1
     }
 }
 
-impl Coord {
-    fn add(&self, dir: &Direction) -> Coord {
-        match dir {
-            Direction::Up => (self.x, self.y - 1).into(),
-            Direction::Right => (self.x + 1, self.y).into(),
-            Direction::Down => (self.x, self.y + 1).into(),
-            Direction::Left => (self.x - 1, self.y).into(),
-        }
-    }
+#[derive(Debug, PartialEq, Eq)]
+enum Part {
+    Wall,
+    Empty,
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct ParseError;
+struct Map {
+    map: Grid<Part>,
+    start: Pos,
+    end: Pos,
+}
 
 impl FromStr for Map {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut start = (usize::MAX, usize::MAX);
-        let mut end = (usize::MAX, usize::MAX);
-
-        let map = s
-            .trim()
-            .lines()
-            .enumerate()
-            .map(|(y, row)| {
-                row.char_indices()
-                    .map(|(x, part)| match part {
-                        '#' => Part::Wall,
-                        '.' => Part::Empty,
-                        'S' => {
-                            start = (x, y);
-                            Part::Empty
-                        }
-                        'E' => {
-                            end = (x, y);
-                            Part::Empty
-                        }
-                        _ => unreachable!(),
-                    })
-                    .collect()
-            })
-            .collect();
+        let tiles = Grid::parse_with(s.trim(), |c| match c {
+            '#' | '.' | 'S' | 'E' => Ok(c),
+            c => Err(ParseError::unexpected_char(c)),
+        })?;
+        let find = |tile: char| {
+            tiles
+                .position(|&c| c == tile)
+                .ok_or(ParseError::new(format!("no {tile} on the map")))
+        };
 
         Ok(Map {
-            map,
-            start: start.into(),
-            end: end.into(),
+            map: tiles.map(|&c| if c == '#' { Part::Wall } else { Part::Empty }),
+            start: find('S')?,
+            end: find('E')?,
         })
     }
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 struct State {
-    pos: Coord,
+    pos: Pos,
     dir: Direction,
 }
 
-#[derive(Debug, Default)]
-struct DijkstraState {
-    todo: BinaryHeap<(i32, State)>,
-    dist: HashMap<State, i32>,
-    prev: HashMap<State, Vec<State>>,
-}
-
 impl Map {
     fn solve_map(&self) -> (u32, u32) {
-        let (dijkstra_state, mut end_nodes) = self.dijkstra();
-
-        let max_cost = dijkstra_state
-            .dist
-            .get(end_nodes.first().unwrap())
-            .unwrap()
-            .unsigned_abs();
-
-        let mut visited = BTreeSet::<Coord>::from([self.start]);
-        while let Some(node) = end_nodes.pop() {
-            visited.insert(node.pos);
-            if let Some(prevs) = dijkstra_state.prev.get(&node) {
-                assert!(prevs.len() <= 2, "{:?}", prevs);
-                end_nodes.extend(prevs);
-            }
-        }
-
-        (max_cost, visited.len() as u32)
-    }
-
-    fn dijkstra(&self) -> (DijkstraState, Vec<State>) {
-        let start = (
-            0,
-            State {
-                pos: self.start,
-                dir: Direction::Right,
-            },
+        let start = State {
+            pos: self.start,
+            dir: Direction::Right,
+        };
+        let search = dijkstra(
+            [start],
+            |state| self.moves(state),
+            |state| state.pos == self.end,
         );
-        let mut dijkstra_state = DijkstraState::default();
-        dijkstra_state.todo.push(start);
-        dijkstra_state.dist.insert(start.1, 0);
+        let cost = search.goal_cost().expect("The end is reachable");
 
-        let mut end_nodes = vec![];
-        let mut end_cost = i32::MIN;
+        // The reindeer may reach the end facing different ways at the same cost.
+        let tiles: HashSet<_> = search
+            .on_cheapest_paths(search.goals().iter().copied())
+            .into_iter()
+            .map(|state| state.pos)
+            .collect();
 
-        while let Some((cost, node)) = dijkstra_state.todo.pop() {
-            self.dijkstra_step(
-                &mut dijkstra_state,
-                &mut end_nodes,
-                &mut end_cost,
-                cost,
-                node,
-            );
-        }
-        (dijkstra_state, end_nodes)
+        (cost, tiles.len() as u32)
     }
 
-    fn dijkstra_step(
-        &self,
-        dijkstra_state: &mut DijkstraState,
-        end_nodes: &mut Vec<State>,
-        end_cost: &mut i32,
-        cost: i32,
-        node: State,
-    ) {
-        if node.pos == self.end && cost > *end_cost {
-            *end_cost = cost;
-            // There might be multiple directions in which the reindeer can end.
-            match dijkstra_state
-                .dist
-                .get(&node)
-                .expect("Must be inserted")
-                .cmp(&cost)
-            {
-                std::cmp::Ordering::Less => *end_nodes = vec![node],
-                std::cmp::Ordering::Equal => end_nodes.push(node),
-                std::cmp::Ordering::Greater => (),
-            }
-        }
-
-        if self.get_straight(&node) == &Part::Empty {
-            let new_state = State {
-                pos: node.pos.add(&node.dir),
-                dir: node.dir,
+    /// Stepping forward costs 1 and turning 1000, turning only where there is
+    /// somewhere to go.
+    fn moves(&self, state: &State) -> Vec<(State, u32)> {
+        let mut moves = Vec::with_capacity(3);
+        if let Some(pos) = self.open_ahead(state) {
+            let forward = State {
+                pos,
+                dir: state.dir,
             };
-            check_insert_new(dijkstra_state, new_state, cost - 1, node);
-        }
-
-        let clock_state = State {
-            pos: node.pos,
-            dir: node.dir.clock(),
-        };
-        if self.get_straight(&clock_state) == &Part::Empty {
-            check_insert_new(dijkstra_state, clock_state, cost - 1000, node);
+            moves.push((forward, 1));
         }
-
-        let counter_state = State {
-            pos: node.pos,
-            dir: node.dir.counter(),
-        };
-        if self.get_straight(&counter_state) == &Part::Empty {
-            check_insert_new(dijkstra_state, counter_state, cost - 1000, node);
+        for dir in [state.dir.turn_right(), state.dir.turn_left()] {
+            let turned = State {
+                pos: state.pos,
+                dir,
+            };
+            if self.open_ahead(&turned).is_some() {
+                moves.push((turned, 1000));
+            }
         }
+        moves
     }
 
-    fn get(&self, coord: &Coord) -> &Part {
-        self.map.get(coord.y).unwrap().get(coord.x).unwrap()
-    }
-
-    fn get_straight(&self, state: &State) -> &Part {
-        let straight = state.pos.add(&state.dir);
-        self.get(&straight)
+    /// The tile in front of the reindeer, if it isn't a wall.
+    fn open_ahead(&self, state: &State) -> Option<Pos> {
+        let ahead = state.dir.step(state.pos)?;
+        (self.map.get(ahead)? == &Part::Empty).then_some(ahead)
     }
 }
 
-fn check_insert_new(dijkstra_state: &mut DijkstraState, next_state: State, cost: i32, node: State) {
-    // todo: &mut BinaryHeap<(i32, State)>,
-    // dist: &mut HashMap<State, i32>,
-    // prev: &mut HashMap<State, Vec<State>>,
-    dijkstra_state
-        .dist
-        .entry(next_state)
-        .and_modify(|prev_cost| {
-            if *prev_cost <= cost {
-                dijkstra_state.todo.push((cost, next_state));
-                if *prev_cost == cost {
-                    let v = dijkstra_state
-                        .prev
-                        .get_mut(&next_state)
-                        .expect("Must be present");
-                    v.push(node);
-                    // v.sort();
-                    v.dedup();
-                } else {
-                    dijkstra_state.prev.insert(next_state, vec![node]);
-                }
-                *prev_cost = cost;
-            }
-        })
-        .or_insert_with(|| {
-            dijkstra_state.todo.push((cost, next_state));
-            dijkstra_state.prev.insert(next_state, vec![node]);
-            cost
-        });
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,7 +136,7 @@ mod tests {
     #[rstest]
     #[case(
         "###############\n#.......#....E#\n#.#.###.#.###.#\n#.....#.#...#.#\n#.###.#####.#.#\n#.#.#.......#.#\n#.#.#####.###.#\n#...........#.#\n###.#.#####.#.#\n#...#.....#.#.#\n#.#.#.###.#.#.#\n#.....#...#.#.#\n#.###.#.#.#.#.#\n#S..#.....#...#\n###############",
-        Map { map: vec![
+        Map { map: Grid::from_rows(vec![
             vec![Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall],
             vec![Part::Wall,Part::Empty,Part::Empty,Part::Empty,Part::Empty,Part::Empty,Part::Empty,Part::Empty,Part::Wall,Part::Empty,Part::Empty,Part::Empty,Part::Empty,Part::Empty,Part::Wall],
             vec![Part::Wall,Part::Empty,Part::Wall,Part::Empty,Part::Wall,Part::Wall,Part::Wall,Part::Empty,Part::Wall,Part::Empty,Part::Wall,Part::Wall,Part::Wall,Part::Empty,Part::Wall],
@@ -293,8 +152,8 @@ mod tests {
             vec![Part::Wall,Part::Empty,Part::Wall,Part::Wall,Part::Wall,Part::Empty,Part::Wall,Part::Empty,Part::Wall,Part::Empty,Part::Wall,Part::Empty,Part::Wall,Part::Empty,Part::Wall],
             vec![Part::Wall,Part::Empty,Part::Empty,Part::Empty,Part::Wall,Part::Empty,Part::Empty,Part::Empty,Part::Empty,Part::Empty,Part::Wall,Part::Empty,Part::Empty,Part::Empty,Part::Wall],
             vec![Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall,Part::Wall],
-        ], start: Coord {x: 1, y: 13},
-        end: Coord {x: 13, y: 1},}
+        ]).unwrap(), start: (1, 13),
+        end: (13, 1),}
     )]
     fn test_parse(#[case] input: &str, #[case] map: Map) {
         assert_eq!(input.parse(), Ok(map))
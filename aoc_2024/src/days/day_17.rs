@@ -1,62 +1,112 @@
+use aoc_common::{Day, ParseError, Solution};
 use rayon::iter::ParallelIterator;
 use rayon::prelude::*;
 
-use super::RE_DIGITS;
-
 pub fn solve_day(input_file: &str) -> (String, u64) {
-    let a = part_a(&input_file);
-    // let b = part_b(&input_file);
+    let (computer, program) = parse(input_file).unwrap();
+    let a = part_a(&computer, &program);
+    // let b = part_b(&computer, &program);
     let b = 0;
     (a, b)
 }
 
-fn part_a(input_file: &str) -> String {
-    let (mut computer, program) = parse(input_file);
-    let out = computer.run(&program);
+pub const DAY: Day = Day::checked(17, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = (Computer, Vec<u64>);
+    type AnswerA = String;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a((computer, program): &Self::Parsed<'_>) -> String {
+        part_a(computer, program)
+    }
+
+    fn part_b(_input: &Self::Parsed<'_>) -> u64 {
+        // part_b(computer, program)
+        0
+    }
+}
+
+fn part_a(computer: &Computer, program: &[u64]) -> String {
+    let mut computer = *computer;
+    let out = computer.run(program);
     out.iter()
         .map(|d| d.to_string())
         .collect::<Vec<String>>()
         .join(",")
 }
 
-fn part_b(input_file: &str) -> u64 {
-    let (computer, program) = parse(input_file);
-    let MIN_A = 8usize.pow((program.len() - 1) as u32);
-    println!("{}", MIN_A);
+#[allow(dead_code)]
+fn part_b(computer: &Computer, program: &[u64]) -> u64 {
+    let min_a = 8usize.pow((program.len() - 1) as u32);
+    println!("{}", min_a);
 
-    let first = (MIN_A..10_000_000_000_000_000)
+    let first = (min_a..10_000_000_000_000_000)
         .into_par_iter()
         .by_exponential_blocks()
         .find_first(|a: &usize| {
-            let mut computer = computer;
+            let mut computer = *computer;
             computer.reg.a = *a as u64;
-            program == computer.run(&program)
+            program == computer.run(program)
         });
 
     if let Some(first) = first {
         println!("A: {first}");
-        return first as u64;
+        first as u64
     } else {
         panic!()
     }
 }
 
-fn parse(input_file: &str) -> (Computer, Vec<u64>) {
-    let digits: Vec<u64> = RE_DIGITS
-        .captures_iter(input_file)
-        .map(|x| {
-            x.get(0)
-                .unwrap()
-                .as_str()
-                .parse()
-                .expect("Catches only digits")
+fn parse(input_file: &str) -> Result<(Computer, Vec<u64>), ParseError> {
+    let (registers, program) = input_file
+        .trim()
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::new("expected registers and a program"))?;
+
+    let registers = registers
+        .lines()
+        .zip(["A", "B", "C"])
+        .map(|(line, name)| {
+            line.strip_prefix(&format!("Register {name}: "))
+                .ok_or_else(|| ParseError::new(format!("expected register {name}")))?
+                .parse::<u64>()
+                .map_err(ParseError::from)
         })
-        .collect();
+        .collect::<Result<Vec<u64>, _>>()?;
+    let &[a, b, c] = registers.as_slice() else {
+        return Err(ParseError::new("expected registers A, B and C"));
+    };
+
+    let program = program
+        .strip_prefix("Program: ")
+        .ok_or_else(|| ParseError::new("expected a program"))?
+        .split(',')
+        .map(str::parse::<u64>)
+        .collect::<Result<Vec<u64>, _>>()?;
+    if program.len() % 2 != 0 {
+        return Err(ParseError::new("every instruction needs an operand"));
+    }
+    for ins in program.chunks(2) {
+        if ins.iter().any(|&n| n > 7) {
+            return Err(ParseError::new("instructions and operands are 3 bits"));
+        }
+        if Instruction::from_opcode(&ins[0]).has_combo_operand() && ins[1] == 7 {
+            return Err(ParseError::new("combo operand 7 is reserved"));
+        }
+    }
 
-    (
-        (digits[0], digits[1], digits[2]).into(),
-        digits.into_iter().skip(3).collect(),
-    )
+    Ok(((a, b, c).into(), program))
 }
 
 #[derive(Debug)]
@@ -85,6 +135,13 @@ impl Instruction {
             _ => unreachable!("Should not be reachable"),
         }
     }
+
+    fn has_combo_operand(&self) -> bool {
+        matches!(
+            self,
+            Self::Adv | Self::Bst | Self::Out | Self::Bdv | Self::Cdv
+        )
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -103,7 +160,7 @@ struct Computer {
 impl Computer {
     fn run(&mut self, program: &[u64]) -> Vec<u64> {
         let mut out = vec![];
-        while let Some((ins, lit)) = self.get_ins_lit(&program) {
+        while let Some((ins, lit)) = self.get_ins_lit(program) {
             let inst = Instruction::from_opcode(&ins);
             match inst {
                 Instruction::Adv => self.reg.a = self.division(lit),
@@ -129,7 +186,7 @@ impl Computer {
 
     fn division(&self, lit: u64) -> u64 {
         let t = 2_u64.pow(self.get_combo(lit) as u32);
-        return self.reg.a / t;
+        self.reg.a / t
     }
 
     fn get_ins_lit(&self, program: &[u64]) -> Option<(u64, u64)> {
@@ -181,7 +238,23 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    fn test_parse() {}
+    fn test_parse() {
+        let (computer, program) =
+            parse("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0").unwrap();
+        assert_eq!(computer.reg, (729, 0, 0).into());
+        assert_eq!(program, vec![0, 1, 5, 4, 3, 0]);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("Register A: 729\nRegister B: 0\n\nProgram: 0,1")]
+    #[case("Register A: 729\nRegister B: 0\nRegister C: x\n\nProgram: 0,1")]
+    #[case("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5")]
+    #[case("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8")]
+    #[case("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 5,7")]
+    fn test_parse_invalid(#[case] input: &str) {
+        assert!(parse(input).is_err())
+    }
 
     #[rstest]
     #[case((0, 0, 9).into(), vec![2, 6], (0, 1, 9).into(), vec![])]
@@ -207,7 +280,8 @@ mod tests {
         "4,6,3,5,6,3,5,2,1,0"
     )]
     fn test_part_a(#[case] input: &str, #[case] output: &str) {
-        assert_eq!(part_a(input), output)
+        let (computer, program) = parse(input).unwrap();
+        assert_eq!(part_a(&computer, &program), output)
     }
 
     #[rstest]
@@ -216,6 +290,7 @@ mod tests {
         117440
     )]
     fn test_part_b(#[case] input: &str, #[case] output: u64) {
-        assert_eq!(part_b(input), output)
+        let (computer, program) = parse(input).unwrap();
+        assert_eq!(part_b(&computer, &program), output)
     }
 }
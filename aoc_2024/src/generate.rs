@@ -0,0 +1,76 @@
+//! Random inputs for stress testing days beyond their official input.
+
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub static GENERATORS: &[Generator] = &[Generator {
+    day: 6,
+    generate: day_06,
+    unit: "side",
+    sizes: &[32, 64, 130, 256, 512],
+}];
+
+/// A square guard map with sides of `size`, where the guard walks off the
+/// map rather than getting stuck in a loop.
+pub fn day_06(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    loop {
+        let mut map: Vec<Vec<char>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.gen_ratio(1, 20) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let (x, y) = (rng.gen_range(0..size), rng.gen_range(0..size));
+        map[y][x] = '^';
+        if guard_leaves(&map, (x, y)) {
+            return map
+                .iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect();
+        }
+    }
+}
+
+fn guard_leaves(map: &[Vec<char>], start: (usize, usize)) -> bool {
+    let size = map.len() as isize;
+    let (mut x, mut y) = (start.0 as isize, start.1 as isize);
+    let (mut dx, mut dy) = (0, -1);
+    // The guard can't be in a state more often than there are states
+    for _ in 0..4 * size * size {
+        let (next_x, next_y) = (x + dx, y + dy);
+        if !(0..size).contains(&next_x) || !(0..size).contains(&next_y) {
+            return true;
+        }
+        if map[next_y as usize][next_x as usize] == '#' {
+            (dx, dy) = (-dy, dx);
+        } else {
+            (x, y) = (next_x, next_y);
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::YEAR;
+
+    #[test]
+    fn test_generated_day_06_is_solvable() {
+        let input = day_06(40, 1);
+        assert_eq!(input.lines().count(), 40);
+        assert!(input.lines().all(|line| line.len() == 40));
+        assert_eq!(input.matches('^').count(), 1);
+        YEAR.day(6).unwrap().solution.solve(&input);
+    }
+
+    #[test]
+    fn test_generators_are_deterministic() {
+        for generator in GENERATORS {
+            assert_eq!(generator.generate(20, 7), generator.generate(20, 7));
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod days;
+#[cfg(feature = "generate")]
+pub mod generate;
 
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
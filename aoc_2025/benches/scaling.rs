@@ -0,0 +1 @@
+aoc_common::scaling_main!(&aoc_2025::days::YEAR, aoc_2025::generate::GENERATORS);
@@ -1,10 +1,38 @@
+use aoc_common::{Day, ParseError, Solution};
+
 pub fn solve_day(input_file: &str) -> (u32, u32) {
-    let instructions = parse(input_file);
+    let instructions = parse(input_file).unwrap();
     let a = part_a(&instructions);
     let b = part_b(&instructions);
     (a, b)
 }
 
+pub const DAY: Day = Day::checked(1, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Instruction>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(instructions: &Self::Parsed<'_>) -> u32 {
+        part_a(instructions)
+    }
+
+    fn part_b(instructions: &Self::Parsed<'_>) -> u32 {
+        part_b(instructions)
+    }
+}
+
 pub fn part_a(instructions: &[Instruction]) -> u32 {
     instructions
         .iter()
@@ -56,12 +84,14 @@ enum Direction {
     Right,
 }
 
-impl From<char> for Direction {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, ParseError> {
         match c {
-            'L' => Direction::Left,
-            'R' => Direction::Right,
-            _ => panic!("Invalid direction character: {}", c),
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(ParseError::unexpected_char(c)),
         }
     }
 }
@@ -72,27 +102,26 @@ pub struct Instruction {
 }
 
 impl Instruction {
-    fn new(direction: Direction, number: u32) -> Self {
+    fn new(direction: Direction, number: i32) -> Self {
         if direction == Direction::Left {
-            Instruction {
-                number: -(number as i32),
-            }
+            Instruction { number: -number }
         } else {
-            Instruction {
-                number: number as i32,
-            }
+            Instruction { number }
         }
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let dir_char = line.chars().next().unwrap();
-            let direction = Direction::from(dir_char);
-            let number: u32 = line[1..].parse().unwrap();
-            Instruction::new(direction, number)
+            let mut chars = line.chars();
+            let dir_char = chars
+                .next()
+                .ok_or_else(|| ParseError::new("empty instruction"))?;
+            let direction = Direction::try_from(dir_char)?;
+            let number = chars.as_str().parse::<u16>()?;
+            Ok(Instruction::new(direction, number.into()))
         })
         .collect()
 }
@@ -107,7 +136,7 @@ mod tests {
     #[rstest]
     fn test_parse() {
         let input_file = read_test_day_input("01");
-        let input = parse(&input_file);
+        let input = parse(&input_file).unwrap();
         assert_eq!(
             input,
             vec![
@@ -128,7 +157,7 @@ mod tests {
     #[rstest]
     fn test_day_01_a() {
         let input_file = read_test_day_input("01");
-        let instructions = parse(&input_file);
+        let instructions = parse(&input_file).unwrap();
         let result = part_a(&instructions);
         assert_eq!(result, 3)
     }
@@ -136,7 +165,7 @@ mod tests {
     #[rstest]
     fn test_day_01_b() {
         let input_file = read_test_day_input("01");
-        let instructions = parse(&input_file);
+        let instructions = parse(&input_file).unwrap();
         let result = part_b(&instructions);
         assert_eq!(result, 6)
     }
@@ -1,5 +1,6 @@
-use anyhow::{Error, Result, anyhow};
-use std::str::FromStr;
+use anyhow::{Result, anyhow};
+use aoc_common::{Day, IntervalSet, Solution};
+use std::ops::RangeInclusive;
 
 pub fn solve_day(input_file: &str) -> (u64, u64) {
     let ranges = parse(input_file);
@@ -8,34 +9,63 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
-pub fn part_a(ranges: &[Range]) -> u64 {
-    ranges.iter().map(|r| r.sum_invalid()).sum()
-}
+pub const DAY: Day = Day::new(2, &Solver);
 
-pub fn part_b(ranges: &[Range]) -> u64 {
-    ranges.iter().map(|r| r.sum_invalid_b()).sum()
-}
+struct Solver;
 
-#[derive(Debug, PartialEq)]
-pub struct Range {
-    start: u64,
-    end: u64,
-}
+impl Solution for Solver {
+    type Parsed<'a> = Vec<RangeInclusive<u64>>;
+    type AnswerA = u64;
+    type AnswerB = u64;
 
-impl Range {
-    fn new(start: u64, end: u64) -> Self {
-        Range { start, end }
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
     }
 
-    fn sum_invalid(&self) -> u64 {
-        (self.start..=self.end).filter(check_invalid_part_a).sum()
+    fn part_a(ranges: &Self::Parsed<'_>) -> u64 {
+        part_a(ranges)
     }
 
-    fn sum_invalid_b(&self) -> u64 {
-        (self.start..=self.end).filter(check_invalid_part_b).sum()
+    fn part_b(ranges: &Self::Parsed<'_>) -> u64 {
+        part_b(ranges)
     }
 }
 
+/// An ID in more than one range counts once for each of them.
+pub fn part_a(ranges: &[RangeInclusive<u64>]) -> u64 {
+    let even_length = even_length_ids();
+    ranges
+        .iter()
+        .map(|range| {
+            // u64::MAX has two different halves, so leaving it out is fine
+            let range = IntervalSet::from(*range.start()..range.end().saturating_add(1));
+            let candidates = range.intersection(&even_length);
+            candidates
+                .iter()
+                .flatten()
+                .filter(check_invalid_part_a)
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+/// The IDs with an even number of digits, the only ones that can be made of
+/// two equal halves.
+fn even_length_ids() -> IntervalSet<u64> {
+    (1..u64::MAX.ilog10())
+        .step_by(2)
+        .map(|digits| 10u64.pow(digits)..10u64.pow(digits + 1))
+        .chain(std::iter::once(10u64.pow(u64::MAX.ilog10())..u64::MAX))
+        .collect()
+}
+
+pub fn part_b(ranges: &[RangeInclusive<u64>]) -> u64 {
+    ranges
+        .iter()
+        .map(|range| range.clone().filter(check_invalid_part_b).sum::<u64>())
+        .sum()
+}
+
 fn check_invalid_part_a(n: &u64) -> bool {
     let s = n.to_string();
     if !s.len().is_multiple_of(2) {
@@ -61,22 +91,19 @@ fn check_invalid_part_b(n: &u64) -> bool {
     false
 }
 
-impl FromStr for Range {
-    type Err = Error;
-    fn from_str(s: &str) -> Result<Self> {
-        let s = s.trim_end();
-        if let Some((start, end)) = s.split_once('-') {
-            Ok(Range::new(start.parse::<u64>()?, end.parse::<u64>()?))
-        } else {
-            Err(anyhow!("Could not parse: {}.", s))
-        }
+fn parse_range(s: &str) -> Result<RangeInclusive<u64>> {
+    let s = s.trim_end();
+    if let Some((start, end)) = s.split_once('-') {
+        Ok(start.parse::<u64>()?..=end.parse::<u64>()?)
+    } else {
+        Err(anyhow!("Could not parse: {}.", s))
     }
 }
 
-fn parse(input_file: &str) -> Vec<Range> {
+fn parse(input_file: &str) -> Vec<RangeInclusive<u64>> {
     input_file
         .split(',')
-        .map(|line| Range::from_str(line).unwrap_or_else(|_| panic!("Could not parse {}.", line)))
+        .map(|line| parse_range(line).unwrap_or_else(|_| panic!("Could not parse {}.", line)))
         .collect()
 }
 
@@ -88,10 +115,10 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case("11-22", Range::new(11, 22))]
-    #[case("2121212118-2121212124", Range::new(2121212118, 2121212124))]
-    fn test_parse_single(#[case] input: &str, #[case] range: Range) {
-        assert_eq!(Range::from_str(input).unwrap(), range);
+    #[case("11-22", 11..=22)]
+    #[case("2121212118-2121212124", 2121212118..=2121212124)]
+    fn test_parse_single(#[case] input: &str, #[case] range: RangeInclusive<u64>) {
+        assert_eq!(parse_range(input).unwrap(), range);
     }
 
     #[rstest]
@@ -99,43 +126,60 @@ mod tests {
         let input = read_test_day_input("02");
         assert_eq!(
             parse(&input),
-            vec![
-                Range::new(11, 22),
-                Range::new(95, 115),
-                Range::new(998, 1012),
-                Range::new(1188511880, 1188511890),
-                Range::new(222220, 222224),
-                Range::new(1698522, 1698528),
-                Range::new(446443, 446449),
-                Range::new(38593856, 38593862),
-                Range::new(565653, 565659),
-                Range::new(824824821, 824824827),
-                Range::new(2121212118, 2121212124),
+            [
+                11..=22,
+                95..=115,
+                998..=1012,
+                1188511880..=1188511890,
+                222220..=222224,
+                1698522..=1698528,
+                446443..=446449,
+                38593856..=38593862,
+                565653..=565659,
+                824824821..=824824827,
+                2121212118..=2121212124,
             ]
         );
     }
 
     #[rstest]
-    #[case(Range::new(11, 22), 11 + 22)]
-    #[case(Range::new(95, 115), 99)]
-    #[case(Range::new(998, 1012), 1010)]
-    #[case(Range::new(1188511880, 1188511890), 1188511885)]
-    #[case(Range::new(222220, 222224), 222222)]
-    #[case(Range::new(1698522, 1698528), 0)]
-    #[case(Range::new(446443, 446449), 446446)]
-    #[case(Range::new(38593856, 38593862), 38593859)]
-    #[case(Range::new(565653, 565659), 0)]
-    #[case(Range::new(824824821, 824824827), 0)]
-    #[case(Range::new(2121212118, 2121212124), 0)]
-    fn test_sum_invalid(#[case] range: Range, #[case] expected: u64) {
-        assert_eq!(range.sum_invalid(), expected);
+    #[case(11..=22, 11 + 22)]
+    #[case(95..=115, 99)]
+    #[case(998..=1012, 1010)]
+    #[case(1188511880..=1188511890, 1188511885)]
+    #[case(222220..=222224, 222222)]
+    #[case(1698522..=1698528, 0)]
+    #[case(446443..=446449, 446446)]
+    #[case(38593856..=38593862, 38593859)]
+    #[case(565653..=565659, 0)]
+    #[case(824824821..=824824827, 0)]
+    #[case(2121212118..=2121212124, 0)]
+    fn test_sum_invalid(#[case] range: RangeInclusive<u64>, #[case] expected: u64) {
+        assert_eq!(part_a(&[range]), expected);
+    }
+
+    #[rstest]
+    #[case(9, false)]
+    #[case(10, true)]
+    #[case(99, true)]
+    #[case(100, false)]
+    #[case(123456, true)]
+    #[case(u64::MAX - 1, true)]
+    fn test_even_length_ids(#[case] id: u64, #[case] expected: bool) {
+        assert_eq!(even_length_ids().contains(id), expected);
+    }
+
+    #[rstest]
+    fn test_overlapping_ranges_count_twice() {
+        assert_eq!(part_a(&[11..=22, 20..=33]), 11 + 22 + 22 + 33);
+        assert_eq!(part_b(&[100..=111, 111..=111]), 111 + 111);
     }
 
     #[rstest]
     fn test_day_02_a() {
         let input_file = read_test_day_input("02");
-        let ranges = parse(&input_file);
-        let result = part_a(&ranges);
+        let ids = parse(&input_file);
+        let result = part_a(&ids);
         assert_eq!(result, 1227775554);
     }
 
@@ -152,8 +196,8 @@ mod tests {
     #[rstest]
     fn test_day_02_b() {
         let input_file = read_test_day_input("02");
-        let ranges = parse(&input_file);
-        let result = part_b(&ranges);
+        let ids = parse(&input_file);
+        let result = part_b(&ids);
         assert_eq!(result, 4174379265);
     }
 }
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use aoc_common::{Day, Solution};
 use std::str::FromStr;
 
 pub fn solve_day(input_file: &str) -> (u64, u64) {
@@ -8,6 +9,28 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(3, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Bank>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part_a(banks: &Self::Parsed<'_>) -> u64 {
+        part_a(banks)
+    }
+
+    fn part_b(banks: &Self::Parsed<'_>) -> u64 {
+        part_b(banks)
+    }
+}
+
 pub fn part_a(banks: &[Bank]) -> u64 {
     banks.iter().map(|bank| bank.max_joltage(2)).sum()
 }
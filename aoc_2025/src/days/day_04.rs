@@ -1,4 +1,4 @@
-use anyhow::Result;
+use aoc_common::{Day, Grid, ParseError, Pos, Solution};
 use std::str::FromStr;
 
 pub fn solve_day(input_file: &str) -> (u64, u64) {
@@ -8,34 +8,48 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
-pub fn part_a(map: &Map) -> u64 {
-    let mut count = 0;
-    for y in 0..map.is_paper.len() {
-        for x in 0..map.is_paper[y].len() {
-            if map.check_paper_reachable(x, y) {
-                count += 1;
-            }
-        }
+pub const DAY: Day = Day::new(4, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Map;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part_a(map: &Self::Parsed<'_>) -> u64 {
+        part_a(map)
+    }
+
+    fn part_b(map: &Self::Parsed<'_>) -> u64 {
+        part_b(&mut map.clone())
     }
-    count
+}
+
+pub fn part_a(map: &Map) -> u64 {
+    map.is_paper
+        .positions()
+        .filter(|&pos| map.check_paper_reachable(pos))
+        .count() as u64
 }
 
 pub fn part_b(map: &mut Map) -> u64 {
     let mut count = 0;
     let mut any_removed = true;
     while any_removed {
-        let mut to_be_removed = vec![];
-        for y in 0..map.is_paper.len() {
-            for x in 0..map.is_paper[y].len() {
-                if map.check_paper_reachable(x, y) {
-                    count += 1;
-                    to_be_removed.push((x, y));
-                }
-            }
-        }
+        let to_be_removed: Vec<Pos> = map
+            .is_paper
+            .positions()
+            .filter(|&pos| map.check_paper_reachable(pos))
+            .collect();
+        count += to_be_removed.len() as u64;
         any_removed = !to_be_removed.is_empty();
-        for (x, y) in to_be_removed {
-            map.remove(x, y);
+        for pos in to_be_removed {
+            map.is_paper[pos] = false;
         }
     }
     count
@@ -45,76 +59,33 @@ fn parse(input_file: &str) -> Map {
     Map::from_str(input_file).unwrap()
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Map {
-    is_paper: Vec<Vec<bool>>,
+    is_paper: Grid<bool>,
 }
 
 impl Map {
-    fn check_paper_reachable(&self, x: usize, y: usize) -> bool {
-        let mut count = 0;
-        let tile = self.get(x, y);
-        if tile.is_none() {
-            return false;
-        }
-        if let Some(false) = tile {
-            return false;
-        }
-        for x_offset in -1..=1 {
-            for y_offset in -1..=1 {
-                let check_x = x.checked_add_signed(x_offset);
-                let check_y = y.checked_add_signed(y_offset);
-                if check_x.is_none() || check_y.is_none() {
-                    continue;
-                }
-                let check_x = check_x.unwrap();
-                let check_y = check_y.unwrap();
-                let tile = self.get(check_x, check_y);
-                if tile.is_none() {
-                    continue;
-                }
-                if tile.unwrap() {
-                    count += 1;
-                }
-                if count > 4 {
-                    return false;
-                }
-            }
-        }
-        true
-    }
-
-    fn get(&self, x: usize, y: usize) -> Option<bool> {
-        if y >= self.is_paper.len() || x >= self.is_paper[y].len() {
-            return None;
-        }
-        Some(self.is_paper[y][x])
-    }
-
-    fn remove(&mut self, x: usize, y: usize) {
-        if y >= self.is_paper.len() || x >= self.is_paper[y].len() {
-            return;
-        }
-        self.is_paper[y][x] = false;
+    /// Paper with fewer than four rolls of paper around it.
+    fn check_paper_reachable(&self, pos: Pos) -> bool {
+        self.is_paper.get(pos) == Some(&true)
+            && self
+                .is_paper
+                .neighbours8(pos)
+                .filter(|&neighbour| self.is_paper[neighbour])
+                .count()
+                < 4
     }
 }
 
 impl FromStr for Map {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let is_paper = s
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| match c {
-                        '@' => Ok(true),
-                        '.' => Ok(false),
-                        _ => Err(()),
-                    })
-                    .collect::<Result<Vec<bool>, ()>>()
-            })
-            .collect::<Result<Vec<Vec<bool>>, ()>>()?;
+        let is_paper = Grid::parse_with(s, |c| match c {
+            '@' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::unexpected_char(c)),
+        })?;
         Ok(Map { is_paper })
     }
 }
@@ -133,7 +104,7 @@ mod tests {
         assert_eq!(
             map,
             Map {
-                is_paper: vec![
+                is_paper: Grid::from_rows(vec![
                     vec![
                         false, false, true, true, false, true, true, true, true, false
                     ],
@@ -154,7 +125,8 @@ mod tests {
                     vec![
                         true, false, true, false, true, true, true, false, true, false
                     ],
-                ]
+                ])
+                .unwrap()
             }
         )
     }
@@ -171,7 +143,7 @@ mod tests {
     fn test_check_paper_reachable(#[case] x: usize, #[case] y: usize, #[case] expected: bool) {
         let input_file = read_test_day_input("04");
         let map = parse(&input_file);
-        assert_eq!(map.check_paper_reachable(x, y), expected);
+        assert_eq!(map.check_paper_reachable((x, y)), expected);
     }
 
     #[rstest]
@@ -1,52 +1,55 @@
 use anyhow::Result;
-use std::str::FromStr;
+use aoc_common::{Day, IntervalSet, Solution};
+use std::ops::RangeInclusive;
 
 pub fn solve_day(input_file: &str) -> (u64, u64) {
     let (ranges, ids) = parse(input_file);
     let a = part_a(&ranges, &ids);
-    let b = part_b(ranges);
+    let b = part_b(&ranges);
     (a, b)
 }
 
-pub fn part_a(ranges: &[Range], ids: &[u64]) -> u64 {
-    let mut count = 0;
-    for id in ids {
-        for range in ranges {
-            if range.contains(*id) {
-                count += 1;
-                break;
-            }
-        }
+pub const DAY: Day = Day::new(5, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = (Vec<RangeInclusive<u64>>, Vec<u64>);
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part_a((ranges, ids): &Self::Parsed<'_>) -> u64 {
+        part_a(ranges, ids)
+    }
+
+    fn part_b((ranges, _): &Self::Parsed<'_>) -> u64 {
+        part_b(ranges)
     }
-    count
 }
 
-pub fn part_b(mut ranges: Vec<Range>) -> u64 {
-    ranges = merge_ranges(ranges);
-    ranges.iter().map(|r| r.len()).sum()
+pub fn part_a(ranges: &[RangeInclusive<u64>], ids: &[u64]) -> u64 {
+    let fresh = fresh_ids(ranges);
+    ids.iter().filter(|&&id| fresh.contains(id)).count() as u64
 }
 
-fn merge_ranges(mut ranges: Vec<Range>) -> Vec<Range> {
-    ranges.sort();
-    for i in 0..ranges.len() {
-        let mut offset = 0;
-        for j in i + 1..ranges.len() {
-            if let Some(merged) = ranges[i].merge(&ranges[j - offset]) {
-                ranges[i] = merged;
-                ranges.remove(j - offset);
-                offset += 1;
-            }
-        }
-    }
-    ranges
+pub fn part_b(ranges: &[RangeInclusive<u64>]) -> u64 {
+    fresh_ids(ranges).len()
+}
+
+fn fresh_ids(ranges: &[RangeInclusive<u64>]) -> IntervalSet<u64> {
+    ranges.iter().cloned().collect()
 }
 
-fn parse(input_file: &str) -> (Vec<Range>, Vec<u64>) {
+fn parse(input_file: &str) -> (Vec<RangeInclusive<u64>>, Vec<u64>) {
     if let Some((ranges, ids)) = input_file.split_once("\n\n") {
         let ranges = ranges
             .trim()
             .split('\n')
-            .map(Range::from_str)
+            .map(parse_range)
             .collect::<Result<Vec<_>, _>>()
             .unwrap();
         let ids = ids
@@ -60,45 +63,14 @@ fn parse(input_file: &str) -> (Vec<Range>, Vec<u64>) {
     panic!("Invalid input format");
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Range {
-    start: u64,
-    end: u64,
-}
-
-impl Range {
-    fn contains(&self, value: u64) -> bool {
-        value >= self.start && value <= self.end
-    }
-
-    fn len(&self) -> u64 {
-        self.end - self.start + 1
-    }
-
-    fn merge(&self, other: &Range) -> Option<Range> {
-        if self.end < other.start || other.end < self.start {
-            None
-        } else {
-            Some(Range {
-                start: self.start.min(other.start),
-                end: self.end.max(other.end),
-            })
-        }
-    }
-}
-
-impl FromStr for Range {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start, end) = s
-            .split_once('-')
-            .ok_or_else(|| anyhow::anyhow!("Invalid range format"))?;
-        Ok(Range {
-            start: start.parse()?,
-            end: end.parse()?,
-        })
-    }
+fn parse_range(s: &str) -> Result<RangeInclusive<u64>> {
+    let (start, end) = s
+        .split_once('-')
+        .ok_or_else(|| anyhow::anyhow!("Invalid range format"))?;
+    let end: u64 = end.parse()?;
+    // Fresh IDs are kept as half-open ranges, which can't reach past the end.
+    anyhow::ensure!(end < u64::MAX, "Range end {end} is too large");
+    Ok(start.parse()?..=end)
 }
 
 #[cfg(test)]
@@ -106,24 +78,26 @@ mod tests {
     use crate::days::read_test_day_input;
 
     use super::*;
+    use proptest::prelude::*;
     use rstest::rstest;
+    use std::collections::BTreeSet;
 
     #[rstest]
     fn test_parse() {
         let input_file = read_test_day_input("05");
         let (ranges, ids) = parse(&input_file);
-        assert_eq!(
-            ranges,
-            vec![
-                Range { start: 3, end: 5 },
-                Range { start: 10, end: 14 },
-                Range { start: 16, end: 20 },
-                Range { start: 12, end: 18 },
-            ]
-        );
+        assert_eq!(ranges, vec![3..=5, 10..=14, 16..=20, 12..=18]);
         assert_eq!(ids, vec![1, 5, 8, 11, 17, 32,]);
     }
 
+    #[rstest]
+    #[case("0-18446744073709551614", true)]
+    #[case("0-18446744073709551615", false)]
+    #[case("3", false)]
+    fn test_parse_range(#[case] input: &str, #[case] valid: bool) {
+        assert_eq!(parse_range(input).is_ok(), valid);
+    }
+
     #[rstest]
     fn test_part_a() {
         let input_file = read_test_day_input("05");
@@ -135,6 +109,24 @@ mod tests {
     fn test_part_b() {
         let input_file = read_test_day_input("05");
         let (ranges, _) = parse(&input_file);
-        assert_eq!(part_b(ranges), 14);
+        assert_eq!(part_b(&ranges), 14);
+    }
+
+    proptest! {
+        #[test]
+        fn test_fresh_ids_same_coverage(
+            bounds in prop::collection::vec((0u64..200, 0u64..30), 0..20)
+        ) {
+            let ranges: Vec<RangeInclusive<u64>> = bounds
+                .iter()
+                .map(|&(start, len)| start..=start + len)
+                .collect();
+            let covered: BTreeSet<u64> = ranges.iter().flat_map(|range| range.clone()).collect();
+
+            let fresh = fresh_ids(&ranges);
+            prop_assert_eq!(fresh.len(), covered.len() as u64);
+            prop_assert!(covered.iter().all(|&id| fresh.contains(id)));
+            prop_assert_eq!(fresh.iter().flatten().collect::<BTreeSet<_>>(), covered);
+        }
     }
 }
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::str::FromStr;
 
 pub fn solve_day(input_file: &str) -> (u64, u64) {
@@ -7,6 +8,28 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(6, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = (Vec<Vec<CephalapodMath>>, &'a str);
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        (parse(input), input)
+    }
+
+    fn part_a((ops, _): &Self::Parsed<'_>) -> u64 {
+        part_a(ops)
+    }
+
+    fn part_b((_, input): &Self::Parsed<'_>) -> u64 {
+        part_b(input)
+    }
+}
+
 pub fn part_a(ops: &[Vec<CephalapodMath>]) -> u64 {
     let mut sum = 0;
     for column in ops {
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution};
 use std::fmt::Display;
 
 pub fn solve_day(input_file: &str) -> (u64, u64) {
@@ -7,6 +8,28 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(7, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Vec<Tile>>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part_a(map: &Self::Parsed<'_>) -> u64 {
+        part_a(map.clone()).0
+    }
+
+    fn part_b(map: &Self::Parsed<'_>) -> u64 {
+        part_a(map.clone()).1
+    }
+}
+
 pub fn part_a(map: Vec<Vec<Tile>>) -> (u64, u64) {
     let mut new_map = Vec::with_capacity(map.len());
     let mut split_count = 0;
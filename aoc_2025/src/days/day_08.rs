@@ -1,4 +1,5 @@
 use anyhow::{Result, anyhow};
+use aoc_common::{Day, Solution};
 use std::{
     collections::{BinaryHeap, HashSet},
     str::FromStr,
@@ -12,6 +13,28 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(8, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Box>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part_a(boxes: &Self::Parsed<'_>) -> u64 {
+        part_a(boxes, 1_000)
+    }
+
+    fn part_b(boxes: &Self::Parsed<'_>) -> u64 {
+        part_b(boxes)
+    }
+}
+
 fn parse(input_file: &str) -> Vec<Box> {
     input_file
         .trim()
@@ -1,5 +1,5 @@
-use anyhow::{Result, anyhow};
-use std::{collections::BinaryHeap, str::FromStr};
+use aoc_common::{Coord, Day, Solution};
+use std::collections::BinaryHeap;
 
 pub fn solve_day(input_file: &str) -> (u64, u64) {
     let coords = parse(input_file);
@@ -8,19 +8,41 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
-fn part_a(coords: &[Coord]) -> u64 {
+pub const DAY: Day = Day::new(9, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Coord<u64>>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part_a(coords: &Self::Parsed<'_>) -> u64 {
+        part_a(coords)
+    }
+
+    fn part_b(coords: &Self::Parsed<'_>) -> u64 {
+        part_b(coords)
+    }
+}
+
+fn part_a(coords: &[Coord<u64>]) -> u64 {
     coords
         .iter()
-        .flat_map(|c1| coords.iter().map(move |c2| c1.square(c2)))
+        .flat_map(|c1| coords.iter().map(move |c2| square(c1, c2)))
         .max()
         .unwrap_or(0)
 }
 
-fn part_b(coords: &[Coord]) -> u64 {
+fn part_b(coords: &[Coord<u64>]) -> u64 {
     let mut squares = BinaryHeap::with_capacity(coords.len() * (coords.len() - 1) / 2);
     for i in 0..coords.len() {
         for j in (i + 1)..coords.len() {
-            squares.push((coords[i].square(&coords[j]), coords[i], coords[j]));
+            squares.push((square(&coords[i], &coords[j]), coords[i], coords[j]));
         }
     }
 
@@ -36,17 +58,22 @@ fn part_b(coords: &[Coord]) -> u64 {
     panic!("No valid square found");
 }
 
-fn check_valid_square(c1: &Coord, c2: &Coord, borders: &[Line], polygon: &[Coord]) -> bool {
+fn check_valid_square(
+    c1: &Coord<u64>,
+    c2: &Coord<u64>,
+    borders: &[Line],
+    polygon: &[Coord<u64>],
+) -> bool {
     let min_x = c1.x.min(c2.x);
     let max_x = c1.x.max(c2.x);
     let min_y = c1.y.min(c2.y);
     let max_y = c1.y.max(c2.y);
 
     let corners = [
-        Coord::from((min_x, min_y)),
-        Coord::from((max_x, min_y)),
-        Coord::from((max_x, max_y)),
-        Coord::from((min_x, max_y)),
+        Coord::new(min_x, min_y),
+        Coord::new(max_x, min_y),
+        Coord::new(max_x, max_y),
+        Coord::new(min_x, max_y),
     ];
 
     // Check if all corners are inside the polygon
@@ -68,7 +95,7 @@ fn check_valid_square(c1: &Coord, c2: &Coord, borders: &[Line], polygon: &[Coord
     })
 }
 
-fn is_point_inside_polygon(point: &Coord, polygon: &[Coord]) -> bool {
+fn is_point_inside_polygon(point: &Coord<u64>, polygon: &[Coord<u64>]) -> bool {
     // First check if point is on a polygon edge
     for i in 0..polygon.len() {
         let j = (i + 1) % polygon.len();
@@ -100,7 +127,7 @@ fn is_point_inside_polygon(point: &Coord, polygon: &[Coord]) -> bool {
     inside
 }
 
-fn is_point_on_segment(point: &Coord, p1: &Coord, p2: &Coord) -> bool {
+fn is_point_on_segment(point: &Coord<u64>, p1: &Coord<u64>, p2: &Coord<u64>) -> bool {
     // Check if it's on a horizontal or vertical line within bounds
     if p1.x == p2.x {
         point.x == p1.x && point.y >= p1.y.min(p2.y) && point.y <= p1.y.max(p2.y)
@@ -111,28 +138,22 @@ fn is_point_on_segment(point: &Coord, p1: &Coord, p2: &Coord) -> bool {
     }
 }
 
-fn parse(input_file: &str) -> Vec<Coord> {
+fn parse(input_file: &str) -> Vec<Coord<u64>> {
     input_file
         .trim()
         .lines()
-        .filter_map(|line| Coord::from_str(line).ok())
+        .filter_map(|line| line.parse().ok())
         .collect()
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
-struct Coord {
-    x: u64,
-    y: u64,
-}
-
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 struct Line {
-    start: Coord,
-    end: Coord,
+    start: Coord<u64>,
+    end: Coord<u64>,
 }
 
 impl Line {
-    fn new(start: Coord, end: Coord) -> Self {
+    fn new(start: Coord<u64>, end: Coord<u64>) -> Self {
         let line = Line {
             start: start.min(end),
             end: start.max(end),
@@ -186,31 +207,9 @@ impl Line {
     }
 }
 
-impl Coord {
-    fn square(&self, other: &Coord) -> u64 {
-        (self.x.abs_diff(other.x) + 1) * (self.y.abs_diff(other.y) + 1)
-    }
-}
-
-impl FromStr for Coord {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self> {
-        let (x, y) = s
-            .trim()
-            .split_once(',')
-            .ok_or_else(|| anyhow!("Invalid coord format"))?;
-        Ok(Coord {
-            x: x.parse()?,
-            y: y.parse()?,
-        })
-    }
-}
-
-impl From<(u64, u64)> for Coord {
-    fn from((x, y): (u64, u64)) -> Self {
-        Coord { x, y }
-    }
+/// The area of the rectangle with opposite corners on `c1` and `c2`.
+fn square(c1: &Coord<u64>, c2: &Coord<u64>) -> u64 {
+    (c1.x.abs_diff(c2.x) + 1) * (c1.y.abs_diff(c2.y) + 1)
 }
 
 #[cfg(test)]
@@ -222,16 +221,15 @@ mod tests {
     #[rstest]
     #[case("0,0", Coord { x: 0, y: 0 })]
     #[case("1,1", Coord { x: 1, y: 1 })]
-    fn test_parse(#[case] input: &str, #[case] expected: Coord) {
-        let result = Coord::from_str(input).unwrap();
+    fn test_parse(#[case] input: &str, #[case] expected: Coord<u64>) {
+        let result = input.parse::<Coord<u64>>().unwrap();
         assert_eq!(result, expected);
     }
 
     #[rstest]
     #[case("7,1", "11,7", 35)]
-    fn test_square(#[case] c1: Coord, #[case] c2: Coord, #[case] expected_square: u64) {
-        let square = c1.square(&c2);
-        assert_eq!(square, expected_square);
+    fn test_square(#[case] c1: Coord<u64>, #[case] c2: Coord<u64>, #[case] expected_square: u64) {
+        assert_eq!(square(&c1, &c2), expected_square);
     }
 
     #[rstest]
@@ -255,7 +253,11 @@ mod tests {
     #[case("9,7", "9,5", true)]
     #[case("9,5", "2,3", true)]
     #[case("2,5", "11,1", false)]
-    fn test_check_valid_square(#[case] c1: Coord, #[case] c2: Coord, #[case] expected: bool) {
+    fn test_check_valid_square(
+        #[case] c1: Coord<u64>,
+        #[case] c2: Coord<u64>,
+        #[case] expected: bool,
+    ) {
         let input_file = &read_test_day_input("09");
         let coords = parse(input_file);
         let borders: Vec<_> = (0..coords.len())
@@ -1,3 +1,4 @@
+use aoc_common::{Day, Solution, bfs};
 use good_lp::{Expression, SolverModel, microlp, variable};
 use std::{str::FromStr, vec};
 
@@ -8,6 +9,28 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(10, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Machine>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part_a(machines: &Self::Parsed<'_>) -> u64 {
+        part_a(machines)
+    }
+
+    fn part_b(machines: &Self::Parsed<'_>) -> u64 {
+        part_b(machines)
+    }
+}
+
 fn part_a(machines: &[Machine]) -> u64 {
     machines.iter().map(|m| m.startup()).sum()
 }
@@ -126,21 +149,57 @@ impl Machine {
     }
 
     fn _startup_bfs(target_state: &[bool], buttons: &[Vec<usize>]) -> u64 {
-        let state = vec![false; target_state.len()];
-        let mut queue: std::collections::VecDeque<(u64, Vec<bool>)> =
-            std::collections::VecDeque::from([(0, state.to_vec())]);
-        let mut visited = std::collections::HashSet::new();
-        visited.insert(state.to_vec());
+        let search = bfs(
+            [vec![false; target_state.len()]],
+            |state| {
+                buttons
+                    .iter()
+                    .map(|button| Self::_apply_button(state, button))
+                    .collect::<Vec<_>>()
+            },
+            |state| state == target_state,
+        );
+        search.goal_cost().map_or(u64::MAX, |steps| steps as u64)
+    }
+
+    /// Oracle for [`Machine::startup`]: pressing a button twice undoes it,
+    /// so try every set of buttons pressed once.
+    #[cfg(test)]
+    fn _startup_exhaustive(target_state: &[bool], buttons: &[Vec<usize>]) -> u64 {
+        (0..1u32 << buttons.len())
+            .filter(|pressed| {
+                let state = (0..buttons.len())
+                    .filter(|i| pressed & (1 << i) != 0)
+                    .fold(vec![false; target_state.len()], |state, i| {
+                        Self::_apply_button(&state, &buttons[i])
+                    });
+                state == target_state
+            })
+            .map(|pressed| pressed.count_ones() as u64)
+            .min()
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Oracle for [`Machine::joltage_rating`], only feasible for low joltages.
+    #[cfg(test)]
+    fn _joltage_rating_bfs(target: &[u16], buttons: &[Vec<usize>]) -> u64 {
+        let state = vec![0; target.len()];
+        let mut queue = std::collections::VecDeque::from([(0, state.clone())]);
+        let mut visited = std::collections::HashSet::from([state]);
 
         while let Some((steps, current_state)) = queue.pop_front() {
-            if current_state == target_state {
+            if current_state == target {
                 return steps;
             }
 
             for button in buttons {
-                let new_state = Self::_apply_button(&current_state, button);
-                if !visited.contains(&new_state) {
-                    visited.insert(new_state.to_vec());
+                let mut new_state = current_state.clone();
+                for &idx in button {
+                    new_state[idx] += 1;
+                }
+                if new_state.iter().zip(target).all(|(j, t)| j <= t)
+                    && visited.insert(new_state.clone())
+                {
                     queue.push_back((steps + 1, new_state));
                 }
             }
@@ -163,6 +222,7 @@ mod tests {
     use crate::days::read_test_day_input;
 
     use super::*;
+    use proptest::prelude::*;
     use rstest::rstest;
 
     #[rstest]
@@ -241,4 +301,45 @@ mod tests {
         let result = part_b(&machines);
         assert_eq!(result, 33);
     }
+
+    /// Small machines whose joltages can always be reached, by building them
+    /// from random presses of the buttons.
+    fn machine_strategy() -> impl Strategy<Value = Machine> {
+        (1..=4usize)
+            .prop_flat_map(|n| {
+                let button = prop::sample::subsequence((0..n).collect::<Vec<_>>(), 1..=n);
+                (
+                    prop::collection::vec(any::<bool>(), n),
+                    prop::collection::vec((button, 0..=4u16), 1..=5),
+                )
+            })
+            .prop_map(|(lights, presses)| {
+                let mut joltage = vec![0; lights.len()];
+                for (button, n) in &presses {
+                    for &idx in button {
+                        joltage[idx] += n;
+                    }
+                }
+                let buttons = presses.into_iter().map(|(button, _)| button).collect();
+                Machine::new(lights, buttons, joltage)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_startup_bfs_matches_exhaustive(machine in machine_strategy()) {
+            prop_assert_eq!(
+                Machine::_startup_bfs(&machine.lights, &machine.buttons),
+                Machine::_startup_exhaustive(&machine.lights, &machine.buttons)
+            );
+        }
+
+        #[test]
+        fn test_joltage_rating_matches_bfs(machine in machine_strategy()) {
+            prop_assert_eq!(
+                machine.joltage_rating(),
+                Machine::_joltage_rating_bfs(&machine.joltage, &machine.buttons)
+            );
+        }
+    }
 }
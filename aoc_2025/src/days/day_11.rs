@@ -1,4 +1,5 @@
-use std::{collections::HashMap, hash::Hash};
+use aoc_common::{Day, Solution};
+use std::collections::HashMap;
 
 pub fn solve_day(input_file: &str) -> (u64, u64) {
     let nodes = parse(input_file);
@@ -7,6 +8,28 @@ pub fn solve_day(input_file: &str) -> (u64, u64) {
     (a, b)
 }
 
+pub const DAY: Day = Day::new(11, &Solver);
+
+struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = HashMap<[char; 3], Vec<[char; 3]>>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part_a(nodes: &Self::Parsed<'_>) -> u64 {
+        part_a(nodes)
+    }
+
+    fn part_b(nodes: &Self::Parsed<'_>) -> u64 {
+        part_b(nodes)
+    }
+}
+
 fn part_a(nodes: &HashMap<[char; 3], Vec<[char; 3]>>) -> u64 {
     let mut memo = HashMap::new();
     count_paths_recursive(nodes, ['y', 'o', 'u'], ['o', 'u', 't'], &mut memo)
@@ -1,4 +1,5 @@
-use std::fs;
+use aoc_common::{DayOutcome, RunConfig, Year};
+pub use aoc_common::{read_day_input, read_day_input_if_present, read_test_day_input};
 
 pub mod day_01;
 pub mod day_02;
@@ -12,38 +13,29 @@ pub mod day_09;
 pub mod day_10;
 pub mod day_11;
 
-pub fn solve_days() {
-    let start = std::time::Instant::now();
-    println!("Solving Advent of Code 2025:");
-    timeit_day(1, || day_01::solve_day(&read_day_input("01")));
-    timeit_day(2, || day_02::solve_day(&read_day_input("02")));
-    timeit_day(3, || day_03::solve_day(&read_day_input("03")));
-    timeit_day(4, || day_04::solve_day(&read_day_input("04")));
-    timeit_day(5, || day_05::solve_day(&read_day_input("05")));
-    timeit_day(6, || day_06::solve_day(&read_day_input("06")));
-    timeit_day(7, || day_07::solve_day(&read_day_input("07")));
-    timeit_day(8, || day_08::solve_day(&read_day_input("08")));
-    timeit_day(9, || day_09::solve_day(&read_day_input("09")));
-    timeit_day(10, || day_10::solve_day(&read_day_input("10")));
-    timeit_day(11, || day_11::solve_day(&read_day_input("11")));
-    let duration = start.elapsed();
-    println!("Total time: {:.2?}", duration);
+aoc_common::days! {
+    day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11,
 }
 
-fn timeit_day<F: FnOnce() -> R, R: std::fmt::Debug>(i: u8, f: F) {
-    let start = std::time::Instant::now();
-    let result = f();
-    let duration = start.elapsed();
-    let result_string = format!("Day {i}: {result:?}");
-    println!("{result_string:60}  (took: {:.2?})", duration);
-}
+pub static YEAR: Year = Year {
+    year: 2025,
+    days: DAYS,
+    input_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    baseline: concat!(env!("CARGO_MANIFEST_DIR"), "/benches/baseline.toml"),
+};
 
-pub fn read_day_input(day: &str) -> String {
-    let file_path = format!("inputs/{day}.txt");
-    fs::read_to_string(&file_path).unwrap_or_else(|_| panic!("Could not load: {file_path}"))
+pub fn solve_days() -> Vec<DayOutcome> {
+    aoc_common::run_year(&YEAR, &RunConfig::from_env())
 }
 
-pub fn read_test_day_input(day: &str) -> String {
-    let file_path = format!("test_inputs/test_{day}.txt");
-    fs::read_to_string(&file_path).unwrap_or_else(|_| panic!("Could not load: {file_path}"))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_in_day_order() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.day as usize, i + 1);
+        }
+    }
 }
@@ -0,0 +1,54 @@
+//! Random inputs for stress testing days beyond their official input.
+
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+pub static GENERATORS: &[Generator] = &[Generator {
+    day: 8,
+    generate: day_08,
+    unit: "boxes",
+    sizes: &[1000, 1500, 2000, 3000, 4000],
+}];
+
+/// `size` junction boxes at distinct positions in the same space as the
+/// official input. Part A makes 1000 connections, so it needs at least about
+/// 1000 boxes to still have three circuits left.
+pub fn day_08(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut boxes = HashSet::with_capacity(size);
+    let mut input = String::new();
+    while boxes.len() < size {
+        let position: (u32, u32, u32) = (
+            rng.gen_range(0..100_000),
+            rng.gen_range(0..100_000),
+            rng.gen_range(0..100_000),
+        );
+        if boxes.insert(position) {
+            let (x, y, z) = position;
+            input += &format!("{x},{y},{z}\n");
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::YEAR;
+
+    #[test]
+    fn test_generated_day_08_is_solvable() {
+        let input = day_08(1000, 1);
+        assert_eq!(input.lines().count(), 1000);
+        YEAR.day(8).unwrap().solution.solve(&input);
+    }
+
+    #[test]
+    fn test_generators_are_deterministic() {
+        for generator in GENERATORS {
+            assert_eq!(generator.generate(20, 7), generator.generate(20, 7));
+        }
+    }
+}
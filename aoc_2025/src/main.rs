@@ -1,5 +1,5 @@
 use aoc_2025::days::solve_days;
 
 fn main() {
-    solve_days()
+    solve_days();
 }
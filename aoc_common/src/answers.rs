@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::Part;
+
+/// The accepted answers of a year, read from an `answers.toml` next to the
+/// inputs it belongs to:
+///
+/// ```toml
+/// [1]
+/// a = 66719
+/// b = "198551"
+/// ```
+///
+/// Answers may be given as integers or strings, and either part may be left
+/// out until it is known.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<u8, DayAnswers>);
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+struct DayAnswers {
+    a: Option<Answer>,
+    b: Option<Answer>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Integer(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Unreadable(PathBuf, io::Error),
+    Invalid(PathBuf, String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Unreadable(path, err) => {
+                write!(f, "could not read answers {}: {err}", path.display())
+            }
+            AnswersError::Invalid(path, err) => {
+                write!(f, "invalid answers {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AnswersError::Unreadable(_, err) => Some(err),
+            AnswersError::Invalid(..) => None,
+        }
+    }
+}
+
+impl Answers {
+    /// Loads the answers at `path`. A missing file just means no answers are
+    /// known yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AnswersError> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|err| AnswersError::Invalid(path.to_path_buf(), err))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AnswersError::Unreadable(path.to_path_buf(), err)),
+        }
+    }
+
+    fn parse(contents: &str) -> Result<Self, String> {
+        let days: BTreeMap<String, DayAnswers> =
+            toml::from_str(contents).map_err(|err| err.to_string())?;
+        days.into_iter()
+            .map(|(day, answers)| match day.parse() {
+                Ok(day) => Ok((day, answers)),
+                Err(_) => Err(format!("`{day}` is not a day")),
+            })
+            .collect::<Result<_, _>>()
+            .map(Answers)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<String> {
+        let answers = self.0.get(&day)?;
+        let answer = match part {
+            Part::A => &answers.a,
+            Part::B => &answers.b,
+        };
+        answer.as_ref().map(Answer::to_string)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<String>, actual: &str) -> Self {
+        match expected {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("[1]\na = 66719\nb = \"198551\"\n\n[02]\nb = 12\n").unwrap();
+        assert_eq!(answers.get(1, Part::A), Some("66719".into()));
+        assert_eq!(answers.get(1, Part::B), Some("198551".into()));
+        assert_eq!(answers.get(2, Part::A), None);
+        assert_eq!(answers.get(2, Part::B), Some("12".into()));
+        assert_eq!(answers.get(3, Part::A), None);
+
+        assert!(Answers::parse("[first]\na = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(Verdict::check(Some("12".into()), "12"), Verdict::Pass);
+        assert_eq!(
+            Verdict::check(Some("12".into()), "13"),
+            Verdict::Fail {
+                expected: "12".into()
+            }
+        );
+        assert_eq!(Verdict::check(None, "13"), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Year, STEPS};
+
+/// The criterion group of a day, which is also the folder criterion keeps
+/// its measurements in.
+pub fn group_name(year: u16, day: u8) -> String {
+    format!("{year}_day_{day:02}")
+}
+
+/// Median times in nanoseconds per day and step, as kept in a year's
+/// `benches/baseline.toml`:
+///
+/// ```toml
+/// [01]
+/// parse = 2.2
+/// part_a = 66969.3
+/// part_b = 71578.0
+/// ```
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline(BTreeMap<String, BTreeMap<String, f64>>);
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Unreadable(PathBuf, io::Error),
+    Invalid(PathBuf, String),
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaselineError::Unreadable(path, err) => {
+                write!(f, "could not access {}: {err}", path.display())
+            }
+            BaselineError::Invalid(path, err) => write!(f, "invalid {}: {err}", path.display()),
+        }
+    }
+}
+
+impl Error for BaselineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BaselineError::Unreadable(_, err) => Some(err),
+            BaselineError::Invalid(..) => None,
+        }
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("{day:02}")
+}
+
+impl Baseline {
+    /// Collects the latest criterion medians of every day and step of `year`
+    /// found in `criterion_dir`. Days that were not benched are left out.
+    pub fn from_criterion(year: &Year, criterion_dir: &Path) -> Result<Self, BaselineError> {
+        let mut baseline = Baseline::default();
+        for day in year.days {
+            for step in STEPS {
+                let estimates = criterion_dir
+                    .join(group_name(year.year, day.day))
+                    .join(step)
+                    .join("new/estimates.json");
+                if let Some(median) = read_median(&estimates)? {
+                    baseline.insert(day.day, step, median);
+                }
+            }
+        }
+        Ok(baseline)
+    }
+
+    /// Loads the baseline at `path`, which is empty if nothing was recorded
+    /// yet.
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| BaselineError::Invalid(path.to_path_buf(), err.to_string())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Baseline::default()),
+            Err(err) => Err(BaselineError::Unreadable(path.to_path_buf(), err)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        let contents = toml::to_string(self)
+            .map_err(|err| BaselineError::Invalid(path.to_path_buf(), err.to_string()))?;
+        fs::write(path, contents).map_err(|err| BaselineError::Unreadable(path.to_path_buf(), err))
+    }
+
+    pub fn insert(&mut self, day: u8, step: &str, nanos: f64) {
+        self.0
+            .entry(day_key(day))
+            .or_default()
+            .insert(step.to_string(), nanos);
+    }
+
+    pub fn get(&self, day: u8, step: &str) -> Option<f64> {
+        self.0.get(&day_key(day))?.get(step).copied()
+    }
+
+    /// Takes over every measurement of `other`, keeping the days and steps
+    /// it has no measurement for.
+    pub fn update(&mut self, other: Baseline) {
+        for (day, steps) in other.0 {
+            self.0.entry(day).or_default().extend(steps);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Compares every day and step measured in `current` against this
+    /// baseline. A step regressed when it got slower by more than
+    /// `threshold` percent.
+    pub fn compare(&self, current: &Baseline, threshold: f64) -> Vec<Comparison> {
+        current
+            .0
+            .iter()
+            .flat_map(|(day, steps)| steps.iter().map(move |(step, nanos)| (day, step, *nanos)))
+            .map(|(day, step, current_nanos)| {
+                let baseline = self.0.get(day).and_then(|steps| steps.get(step)).copied();
+                let change = baseline.map(|baseline| (current_nanos / baseline - 1.0) * 100.0);
+                Comparison {
+                    day: day.parse().expect("Baseline keys are day numbers"),
+                    step: step.clone(),
+                    baseline,
+                    current: current_nanos,
+                    change,
+                    regressed: change.is_some_and(|change| change > threshold),
+                }
+            })
+            .collect()
+    }
+}
+
+fn read_median(estimates: &Path) -> Result<Option<f64>, BaselineError> {
+    #[derive(Deserialize)]
+    struct Estimates {
+        median: Estimate,
+    }
+
+    #[derive(Deserialize)]
+    struct Estimate {
+        point_estimate: f64,
+    }
+
+    let contents = match fs::read_to_string(estimates) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(BaselineError::Unreadable(estimates.to_path_buf(), err)),
+    };
+    let estimates: Estimates = serde_json::from_str(&contents)
+        .map_err(|err| BaselineError::Invalid(estimates.to_path_buf(), err.to_string()))?;
+    Ok(Some(estimates.median.point_estimate))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub step: String,
+    /// Median in nanoseconds, if the step is in the baseline.
+    pub baseline: Option<f64>,
+    pub current: f64,
+    /// Change relative to the baseline in percent.
+    pub change: Option<f64>,
+    pub regressed: bool,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = |nanos: f64| format!("{:.2?}", std::time::Duration::from_secs_f64(nanos / 1e9));
+        let step = format!("Day {} {}:", self.day, self.step);
+        match (self.baseline, self.change) {
+            (Some(baseline), Some(change)) => write!(
+                f,
+                "{step:20}{:>12} -> {:>12} ({change:+.1}%){}",
+                nanos(baseline),
+                nanos(self.current),
+                if self.regressed { "  REGRESSED" } else { "" }
+            ),
+            _ => write!(f, "{step:20}{:>12} (no baseline)", nanos(self.current)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        baseline.insert(1, "parse", 2.5);
+        baseline.insert(10, "part_b", 1000.0);
+
+        let contents = toml::to_string(&baseline).unwrap();
+        assert!(contents.starts_with("[01]\nparse = 2.5\n"));
+        assert_eq!(toml::from_str::<Baseline>(&contents).unwrap(), baseline);
+        assert_eq!(baseline.get(10, "part_b"), Some(1000.0));
+    }
+
+    #[test]
+    fn test_update() {
+        let mut baseline = Baseline::default();
+        baseline.insert(1, "parse", 2.5);
+        baseline.insert(1, "part_a", 7.0);
+        let mut newer = Baseline::default();
+        newer.insert(1, "part_a", 6.0);
+        newer.insert(2, "part_b", 3.0);
+
+        baseline.update(newer);
+        assert_eq!(baseline.get(1, "parse"), Some(2.5));
+        assert_eq!(baseline.get(1, "part_a"), Some(6.0));
+        assert_eq!(baseline.get(2, "part_b"), Some(3.0));
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut baseline = Baseline::default();
+        baseline.insert(1, "part_a", 100.0);
+        baseline.insert(1, "part_b", 100.0);
+        let mut current = Baseline::default();
+        current.insert(1, "part_a", 105.0);
+        current.insert(1, "part_b", 150.0);
+        current.insert(2, "parse", 10.0);
+
+        let comparisons = baseline.compare(&current, 10.0);
+        let regressed: Vec<(u8, &str, bool)> = comparisons
+            .iter()
+            .map(|c| (c.day, c.step.as_str(), c.regressed))
+            .collect();
+        assert_eq!(
+            regressed,
+            [
+                (1, "part_a", false),
+                (1, "part_b", true),
+                (2, "parse", false)
+            ]
+        );
+        assert_eq!(comparisons[1].change, Some(50.0));
+        assert_eq!(comparisons[2].baseline, None);
+    }
+}
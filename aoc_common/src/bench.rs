@@ -0,0 +1,82 @@
+//! Criterion benches generated from a year's registry: one group per day,
+//! with parsing, part A and part B as separate functions. Days with an input
+//! generator can also be benched over a range of input sizes.
+
+pub use criterion;
+
+use criterion::{BenchmarkId, Criterion};
+
+use crate::{group_name, Generator, RunConfig, Year};
+
+/// Benches every day of `year` that has an input. Inputs are found the same
+/// way as for the runners, so `AOC_INPUT_DIR` is honoured.
+pub fn bench_year(c: &mut Criterion, year: &Year) {
+    let config = RunConfig::from_env();
+    for day in year.days {
+        let input = match year.load_input(config.input_root.as_deref(), day.day) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Skipping {} day {}: {err}", year.year, day.day);
+                continue;
+            }
+        };
+        let mut group = c.benchmark_group(group_name(year.year, day.day));
+        day.solution.for_each_step(&input, &mut |step, run| {
+            group.bench_function(step, |b| b.iter(&mut *run));
+        });
+        group.finish();
+    }
+}
+
+/// Benches solving each generated day of `year` once per size in its
+/// generator's `sizes`, so the results chart solve time against input size.
+pub fn bench_scaling(c: &mut Criterion, year: &Year, generators: &[Generator]) {
+    for generator in generators {
+        let Some(day) = year.day(generator.day) else {
+            eprintln!(
+                "Skipping {} day {}: not registered",
+                year.year, generator.day
+            );
+            continue;
+        };
+        let mut group =
+            c.benchmark_group(format!("{}_scaling", group_name(year.year, generator.day)));
+        group.sample_size(10);
+        for &size in generator.sizes {
+            let input = generator.generate(size, 0);
+            group.bench_with_input(
+                BenchmarkId::new(generator.unit, size),
+                &input,
+                |b, input| b.iter(|| day.solution.solve(input)),
+            );
+        }
+        group.finish();
+    }
+}
+
+/// Generates the `main` of a bench target that benches every day of a year.
+#[macro_export]
+macro_rules! bench_main {
+    ($year:expr) => {
+        fn bench_year(c: &mut $crate::bench::criterion::Criterion) {
+            $crate::bench::bench_year(c, $year);
+        }
+
+        $crate::bench::criterion::criterion_group!(benches, bench_year);
+        $crate::bench::criterion::criterion_main!(benches);
+    };
+}
+
+/// Generates the `main` of a bench target that benches a year's generated
+/// days over their range of input sizes.
+#[macro_export]
+macro_rules! scaling_main {
+    ($year:expr, $generators:expr) => {
+        fn bench_scaling(c: &mut $crate::bench::criterion::Criterion) {
+            $crate::bench::bench_scaling(c, $year, $generators);
+        }
+
+        $crate::bench::criterion::criterion_group!(benches, bench_scaling);
+        $crate::bench::criterion::criterion_main!(benches);
+    };
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states, each made from the one before, starts
+/// repeating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps taken before the first state that comes around again.
+    pub start: usize,
+    /// Steps it takes to come around again.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step that ends up in the same state as step `n`.
+    pub fn index_after(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// How many times the cycle comes around between [`Cycle::index_after`]
+    /// and `n`.
+    pub fn laps(&self, n: usize) -> usize {
+        n.saturating_sub(self.start) / self.length
+    }
+}
+
+/// Steps from `initial` until a state comes around again, remembering every
+/// state on the way.
+///
+/// The states run from `initial` up to and including the repeat, so there
+/// are `start + length + 1` of them.
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    find_cycle_by_key(initial, step, S::clone)
+}
+
+/// [`find_cycle`], where two states are the same when their `key`s are.
+///
+/// Useful when the states carry something that keeps growing, like a height,
+/// next to the part that repeats.
+pub fn find_cycle_by_key<S, K: Eq + Hash>(
+    initial: S,
+    step: impl FnMut(&S) -> S,
+    key: impl FnMut(&S) -> K,
+) -> (Cycle, Vec<S>) {
+    let (cycle, states) = walk(initial, step, key, usize::MAX);
+    (cycle.expect("Only stops at a cycle"), states)
+}
+
+/// The state after `n` steps from `initial`, skipping whole cycles once the
+/// states start repeating.
+pub fn state_after<S: Clone + Eq + Hash>(initial: S, n: usize, step: impl FnMut(&S) -> S) -> S {
+    let (cycle, mut states) = walk(initial, step, S::clone, n);
+    let index = cycle.map_or(n, |cycle| cycle.index_after(n));
+    states.swap_remove(index)
+}
+
+/// Steps until a state repeats or `limit` steps are taken.
+fn walk<S, K: Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+    limit: usize,
+) -> (Option<Cycle>, Vec<S>) {
+    let mut seen = HashMap::from([(key(&initial), 0)]);
+    let mut states = vec![initial];
+    while states.len() <= limit {
+        let next = step(states.last().unwrap());
+        let index = states.len();
+        let first = *seen.entry(key(&next)).or_insert(index);
+        states.push(next);
+        if first != index {
+            let cycle = Cycle {
+                start: first,
+                length: index - first,
+            };
+            return (Some(cycle), states);
+        }
+    }
+    (None, states)
+}
+
+/// Brent's algorithm: finds the cycle without remembering the states on the
+/// way, at the cost of taking more steps.
+///
+/// Also returns the first state that comes around again.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> (Cycle, S) {
+    // Find the length by racing a hare ahead of a tortoise that jumps to it
+    // every power of two steps.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare a whole cycle ahead, they meet where the cycle starts.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    (Cycle { start, length }, tortoise)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 2, 3, 4, ...
+    fn rho(&x: &u32) -> u32 {
+        if x == 4 {
+            2
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn test_state_after() {
+        assert_eq!(state_after(0, 0, rho), 0);
+        assert_eq!(state_after(0, 4, rho), 4);
+        assert_eq!(state_after(0, 5, rho), 2);
+        assert_eq!(state_after(0, 1_000_000, rho), 4);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let (cycle, states) = find_cycle(0, rho);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 2,
+                length: 3
+            }
+        );
+        assert_eq!(states, [0, 1, 2, 3, 4, 2]);
+        assert_eq!(cycle.index_after(9), 3);
+        assert_eq!(cycle.laps(9), 2);
+    }
+
+    #[test]
+    fn test_find_cycle_by_key() {
+        let (cycle, states) =
+            find_cycle_by_key((0, 0), |&(x, total)| (rho(&x), total + x), |s| s.0);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 2,
+                length: 3
+            }
+        );
+        assert_eq!(states[5], (2, 10));
+    }
+
+    #[test]
+    fn test_brent_matches_find_cycle() {
+        for initial in 0..5 {
+            let (expected, states) = find_cycle(initial, rho);
+            assert_eq!(brent(initial, rho), (expected, states[expected.start]));
+        }
+    }
+}
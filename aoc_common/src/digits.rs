@@ -0,0 +1,30 @@
+use std::{fmt::Debug, str::FromStr};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    pub static ref RE_DIGITS: Regex = Regex::new(r"(\d+)").unwrap();
+}
+
+/// Parses every run of digits in `s`, ignoring whatever is in between.
+pub fn extract_digits<T>(s: &str) -> impl Iterator<Item = T> + '_
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    RE_DIGITS
+        .find_iter(s)
+        .map(|digits| digits.as_str().parse().expect("Only digits are matched"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_digits() {
+        let digits: Vec<u64> = extract_digits("Button A: X+94, Y+34").collect();
+        assert_eq!(digits, vec![94, 34]);
+    }
+}
@@ -0,0 +1,21 @@
+/// Produces a random but valid puzzle input for a day. `size` is the day's
+/// natural measure of input size, e.g. the side of a grid or the number of
+/// lines, and the same `seed` always gives the same input.
+pub type Generate = fn(size: usize, seed: u64) -> String;
+
+/// A random input generator for one day, used to see how its solution
+/// scales beyond the single official input.
+pub struct Generator {
+    pub day: u8,
+    pub generate: Generate,
+    /// What `size` counts, for labelling results.
+    pub unit: &'static str,
+    /// Sizes to chart by default, the official input being around the middle.
+    pub sizes: &'static [usize],
+}
+
+impl Generator {
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generate)(size, seed)
+    }
+}
@@ -0,0 +1,476 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::grid::{Pos, ALL_AROUND, ORTHOGONAL};
+use crate::ParseError;
+
+/// A point or a vector in 2D, with y going down like in a [`Grid`](crate::Grid).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point or a vector in 3D.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// The offsets to the six cubes sharing a face with a cube.
+const FACES: [(isize, isize, isize); 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+/// One of the four orthogonal directions on a map, clockwise from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// One step in this direction as `(dx, dy)`, as taken by [`Grid::neighbour`](crate::Grid::neighbour).
+    pub fn offset(self) -> (isize, isize) {
+        ORTHOGONAL[self as usize]
+    }
+
+    /// One step from `pos` in this direction, unless that would go past the
+    /// top or the left of a grid.
+    pub fn step(self, (x, y): Pos) -> Option<Pos> {
+        let (dx, dy) = self.offset();
+        Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+}
+
+/// Arrows (`^>v<`) and the letters `U`, `R`, `D` and `L`.
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, ParseError> {
+        match c {
+            '^' | 'U' => Ok(Direction::Up),
+            '>' | 'R' => Ok(Direction::Right),
+            'v' | 'D' => Ok(Direction::Down),
+            '<' | 'L' => Ok(Direction::Left),
+            _ => Err(ParseError::unexpected_char(c)),
+        }
+    }
+}
+
+impl<T> Coord<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Coord { x, y }
+    }
+}
+
+impl<T> Coord3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Coord3 { x, y, z }
+    }
+}
+
+impl<T> From<(T, T)> for Coord<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Coord { x, y }
+    }
+}
+
+impl<T> From<Coord<T>> for (T, T) {
+    fn from(coord: Coord<T>) -> Self {
+        (coord.x, coord.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Coord3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Coord3 { x, y, z }
+    }
+}
+
+impl<T> From<Coord3<T>> for (T, T, T) {
+    fn from(coord: Coord3<T>) -> Self {
+        (coord.x, coord.y, coord.z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Coord<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Coord3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// Splits `s` on commas into exactly `N` numbers.
+fn parse_components<T: FromStr, const N: usize>(s: &str) -> Result<[T; N], ParseError> {
+    let invalid = || ParseError::new(format!("invalid coordinate {s:?}"));
+    let components = s
+        .trim()
+        .split(',')
+        .map(|part| part.trim().parse().map_err(|_| invalid()))
+        .collect::<Result<Vec<T>, _>>()?;
+    components.try_into().map_err(|_| invalid())
+}
+
+/// Parses `x,y`.
+impl<T: FromStr> FromStr for Coord<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [x, y] = parse_components(s)?;
+        Ok(Coord { x, y })
+    }
+}
+
+/// Parses `x,y,z`.
+impl<T: FromStr> FromStr for Coord3<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [x, y, z] = parse_components(s)?;
+        Ok(Coord3 { x, y, z })
+    }
+}
+
+macro_rules! impl_ops {
+    ($coord:ident { $($field:ident),+ }) => {
+        impl<T: Add<Output = T>> Add for $coord<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $coord { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $coord<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $coord { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $coord<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $coord<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $coord<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $coord { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl<T: Div<Output = T> + Copy> Div<T> for $coord<T> {
+            type Output = Self;
+
+            fn div(self, divisor: T) -> Self {
+                $coord { $($field: self.$field / divisor),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $coord<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $coord { $($field: -self.$field),+ }
+            }
+        }
+    };
+}
+
+impl_ops!(Coord { x, y });
+impl_ops!(Coord3 { x, y, z });
+
+/// Distances and neighbours, for every integer type that coordinates come in.
+/// `$offset` is the signed type of the same width, used to step from a
+/// coordinate without overflowing, and `$distance` the unsigned one, which
+/// holds the distance along any one axis.
+macro_rules! impl_integer {
+    ($($t:ty => $offset:ty, $distance:ty),+ $(,)?) => {$(
+        impl Coord<$t> {
+            /// Panics if the distance doesn't fit in the unsigned type of the same width.
+            pub fn manhattan(self, other: Self) -> $distance {
+                self.x
+                    .abs_diff(other.x)
+                    .checked_add(self.y.abs_diff(other.y))
+                    .unwrap_or_else(|| panic!("distance from {self:?} to {other:?} overflows"))
+            }
+
+            /// The number of king's moves between the two.
+            pub fn chebyshev(self, other: Self) -> $distance {
+                self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+            }
+
+            pub fn euclidean(self, other: Self) -> f64 {
+                let dx = self.x.abs_diff(other.x) as f64;
+                let dy = self.y.abs_diff(other.y) as f64;
+                dx.hypot(dy)
+            }
+
+            /// `self` moved by `(dx, dy)`, if that is still representable.
+            pub fn checked_offset(self, (dx, dy): (isize, isize)) -> Option<Self> {
+                let dx = <$offset>::try_from(dx).ok()?;
+                let dy = <$offset>::try_from(dy).ok()?;
+                Some(Coord {
+                    x: self.x.checked_add_signed(dx as _)?,
+                    y: self.y.checked_add_signed(dy as _)?,
+                })
+            }
+
+            pub fn checked_step(self, direction: Direction) -> Option<Self> {
+                self.checked_offset(direction.offset())
+            }
+
+            /// Up, right, down and left of `self`, skipping any that overflow.
+            pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+                ORTHOGONAL
+                    .into_iter()
+                    .filter_map(move |offset| self.checked_offset(offset))
+            }
+
+            /// Like [`Coord::neighbours4`], including the diagonals.
+            pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+                ALL_AROUND
+                    .into_iter()
+                    .filter_map(move |offset| self.checked_offset(offset))
+            }
+        }
+
+        /// One step in `direction`. Panics when leaving the range of the type,
+        /// like any other arithmetic overflow.
+        impl Add<Direction> for Coord<$t> {
+            type Output = Self;
+
+            fn add(self, direction: Direction) -> Self {
+                self.checked_step(direction)
+                    .unwrap_or_else(|| panic!("stepping {direction:?} from {self:?} overflows"))
+            }
+        }
+
+        impl AddAssign<Direction> for Coord<$t> {
+            fn add_assign(&mut self, direction: Direction) {
+                *self = *self + direction;
+            }
+        }
+
+        impl Coord3<$t> {
+            /// Panics if the distance doesn't fit in the unsigned type of the same width.
+            pub fn manhattan(self, other: Self) -> $distance {
+                self.x
+                    .abs_diff(other.x)
+                    .checked_add(self.y.abs_diff(other.y))
+                    .and_then(|xy| xy.checked_add(self.z.abs_diff(other.z)))
+                    .unwrap_or_else(|| panic!("distance from {self:?} to {other:?} overflows"))
+            }
+
+            pub fn chebyshev(self, other: Self) -> $distance {
+                self.x
+                    .abs_diff(other.x)
+                    .max(self.y.abs_diff(other.y))
+                    .max(self.z.abs_diff(other.z))
+            }
+
+            pub fn euclidean(self, other: Self) -> f64 {
+                let dx = self.x.abs_diff(other.x) as f64;
+                let dy = self.y.abs_diff(other.y) as f64;
+                let dz = self.z.abs_diff(other.z) as f64;
+                (dx * dx + dy * dy + dz * dz).sqrt()
+            }
+
+            pub fn checked_offset(self, (dx, dy, dz): (isize, isize, isize)) -> Option<Self> {
+                let dx = <$offset>::try_from(dx).ok()?;
+                let dy = <$offset>::try_from(dy).ok()?;
+                let dz = <$offset>::try_from(dz).ok()?;
+                Some(Coord3 {
+                    x: self.x.checked_add_signed(dx as _)?,
+                    y: self.y.checked_add_signed(dy as _)?,
+                    z: self.z.checked_add_signed(dz as _)?,
+                })
+            }
+
+            /// The six cubes sharing a face with `self`, skipping any that overflow.
+            pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+                FACES
+                    .into_iter()
+                    .filter_map(move |offset| self.checked_offset(offset))
+            }
+        }
+    )+};
+}
+
+/// `checked_add_signed` on a signed type is just `checked_add`, so the signed
+/// types get it here to share [`impl_integer`].
+trait CheckedAddSigned: Sized {
+    fn checked_add_signed(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_add_signed {
+    ($($t:ty),+) => {$(
+        impl CheckedAddSigned for $t {
+            fn checked_add_signed(self, rhs: Self) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+        }
+    )+};
+}
+
+impl_checked_add_signed!(i32, i64, isize);
+
+impl_integer! {
+    u8 => i8, u8,
+    u32 => i32, u32,
+    u64 => i64, u64,
+    usize => isize, usize,
+    i32 => i32, u32,
+    i64 => i64, u64,
+    isize => isize, usize,
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),+) => {$(
+        impl Coord<$t> {
+            /// The vector turned a quarter clockwise, with y going down.
+            pub fn turn_right(self) -> Self {
+                Coord { x: -self.y, y: self.x }
+            }
+
+            pub fn turn_left(self) -> Self {
+                Coord { x: self.y, y: -self.x }
+            }
+        }
+
+        impl From<Direction> for Coord<$t> {
+            fn from(direction: Direction) -> Self {
+                let (dx, dy) = direction.offset();
+                Coord { x: dx as $t, y: dy as $t }
+            }
+        }
+    )+};
+}
+
+impl_signed!(i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            let vector = Coord::<i64>::from(direction);
+            assert_eq!(vector.turn_right(), direction.turn_right().into());
+            assert_eq!(vector.turn_left(), direction.turn_left().into());
+            assert_eq!(-vector, direction.reverse().into());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    }
+
+    #[test]
+    fn test_step() {
+        assert_eq!(Direction::Down.step((3, 0)), Some((3, 1)));
+        assert_eq!(Direction::Up.step((3, 0)), None);
+        assert_eq!(Direction::Left.step((0, 3)), None);
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Coord::<i64>::new(1, -2), Coord::new(-3, 1));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.euclidean(b), 5.0);
+        let (a, b) = (Coord::<usize>::new(4, 0), Coord::new(1, 4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        let (a, b) = (Coord3::<u32>::new(1, 2, 3), Coord3::new(3, 2, 1));
+        assert_eq!((a.manhattan(b), a.chebyshev(b)), (4, 2));
+    }
+
+    #[test]
+    fn test_large_distances() {
+        let (a, b) = (Coord::<u8>::new(0, 0), Coord::new(200, 55));
+        assert_eq!((a.manhattan(b), a.chebyshev(b)), (255, 200));
+        let (a, b) = (Coord::new(i64::MIN, 0), Coord::new(i64::MAX, 0));
+        assert_eq!((a.manhattan(b), a.chebyshev(b)), (u64::MAX, u64::MAX));
+        let (a, b) = (Coord3::new(-5i32, 0, 0), Coord3::new(5, -5, 5));
+        assert_eq!((a.manhattan(b), a.chebyshev(b)), (20, 10));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_manhattan_past_the_distance_type() {
+        Coord::<u8>::new(0, 0).manhattan(Coord::new(200, 200));
+    }
+
+    #[test]
+    fn test_neighbours_skip_overflow() {
+        let origin = Coord::<usize>::new(0, 0);
+        assert_eq!(
+            origin.neighbours4().collect::<Vec<_>>(),
+            [Coord::new(1, 0), Coord::new(0, 1)]
+        );
+        assert_eq!(origin.neighbours8().count(), 3);
+        assert_eq!(Coord::<i32>::new(0, 0).neighbours8().count(), 8);
+        assert_eq!(Coord3::<u8>::new(0, 5, 255).neighbours6().count(), 4);
+        assert_eq!(origin.checked_step(Direction::Left), None);
+        assert_eq!(origin + Direction::Down, Coord::new(0, 1));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3, -4".parse(), Ok(Coord::<i64>::new(3, -4)));
+        assert_eq!("1,2,3".parse(), Ok(Coord3::<u8>::new(1, 2, 3)));
+        assert!("1,2,3".parse::<Coord<u8>>().is_err());
+        assert!("1".parse::<Coord<u8>>().is_err());
+        assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+    }
+}
@@ -0,0 +1,384 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// A position in a [`Grid`], as `(x, y)` with y going down.
+pub type Pos = (usize, usize);
+
+/// Up, right, down and left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The orthogonal and the diagonal offsets, clockwise from up.
+pub const ALL_AROUND: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangle of cells, stored row by row in one buffer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Fails unless all rows are as long as the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ParseError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(ParseError::new(format!(
+                    "row {y} has {} cells, expected {width}",
+                    row.len()
+                )));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// One cell per character, one row per line. The grid can't be empty.
+    pub fn parse_with(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let rows = input
+            .trim_matches(['\n', '\r'])
+            .lines()
+            .map(|line| line.chars().map(&mut cell).collect())
+            .collect::<Result<Vec<Vec<T>>, _>>()?;
+        let grid = Grid::from_rows(rows)?;
+        if grid.cells.is_empty() {
+            return Err(ParseError::new("empty grid"));
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.index(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let index = self.index(pos);
+        Some(&mut self.cells[index])
+    }
+
+    /// The position `offset` away from `pos`, if that is still in the grid.
+    pub fn neighbour(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The position `offset` away from `pos`, wrapping around the edges.
+    ///
+    /// Panics if the grid is empty, since there is nothing to wrap around to.
+    pub fn wrapping_neighbour(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Pos {
+        assert!(!self.cells.is_empty(), "an empty grid has no neighbours");
+        let wrap = |value: usize, delta: isize, size: usize| {
+            (value as isize + delta).rem_euclid(size as isize) as usize
+        };
+        (wrap(x, dx, self.width), wrap(y, dy, self.height))
+    }
+
+    /// The positions up, right, down and left of `pos` that are in the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |offset| self.neighbour(pos, offset))
+    }
+
+    /// Like [`Grid::neighbours4`], including the diagonals.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_AROUND
+            .into_iter()
+            .filter_map(move |offset| self.neighbour(pos, offset))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// The first position, row by row, whose cell matches.
+    pub fn position(&self, mut matches: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let index = self.cells.iter().position(&mut matches)?;
+        Some((index % self.width, index / self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index(&self, (x, y): Pos) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid in its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{pos:?} is outside the grid");
+        &self.cells[self.index(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{pos:?} is outside the grid");
+        let index = self.index(pos);
+        &mut self.cells[index]
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<ParseError>,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(s, |c| T::try_from(c).map_err(Into::into))
+    }
+}
+
+/// Renders one character per cell, each row on its own line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Wall,
+        Open,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = ParseError;
+
+        fn try_from(c: char) -> Result<Self, ParseError> {
+            match c {
+                '#' => Ok(Tile::Wall),
+                '.' => Ok(Tile::Open),
+                _ => Err(ParseError::unexpected_char(c)),
+            }
+        }
+    }
+
+    impl fmt::Display for Tile {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Tile::Wall => write!(f, "#"),
+                Tile::Open => write!(f, "."),
+            }
+        }
+    }
+
+    const INPUT: &str = "#..\n.#.\n";
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        let grid: Grid<Tile> = INPUT.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], Tile::Wall);
+        assert_eq!(grid[(2, 0)], Tile::Open);
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_input() {
+        assert_eq!(
+            "#.\n#x".parse::<Grid<Tile>>(),
+            Err(ParseError::unexpected_char('x'))
+        );
+        assert_eq!(
+            "#.\n#".parse::<Grid<Tile>>(),
+            Err(ParseError::new("row 1 has 1 cells, expected 2"))
+        );
+        assert_eq!(
+            "\n".parse::<Grid<Tile>>(),
+            Err(ParseError::new("empty grid"))
+        );
+    }
+
+    #[test]
+    fn test_parse_with_digits() {
+        let grid = Grid::parse_with("12\n34", |c| {
+            c.to_digit(10).ok_or(ParseError::unexpected_char(c))
+        })
+        .unwrap();
+        assert_eq!(grid.cells(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.neighbour((0, 0), (-1, 0)), None);
+        assert_eq!(grid.neighbour((0, 0), (1, 1)), Some((1, 1)));
+        assert_eq!(grid.wrapping_neighbour((0, 0), (-1, -1)), (2, 1));
+        assert_eq!(grid.wrapping_neighbour((2, 1), (4, 3)), (0, 0));
+        assert_eq!(
+            grid.neighbours4((1, 0)).collect::<Vec<_>>(),
+            [(2, 0), (1, 1), (0, 0)]
+        );
+        assert_eq!(grid.neighbours8((0, 1)).count(), 3);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::from_fn(3, 2, |(x, y)| 10 * y + x);
+        assert_eq!(grid.row(1), [10, 11, 12]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [2, 12]);
+        let columns: Vec<Vec<usize>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, [[0, 10], [1, 11], [2, 12]]);
+        assert_eq!(grid.position(|&cell| cell == 11), Some((1, 1)));
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &11)));
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = Grid::from_fn(3, 2, |(x, y)| 10 * y + x);
+        // 0  1  2
+        // 10 11 12
+        assert_eq!(grid.transpose().cells(), [0, 10, 1, 11, 2, 12]);
+        assert_eq!(grid.rotate_clockwise().cells(), [10, 0, 11, 1, 12, 2]);
+        assert_eq!(
+            grid.rotate_counterclockwise().cells(),
+            [2, 12, 1, 11, 0, 10]
+        );
+        let turned = (0..4).fold(grid.clone(), |grid, _| grid.rotate_clockwise());
+        assert_eq!(turned, grid);
+    }
+
+    #[test]
+    fn test_get_outside() {
+        let mut grid = Grid::new(2, 2, 'a');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get_mut((0, 2)), None);
+        *grid.get_mut((1, 1)).unwrap() = 'b';
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "AA\nAB\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "an empty grid has no neighbours")]
+    fn test_wrapping_neighbour_in_empty_grid() {
+        Grid::new(0, 0, 'a').wrapping_neighbour((0, 0), (1, 0));
+    }
+}
@@ -0,0 +1,125 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable pointing at a directory with a `{year}` folder of
+/// puzzle inputs per year, used instead of each crate's own `inputs` folder.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Unreadable(PathBuf, io::Error),
+    Empty(PathBuf),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(f, "missing input: {}", path.display()),
+            InputError::Unreadable(path, err) => {
+                write!(f, "could not read input {}: {err}", path.display())
+            }
+            InputError::Empty(path) => write!(f, "empty input: {}", path.display()),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Unreadable(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+pub fn input_root_from_env() -> Option<PathBuf> {
+    env::var_os(INPUT_DIR_VAR).map(PathBuf::from)
+}
+
+/// The file name of a day's input, the same for every year.
+pub fn day_file_name(day: u8) -> String {
+    format!("{day:02}.txt")
+}
+
+/// Reads a puzzle input, treating a file with nothing but whitespace in it as
+/// missing input rather than handing it to a parser. A path of `-` reads the
+/// input from stdin.
+pub fn load_input(path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref();
+    let input = if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|err| InputError::Unreadable(path.to_path_buf(), err))?;
+        input
+    } else {
+        fs::read_to_string(path).map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => InputError::Missing(path.to_path_buf()),
+            _ => InputError::Unreadable(path.to_path_buf(), err),
+        })?
+    };
+    if input.trim().is_empty() {
+        return Err(InputError::Empty(path.to_path_buf()));
+    }
+    Ok(input)
+}
+
+pub fn load_day_input(day: &str) -> Result<String, InputError> {
+    load_input(format!("inputs/{day}.txt"))
+}
+
+pub fn load_test_day_input(day: &str) -> Result<String, InputError> {
+    load_input(format!("test_inputs/test_{day}.txt"))
+}
+
+pub fn read_day_input(day: &str) -> String {
+    load_day_input(day).unwrap_or_else(|err| panic!("{err}"))
+}
+
+pub fn read_test_day_input(day: &str) -> String {
+    load_test_day_input(day).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// The day's puzzle input, or `None` if it isn't there. Puzzle inputs aren't
+/// committed, so tests on them only run where they have been downloaded.
+pub fn read_day_input_if_present(day: &str) -> Option<String> {
+    match load_day_input(day) {
+        Ok(input) => Some(input),
+        Err(InputError::Missing(path)) => {
+            eprintln!("skipping, no puzzle input at {}", path.display());
+            None
+        }
+        Err(err) => panic!("{err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_input_errors() {
+        let dir = std::env::temp_dir().join(format!("aoc_common_input_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let missing = dir.join("missing.txt");
+        assert!(matches!(load_input(&missing), Err(InputError::Missing(path)) if path == missing));
+
+        let empty = dir.join("empty.txt");
+        fs::write(&empty, "\n").unwrap();
+        assert!(matches!(load_input(&empty), Err(InputError::Empty(_))));
+
+        assert!(matches!(load_input(&dir), Err(InputError::Unreadable(..))));
+
+        let present = dir.join("present.txt");
+        fs::write(&present, "1\n2\n").unwrap();
+        assert_eq!(load_input(&present).unwrap(), "1\n2\n");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,257 @@
+use std::cmp::{max, min};
+use std::ops::{Add, Range, RangeInclusive, Sub};
+
+/// A set of integers, kept as sorted half-open ranges that neither overlap
+/// nor touch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+        // Everything from the first range reaching `range` up to the last one
+        // it reaches gets merged into it.
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = min(merged.start, self.ranges[first].start);
+            merged.end = max(merged.end, self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The ranges in the set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    /// The smallest value in the set.
+    pub fn first(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (a, b) = (&self.ranges, &other.ranges);
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            let start = max(a[i].start, b[j].start);
+            let end = min(a[i].end, b[j].end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a[i].end < b[j].end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            for cut in other.ranges[j..].iter().take_while(|r| r.start < range.end) {
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = max(start, cut.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8>> IntervalSet<T> {
+    /// How many values are in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::from(0), |len, r| len + (r.end - r.start))
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> IntervalSet<T> {
+    /// Moves the values inside each `from` range along with it to start at
+    /// `to`. Values outside all of them stay where they are.
+    ///
+    /// The `from` ranges must not overlap.
+    pub fn translate(&self, pieces: impl IntoIterator<Item = (Range<T>, T)>) -> Self {
+        let mut rest = self.clone();
+        let mut moved = IntervalSet::new();
+        for (from, to) in pieces {
+            let piece = IntervalSet::from(from.clone());
+            for range in rest.intersection(&piece).iter() {
+                moved.insert(to + (range.start - from.start)..to + (range.end - from.start));
+            }
+            rest = rest.difference(&piece);
+        }
+        moved.union(&rest)
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+/// Panics if the range ends at `T::MAX`, which a half-open range can't reach.
+impl<T: Successor> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        IntervalSet::from(half_open(range))
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// Panics if a range ends at `T::MAX`, like the [`From`] conversion.
+impl<T: Successor> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        iter.into_iter().map(half_open).collect()
+    }
+}
+
+fn half_open<T: Successor>(range: RangeInclusive<T>) -> Range<T> {
+    let end = range
+        .end()
+        .successor()
+        .expect("Inclusive range should not end at the largest value");
+    *range.start()..end
+}
+
+/// Integers that know the value right after them, so inclusive ranges of them
+/// can be turned into half-open ones.
+pub trait Successor: Copy + Ord {
+    /// `None` for the largest value.
+    fn successor(self) -> Option<Self>;
+}
+
+macro_rules! impl_successor {
+    ($($t:ty),*) => {
+        $(impl Successor for $t {
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+        })*
+    };
+}
+
+impl_successor!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert_merges_overlapping_and_touching() {
+        let mut s = set(&[0..2, 5..7, 10..12]);
+        s.insert(1..5);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [0..7, 10..12]);
+        s.insert(20..20);
+        assert_eq!(s, set(&[0..7, 10..12]));
+        assert_eq!(s.len(), 9);
+        assert!(s.contains(6) && !s.contains(7) && s.contains(10));
+        assert_eq!(IntervalSet::from(3..=4), IntervalSet::from(3..5));
+    }
+
+    #[test]
+    fn test_inclusive_ranges_up_to_the_largest_value() {
+        assert_eq!(IntervalSet::from(0..=u8::MAX - 1).len(), u8::MAX);
+        assert_eq!(IntervalSet::from(i8::MIN..=-1), IntervalSet::from(-128..0));
+    }
+
+    #[test]
+    #[should_panic(expected = "largest value")]
+    fn test_inclusive_range_ending_at_the_largest_value() {
+        let _ = IntervalSet::from(0..=u64::MAX);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+        assert_eq!(a.union(&b), IntervalSet::from(0..40));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25, 28..30]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..28]));
+        assert_eq!(b.difference(&a), set(&[10..20, 30..40]));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_translate() {
+        let s = IntervalSet::from(0..10);
+        let moved = s.translate([(2..4, 100), (6..8, -10)]);
+        assert_eq!(moved, set(&[-10..-8, 0..2, 4..6, 8..10, 100..102]));
+        assert_eq!(moved.len(), s.len());
+        assert_eq!(moved.first(), Some(-10));
+    }
+
+    #[test]
+    fn test_matches_a_set_of_values() {
+        use std::collections::BTreeSet;
+
+        let a = set(&[-3..4, 8..9, 12..20]);
+        let b = set(&[0..1, 2..10, 15..16, 19..25]);
+        let values = |s: &IntervalSet<i64>| s.iter().flatten().collect::<BTreeSet<_>>();
+        let (va, vb) = (values(&a), values(&b));
+        assert_eq!(values(&a.union(&b)), &va | &vb);
+        assert_eq!(values(&a.intersection(&b)), &va & &vb);
+        assert_eq!(values(&a.difference(&b)), &va - &vb);
+    }
+}
@@ -0,0 +1,40 @@
+mod answers;
+mod baseline;
+#[cfg(feature = "bench")]
+pub mod bench;
+mod cycle;
+mod digits;
+mod generate;
+mod geometry;
+mod grid;
+mod input;
+mod interval;
+mod parse;
+mod report;
+mod runner;
+mod search;
+mod solution;
+mod summary;
+
+pub use answers::{Answers, AnswersError, Verdict};
+pub use baseline::{group_name, Baseline, BaselineError, Comparison};
+pub use cycle::{brent, find_cycle, find_cycle_by_key, state_after, Cycle};
+pub use digits::{extract_digits, RE_DIGITS};
+pub use generate::{Generate, Generator};
+pub use geometry::{Coord, Coord3, Direction};
+pub use grid::{Grid, Pos, ALL_AROUND, ORTHOGONAL};
+pub use input::{
+    day_file_name, input_root_from_env, load_day_input, load_input, load_test_day_input,
+    read_day_input, read_day_input_if_present, read_test_day_input, InputError, INPUT_DIR_VAR,
+};
+pub use interval::{IntervalSet, Successor};
+pub use parse::ParseError;
+pub use report::{write_outcomes, Format, Record};
+pub use runner::{
+    format_result, print_outcome, print_result, print_verdict, run_day, run_days, run_year,
+    verify_year, DayError, DayErrorKind, DayOutcome, DayResult, DayVerdict, RunConfig,
+    PARALLEL_VAR, TIMEOUT_VAR,
+};
+pub use search::{astar, bfs, dijkstra, Search};
+pub use solution::{Day, DynSolution, Part, Solution, Solved, Timings, Year, STEPS};
+pub use summary::{format_summary, print_summary, summarise, YearSummary};
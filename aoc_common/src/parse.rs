@@ -0,0 +1,44 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+
+/// Why a puzzle input was rejected by a day's parser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+        }
+    }
+
+    /// For the tiles of a grid, and other single character tokens.
+    pub fn unexpected_char(c: char) -> Self {
+        ParseError::new(format!("unexpected character {c:?}"))
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseIntError> for ParseError {
+    fn from(err: ParseIntError) -> Self {
+        ParseError::new(err.to_string())
+    }
+}
+
+/// For cells that convert from any character.
+impl From<Infallible> for ParseError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
@@ -0,0 +1,156 @@
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::{format_result, DayOutcome};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format `{s}`, expected `table`, `json` or `csv`"
+            )),
+        }
+    }
+}
+
+/// One row of machine readable output. Days that could not be run have no
+/// answers or timings, only an error.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Record<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part_a: Option<&'a str>,
+    pub part_b: Option<&'a str>,
+    pub parse_ns: Option<u64>,
+    pub part_a_ns: Option<u64>,
+    pub part_b_ns: Option<u64>,
+    pub total_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl<'a> From<&'a DayOutcome> for Record<'a> {
+    fn from(outcome: &'a DayOutcome) -> Self {
+        let nanos = |time: std::time::Duration| Some(time.as_nanos() as u64);
+        match outcome {
+            Ok(result) => Record {
+                year: result.year,
+                day: result.day,
+                part_a: Some(&result.part_a),
+                part_b: Some(&result.part_b),
+                parse_ns: nanos(result.timings.parse),
+                part_a_ns: nanos(result.timings.part_a),
+                part_b_ns: nanos(result.timings.part_b),
+                total_ns: nanos(result.time()),
+                error: None,
+            },
+            Err(err) => Record {
+                year: err.year,
+                day: err.day,
+                part_a: None,
+                part_b: None,
+                parse_ns: None,
+                part_a_ns: None,
+                part_b_ns: None,
+                total_ns: None,
+                error: Some(err.kind.to_string()),
+            },
+        }
+    }
+}
+
+pub fn write_outcomes(
+    mut out: impl Write,
+    format: Format,
+    outcomes: &[DayOutcome],
+) -> io::Result<()> {
+    let records = outcomes.iter().map(Record::from);
+    match format {
+        Format::Table => {
+            for outcome in outcomes {
+                match outcome {
+                    Ok(result) => writeln!(out, "{}", format_result(result))?,
+                    Err(err) => writeln!(out, "{err}")?,
+                }
+            }
+        }
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut out, &records.collect::<Vec<_>>())?;
+            writeln!(out)?;
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for record in records {
+                writer.serialize(record).map_err(io::Error::other)?;
+            }
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DayError, DayErrorKind, DayResult, InputError, Timings};
+    use std::time::Duration;
+
+    fn outcomes() -> Vec<DayOutcome> {
+        vec![
+            Ok(DayResult {
+                year: 2022,
+                day: 10,
+                part_a: "14540".into(),
+                part_b: "#.\n.#".into(),
+                timings: Timings {
+                    parse: Duration::from_nanos(10),
+                    part_a: Duration::from_nanos(200),
+                    part_b: Duration::from_nanos(3_000),
+                },
+            }),
+            Err(DayError {
+                year: 2022,
+                day: 11,
+                kind: DayErrorKind::Input(InputError::Missing("inputs/11.txt".into())),
+            }),
+        ]
+    }
+
+    fn write(format: Format) -> String {
+        let mut out = Vec::new();
+        write_outcomes(&mut out, format, &outcomes()).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_write_csv() {
+        assert_eq!(
+            write(Format::Csv),
+            "year,day,part_a,part_b,parse_ns,part_a_ns,part_b_ns,total_ns,error\n\
+             2022,10,14540,\"#.\n.#\",10,200,3000,3210,\n\
+             2022,11,,,,,,,missing input: inputs/11.txt\n"
+        );
+    }
+
+    #[test]
+    fn test_write_json() {
+        let json: serde_json::Value = serde_json::from_str(&write(Format::Json)).unwrap();
+        assert_eq!(json[0]["part_b"], "#.\n.#");
+        assert_eq!(json[0]["total_ns"], 3210);
+        assert_eq!(json[1]["part_a"], serde_json::Value::Null);
+        assert_eq!(json[1]["error"], "missing input: inputs/11.txt");
+    }
+}
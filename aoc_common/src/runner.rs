@@ -0,0 +1,504 @@
+use std::any::Any;
+use std::env;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+
+use crate::summary::print_summary;
+use crate::{
+    input_root_from_env, Answers, AnswersError, Day, InputError, ParseError, Part, Timings,
+    Verdict, Year,
+};
+
+/// Environment variable with the number of seconds a single day may run
+/// before the runners give up on it.
+pub const TIMEOUT_VAR: &str = "AOC_DAY_TIMEOUT";
+
+/// Environment variable that turns on [`RunConfig::parallel`] when set to
+/// anything but `0`.
+pub const PARALLEL_VAR: &str = "AOC_PARALLEL";
+
+/// Solver threads get the same stack as the main thread would have had.
+const SOLVER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Where the runners read inputs from and how long each day may take.
+#[derive(Debug, Clone, Default)]
+pub struct RunConfig {
+    pub input_root: Option<PathBuf>,
+    pub timeout: Option<Duration>,
+    /// Solve the days of a year concurrently. Results are still reported in
+    /// day order, but only once every day is done.
+    pub parallel: bool,
+}
+
+impl RunConfig {
+    pub fn from_env() -> Self {
+        RunConfig {
+            input_root: input_root_from_env(),
+            timeout: env::var(TIMEOUT_VAR)
+                .ok()
+                .and_then(|secs| secs.parse().ok())
+                .map(Duration::from_secs_f64),
+            parallel: env::var_os(PARALLEL_VAR).is_some_and(|value| value != "0"),
+        }
+    }
+
+    fn load_input(&self, year: &Year, day: u8) -> Result<String, InputError> {
+        year.load_input(self.input_root.as_deref(), day)
+    }
+}
+
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub part_a: String,
+    pub part_b: String,
+    pub timings: Timings,
+}
+
+impl DayResult {
+    pub fn time(&self) -> Duration {
+        self.timings.total()
+    }
+}
+
+/// A day that could not be solved. The runners report it and move on to the
+/// next day.
+#[derive(Debug)]
+pub struct DayError {
+    pub year: u16,
+    pub day: u8,
+    pub kind: DayErrorKind,
+}
+
+#[derive(Debug)]
+pub enum DayErrorKind {
+    Input(InputError),
+    Parse(ParseError),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl fmt::Display for DayErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayErrorKind::Input(err) => write!(f, "{err}"),
+            DayErrorKind::Parse(err) => write!(f, "invalid input: {err}"),
+            DayErrorKind::Panicked(message) => write!(f, "panicked: {message}"),
+            DayErrorKind::TimedOut(timeout) => write!(f, "timeout after {timeout:.2?}"),
+        }
+    }
+}
+
+impl fmt::Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {}: {}", self.day, self.kind)
+    }
+}
+
+pub type DayOutcome = Result<DayResult, DayError>;
+
+/// Solves `day` on its own thread, so a panic or a day running past
+/// `timeout` is reported as an error instead of ending the whole run. A day
+/// that times out is left running in the background.
+pub fn run_day(
+    year: u16,
+    day: &'static Day,
+    input: String,
+    timeout: Option<Duration>,
+) -> DayOutcome {
+    let error = |kind| DayError {
+        year,
+        day: day.day,
+        kind,
+    };
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new()
+        .name(format!("{year} day {}", day.day))
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || {
+            let solved = panic::catch_unwind(AssertUnwindSafe(|| day.solution.solve_timed(&input)));
+            // Nobody is listening anymore if the day timed out.
+            let _ = sender.send(solved);
+        })
+        .expect("Failed to spawn solver thread");
+
+    let solved = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(RecvTimeoutError::from),
+    };
+    match solved {
+        Ok(Ok(Ok(solved))) => Ok(DayResult {
+            year,
+            day: day.day,
+            part_a: solved.part_a,
+            part_b: solved.part_b,
+            timings: solved.timings,
+        }),
+        Ok(Ok(Err(err))) => Err(error(DayErrorKind::Parse(err))),
+        Ok(Err(payload)) => Err(error(DayErrorKind::Panicked(panic_message(&*payload)))),
+        Err(RecvTimeoutError::Timeout) => Err(error(DayErrorKind::TimedOut(
+            timeout.expect("Only waits with a timeout can time out"),
+        ))),
+        Err(RecvTimeoutError::Disconnected) => {
+            unreachable!("The solver thread always reports back")
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown cause".to_string()
+    }
+}
+
+fn load_and_run_day(
+    year: u16,
+    day: &'static Day,
+    timeout: Option<Duration>,
+    read_input: impl Fn(u8) -> Result<String, InputError>,
+) -> DayOutcome {
+    let input = read_input(day.day).map_err(|err| DayError {
+        year,
+        day: day.day,
+        kind: DayErrorKind::Input(err),
+    })?;
+    run_day(year, day, input, timeout)
+}
+
+/// Runs every day of `year` and hands each outcome to `report` in day order,
+/// as soon as the day is done or, in parallel mode, once all days are done.
+fn run_days_with<T>(
+    year: &Year,
+    config: &RunConfig,
+    read_input: impl Fn(u8) -> Result<String, InputError> + Sync,
+    report: impl FnMut(DayOutcome) -> T,
+) -> Vec<T> {
+    let run = |day| load_and_run_day(year.year, day, config.timeout, &read_input);
+    if config.parallel {
+        let outcomes: Vec<DayOutcome> = year.days.par_iter().map(run).collect();
+        outcomes.into_iter().map(report).collect()
+    } else {
+        year.days.iter().map(run).map(report).collect()
+    }
+}
+
+/// Runs every registered day of `year` without printing anything.
+pub fn run_days(year: &Year, config: &RunConfig) -> Vec<DayOutcome> {
+    run_days_with(
+        year,
+        config,
+        |day| config.load_input(year, day),
+        |outcome| outcome,
+    )
+}
+
+pub fn format_result(result: &DayResult) -> String {
+    let result_string = format!("Day {}: ({}, {})", result.day, result.part_a, result.part_b);
+    let timings = &result.timings;
+    format!(
+        "{result_string:60}  (parse: {:.2?}, a: {:.2?}, b: {:.2?}, took: {:.2?})",
+        timings.parse,
+        timings.part_a,
+        timings.part_b,
+        result.time()
+    )
+}
+
+pub fn print_result(result: &DayResult) {
+    println!("{}", format_result(result));
+}
+
+pub fn print_outcome(outcome: &DayOutcome) {
+    match outcome {
+        Ok(result) => print_result(result),
+        Err(err) => println!("{err}"),
+    }
+}
+
+/// Runs and prints every registered day of `year` as it goes, followed by
+/// a summary of the time spent solving.
+pub fn run_year(year: &Year, config: &RunConfig) -> Vec<DayOutcome> {
+    println!("Solving Advent of Code {}:", year.year);
+    let start = Instant::now();
+    let outcomes = run_days_with(
+        year,
+        config,
+        |day| config.load_input(year, day),
+        |outcome| {
+            print_outcome(&outcome);
+            outcome
+        },
+    );
+    let wall_clock = start.elapsed();
+    print_summary(&outcomes);
+    if config.parallel {
+        let summed: Duration = outcomes.iter().flatten().map(DayResult::time).sum();
+        println!("Wall clock: {wall_clock:.2?} for {summed:.2?} of solving");
+    }
+    outcomes
+}
+
+pub struct DayVerdict {
+    pub result: DayResult,
+    pub part_a: Verdict,
+    pub part_b: Verdict,
+}
+
+impl DayVerdict {
+    fn check(result: DayResult, answers: &Answers) -> Self {
+        DayVerdict {
+            part_a: Verdict::check(answers.get(result.day, Part::A), &result.part_a),
+            part_b: Verdict::check(answers.get(result.day, Part::B), &result.part_b),
+            result,
+        }
+    }
+
+    fn verdicts(&self) -> [&Verdict; 2] {
+        [&self.part_a, &self.part_b]
+    }
+}
+
+fn describe_verdict(verdict: &Verdict, actual: &str) -> String {
+    match verdict {
+        Verdict::Pass => "pass".to_string(),
+        Verdict::Fail { expected } => format!("FAIL (expected {expected}, got {actual})"),
+        Verdict::Unknown => format!("unknown ({actual})"),
+    }
+}
+
+pub fn print_verdict(outcome: &Result<DayVerdict, DayError>) {
+    match outcome {
+        Ok(verdict) => println!(
+            "Day {}: a {}, b {}",
+            verdict.result.day,
+            describe_verdict(&verdict.part_a, &verdict.result.part_a),
+            describe_verdict(&verdict.part_b, &verdict.result.part_b),
+        ),
+        Err(err) => println!("{err}"),
+    }
+}
+
+/// Runs every registered day of `year` and checks the answers against the
+/// year's `answers.toml`, printing a verdict per day followed by a summary.
+pub fn verify_year(
+    year: &Year,
+    config: &RunConfig,
+) -> Result<Vec<Result<DayVerdict, DayError>>, AnswersError> {
+    let answers = Answers::load(year.answers_path(config.input_root.as_deref()))?;
+    println!("Verifying Advent of Code {}:", year.year);
+    let outcomes = run_days_with(
+        year,
+        config,
+        |day| config.load_input(year, day),
+        |outcome| {
+            let outcome = outcome.map(|result| DayVerdict::check(result, &answers));
+            print_verdict(&outcome);
+            outcome
+        },
+    );
+
+    let verdicts: Vec<&Verdict> = outcomes
+        .iter()
+        .flatten()
+        .flat_map(DayVerdict::verdicts)
+        .collect();
+    let count = |f: fn(&Verdict) -> bool| verdicts.iter().filter(|v| f(v)).count();
+    println!(
+        "{} passed, {} failed, {} unknown, {} days not run",
+        count(|v| *v == Verdict::Pass),
+        count(|v| matches!(v, Verdict::Fail { .. })),
+        count(|v| *v == Verdict::Unknown),
+        outcomes.iter().filter(|outcome| outcome.is_err()).count(),
+    );
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solution;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed<'a> = Vec<u32>;
+        type AnswerA = u32;
+        type AnswerB = usize;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part_a(parsed: &Self::Parsed<'_>) -> u32 {
+            parsed.iter().sum()
+        }
+
+        fn part_b(parsed: &Self::Parsed<'_>) -> usize {
+            parsed.len()
+        }
+    }
+
+    struct CheckedSum;
+
+    impl Solution for CheckedSum {
+        type Parsed<'a> = Vec<u32>;
+        type AnswerA = u32;
+        type AnswerB = usize;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            Self::try_parse(input).unwrap()
+        }
+
+        fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part_a(parsed: &Self::Parsed<'_>) -> u32 {
+            Sum::part_a(parsed)
+        }
+
+        fn part_b(parsed: &Self::Parsed<'_>) -> usize {
+            Sum::part_b(parsed)
+        }
+    }
+
+    struct Slow;
+
+    impl Solution for Slow {
+        type Parsed<'a> = ();
+        type AnswerA = u32;
+        type AnswerB = u32;
+
+        fn parse(_input: &str) -> Self::Parsed<'_> {}
+
+        fn part_a(_parsed: &Self::Parsed<'_>) -> u32 {
+            thread::sleep(Duration::from_secs(5));
+            0
+        }
+
+        fn part_b(_parsed: &Self::Parsed<'_>) -> u32 {
+            0
+        }
+    }
+
+    static YEAR: Year = Year {
+        year: 2000,
+        days: &[Day::new(1, &Sum), Day::new(2, &Sum), Day::new(3, &Sum)],
+        input_dir: "inputs",
+        baseline: "benches/baseline.toml",
+    };
+
+    static SLOW_YEAR: Year = Year {
+        year: 2001,
+        days: &[Day::new(1, &Sum), Day::new(2, &Slow), Day::new(3, &Sum)],
+        input_dir: "inputs",
+        baseline: "benches/baseline.toml",
+    };
+
+    fn run_test_days(
+        year: &Year,
+        config: &RunConfig,
+        read_input: impl Fn(u8) -> Result<String, InputError> + Sync,
+    ) -> Vec<DayOutcome> {
+        run_days_with(year, config, read_input, |outcome| outcome)
+    }
+
+    #[test]
+    fn test_missing_input_does_not_stop_other_days() {
+        for parallel in [false, true] {
+            let config = RunConfig {
+                parallel,
+                ..RunConfig::default()
+            };
+            let outcomes = run_test_days(&YEAR, &config, |day| match day {
+                2 => Err(InputError::Missing("inputs/02.txt".into())),
+                _ => Ok("1\n2\n3".to_string()),
+            });
+
+            assert_eq!(outcomes.len(), 3);
+            assert_eq!(outcomes[0].as_ref().unwrap().part_a, "6");
+            assert!(matches!(
+                outcomes[1],
+                Err(DayError {
+                    year: 2000,
+                    day: 2,
+                    kind: DayErrorKind::Input(InputError::Missing(_))
+                })
+            ));
+            assert_eq!(outcomes[2].as_ref().unwrap().part_b, "3");
+        }
+    }
+
+    #[test]
+    fn test_hardened_parser_reports_invalid_input() {
+        static DAY: Day = Day::new(1, &CheckedSum);
+        let outcome = run_day(2002, &DAY, "1\nnot a number".to_string(), None);
+        assert!(matches!(
+            outcome,
+            Err(DayError {
+                kind: DayErrorKind::Parse(_),
+                ..
+            })
+        ));
+        assert_eq!(
+            run_day(2002, &DAY, "1\n2".to_string(), None)
+                .unwrap()
+                .part_a,
+            "3"
+        );
+    }
+
+    #[test]
+    fn test_panic_and_timeout_do_not_stop_other_days() {
+        let config = RunConfig {
+            timeout: Some(Duration::from_millis(100)),
+            ..RunConfig::default()
+        };
+        let outcomes = run_test_days(&SLOW_YEAR, &config, |day| match day {
+            1 => Ok("1\nnot a number".to_string()),
+            _ => Ok("1\n2".to_string()),
+        });
+
+        assert!(matches!(
+            &outcomes[0],
+            Err(DayError {
+                kind: DayErrorKind::Panicked(message),
+                ..
+            }) if message.contains("ParseIntError")
+        ));
+        assert!(matches!(
+            outcomes[1],
+            Err(DayError {
+                day: 2,
+                kind: DayErrorKind::TimedOut(_),
+                ..
+            })
+        ));
+        assert_eq!(outcomes[2].as_ref().unwrap().part_a, "3");
+    }
+
+    #[test]
+    fn test_parallel_results_are_in_day_order() {
+        let config = RunConfig {
+            parallel: true,
+            ..RunConfig::default()
+        };
+        let outcomes = run_test_days(&YEAR, &config, |day| Ok("1\n".repeat(day.into())));
+
+        let answers: Vec<&str> = outcomes
+            .iter()
+            .map(|outcome| outcome.as_ref().unwrap().part_a.as_str())
+            .collect();
+        assert_eq!(answers, ["1", "2", "3"]);
+    }
+}
@@ -0,0 +1,326 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// What a search found: the cost of every state it reached, the goals it
+/// stopped at and how it got to each state.
+///
+/// All predecessors on a cheapest path are kept, not just the first one found,
+/// so every shortest path can be recovered.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    starts: HashSet<S>,
+    costs: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new(starts: impl IntoIterator<Item = S>, zero: C) -> Self {
+        let starts: HashSet<S> = starts.into_iter().collect();
+        Search {
+            costs: starts.iter().map(|start| (start.clone(), zero)).collect(),
+            starts,
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+        }
+    }
+
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// The cost of every state that was reached.
+    pub fn costs(&self) -> &HashMap<S, C> {
+        &self.costs
+    }
+
+    /// The cheapest goal that was reached, if any.
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first()
+    }
+
+    /// Every goal reached at the cheapest goal cost.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal().and_then(|goal| self.cost(goal))
+    }
+
+    /// The states right before `state` on its cheapest paths.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One cheapest path from a start to `state`, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.costs.get(state)?;
+        let mut path = vec![state.clone()];
+        while !self.starts.contains(path.last().unwrap()) {
+            let previous = &self.predecessors(path.last().unwrap())[0];
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state on any cheapest path to one of `targets`, the targets
+    /// included.
+    pub fn on_cheapest_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut todo: Vec<S> = targets.into_iter().collect();
+        while let Some(state) = todo.pop() {
+            if seen.insert(state.clone()) {
+                todo.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        seen
+    }
+
+    /// Records reaching `next` from `state` at `cost`. Returns whether that is
+    /// the cheapest way found to `next` so far, so it needs to be visited.
+    fn relax(&mut self, state: &S, next: S, cost: C) -> bool
+    where
+        C: Ord,
+    {
+        match self.costs.get(&next).map(|known| cost.cmp(known)) {
+            Some(Ordering::Greater) => false,
+            // Coming back to a start for free, with zero cost steps, is not a
+            // way to reach it.
+            Some(Ordering::Equal) if self.starts.contains(&next) => false,
+            Some(Ordering::Equal) => {
+                let predecessors = self.predecessors.entry(next).or_default();
+                if !predecessors.contains(state) {
+                    predecessors.push(state.clone());
+                }
+                false
+            }
+            Some(Ordering::Less) | None => {
+                self.costs.insert(next.clone(), cost);
+                self.predecessors.insert(next, vec![state.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// A state waiting in the queue, ordered so the cheapest estimate pops first.
+struct Queued<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+/// Cheapest paths from `starts`, where `successors` gives the states one step
+/// away with the cost of that step.
+///
+/// Stops once every state as cheap as the cheapest goal has been visited, or
+/// when there is nothing left to visit if `is_goal` never holds.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// [`dijkstra`] guided by `heuristic`, which must never overestimate the cost
+/// left to reach a goal.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new(starts, C::default());
+    let mut queue: BinaryHeap<_> = search
+        .costs
+        .keys()
+        .map(|state| Queued {
+            estimate: heuristic(state),
+            cost: C::default(),
+            state: state.clone(),
+        })
+        .collect();
+    let mut goal_cost = None;
+
+    while let Some(Queued {
+        estimate,
+        cost,
+        state,
+    }) = queue.pop()
+    {
+        if goal_cost.is_some_and(|goal_cost| estimate > goal_cost) {
+            break;
+        }
+        if search.cost(&state).is_some_and(|known| cost > known) {
+            continue;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            if !search.goals.contains(&state) {
+                search.goals.push(state);
+            }
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if search.relax(&state, next.clone(), next_cost) {
+                queue.push(Queued {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    search
+}
+
+/// Fewest steps from any of `starts`, where every step costs the same.
+///
+/// Stops like [`dijkstra`] does.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(starts, 0);
+    let mut queue: VecDeque<_> = search.costs.keys().cloned().collect();
+
+    while let Some(state) = queue.pop_front() {
+        let steps = search.costs[&state];
+        if search
+            .goal_cost()
+            .is_some_and(|goal_steps| steps > goal_steps)
+        {
+            break;
+        }
+        if is_goal(&state) {
+            search.goals.push(state);
+            continue;
+        }
+        for next in successors(&state) {
+            if search.relax(&state, next.clone(), steps + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A diamond 0 -> {1, 2} -> 3, where going through 2 costs more, and a
+    /// dead end 4.
+    fn edges(state: &u8) -> Vec<(u8, u32)> {
+        match state {
+            0 => vec![(1, 1), (2, 2), (4, 1)],
+            1 => vec![(3, 5)],
+            2 => vec![(3, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra([0], edges, |&state| state == 3);
+        assert_eq!(search.goal(), Some(&3));
+        assert_eq!(search.goal_cost(), Some(6));
+        assert_eq!(search.path_to(&3), Some(vec![0, 1, 3]));
+        assert_eq!(search.predecessors(&3), [1]);
+        assert_eq!(search.path_to(&5), None);
+    }
+
+    #[test]
+    fn test_dijkstra_keeps_every_cheapest_path() {
+        let search = dijkstra(
+            [0],
+            |&s| edges(&s).into_iter().map(|(n, _)| (n, 1)),
+            |_| false,
+        );
+        assert_eq!(search.cost(&4), Some(1));
+        assert_eq!(search.on_cheapest_paths([3]), HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_zero_cost_cycles_keep_paths_finite() {
+        // 0 <-> 1 <-> 2 all for free, and 0 -> 3 for 1.
+        let successors = |&s: &u8| match s {
+            0 => vec![(1, 0), (0, 0), (3, 1)],
+            1 => vec![(0, 0), (2, 0)],
+            2 => vec![(1, 0), (2, 0)],
+            _ => vec![],
+        };
+        let search = dijkstra([0], successors, |_| false);
+        assert!(search.predecessors(&0).is_empty());
+        assert_eq!(search.path_to(&0), Some(vec![0]));
+        assert_eq!(search.path_to(&2), Some(vec![0, 1, 2]));
+        assert_eq!(search.path_to(&3), Some(vec![0, 3]));
+        assert_eq!(search.on_cheapest_paths([2]), HashSet::from([0, 1, 2]));
+
+        let unit = bfs(
+            [0],
+            |&s: &u8| successors(&s).into_iter().map(|(n, _)| n),
+            |_| false,
+        );
+        assert_eq!(unit.path_to(&2), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra_on_a_line() {
+        let successors = |&x: &i32| [(x - 1, 1), (x + 1, 1)];
+        let search = astar([0], successors, |&x| (10 - x).abs(), |&x| x == 10);
+        assert_eq!(search.goal_cost(), Some(10));
+        assert_eq!(search.path_to(&10).unwrap().len(), 11);
+        assert!(search.costs().len() < 20);
+    }
+
+    #[test]
+    fn test_bfs_from_many_starts() {
+        let search = bfs([0, 9], |&x: &i32| [x - 1, x + 1], |&x| x == 6);
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(search.path_to(&6), Some(vec![9, 8, 7, 6]));
+        let unbounded = bfs([0u8], |&x| x.checked_add(1), |_| false);
+        assert_eq!(unbounded.cost(&255), Some(255));
+    }
+}
@@ -0,0 +1,338 @@
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::iter::Sum;
+use std::ops::Add;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::{day_file_name, load_input, InputError, ParseError};
+
+/// A single Advent of Code puzzle, split into parsing and the two parts.
+///
+/// The parsed input may borrow from the puzzle input, so days that work
+/// directly on the input text can simply use `&'a str`.
+pub trait Solution {
+    type Parsed<'a>;
+    type AnswerA: Display;
+    type AnswerB: Display;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+
+    /// Parses without panicking on malformed input. Days with a hardened
+    /// parser override this, the others fall back on [`Solution::parse`].
+    fn try_parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(Self::parse(input))
+    }
+
+    fn part_a(parsed: &Self::Parsed<'_>) -> Self::AnswerA;
+    fn part_b(parsed: &Self::Parsed<'_>) -> Self::AnswerB;
+}
+
+/// Object safe version of [`Solution`], so days with different input and
+/// answer types can live in the same registry.
+pub trait DynSolution: Sync {
+    fn solve(&self, input: &str) -> (String, String);
+    fn solve_part(&self, input: &str, part: Part) -> String;
+    fn solve_timed(&self, input: &str) -> Result<Solved, ParseError>;
+
+    /// Only parses `input`, for fuzzing the parser.
+    fn try_parse(&self, input: &str) -> Result<(), ParseError>;
+
+    /// Hands `measure` a repeatable closure for each of the [`STEPS`] in turn,
+    /// so parsing and the parts can be benchmarked on their own. The parts
+    /// reuse a single parse of `input`.
+    fn for_each_step(&self, input: &str, measure: &mut dyn FnMut(&str, &mut dyn FnMut()));
+}
+
+/// The separately benchmarked steps of solving a day.
+pub const STEPS: [&str; 3] = ["parse", "part_a", "part_b"];
+
+/// Both answers of a day, with the time spent in each step.
+pub struct Solved {
+    pub part_a: String,
+    pub part_b: String,
+    pub timings: Timings,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_a: Duration,
+    pub part_b: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_a + self.part_b
+    }
+}
+
+impl Add for Timings {
+    type Output = Timings;
+
+    fn add(self, other: Timings) -> Timings {
+        Timings {
+            parse: self.parse + other.parse,
+            part_a: self.part_a + other.part_a,
+            part_b: self.part_b + other.part_b,
+        }
+    }
+}
+
+impl Sum for Timings {
+    fn sum<I: Iterator<Item = Timings>>(iter: I) -> Timings {
+        iter.fold(Timings::default(), Add::add)
+    }
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn solve(&self, input: &str) -> (String, String) {
+        let parsed = S::parse(input);
+        (
+            S::part_a(&parsed).to_string(),
+            S::part_b(&parsed).to_string(),
+        )
+    }
+
+    fn solve_part(&self, input: &str, part: Part) -> String {
+        let parsed = S::parse(input);
+        match part {
+            Part::A => S::part_a(&parsed).to_string(),
+            Part::B => S::part_b(&parsed).to_string(),
+        }
+    }
+
+    fn for_each_step(&self, input: &str, measure: &mut dyn FnMut(&str, &mut dyn FnMut())) {
+        let [parse, part_a, part_b] = STEPS;
+        measure(parse, &mut || {
+            black_box(S::parse(black_box(input)));
+        });
+        let parsed = S::parse(input);
+        measure(part_a, &mut || {
+            black_box(S::part_a(black_box(&parsed)));
+        });
+        measure(part_b, &mut || {
+            black_box(S::part_b(black_box(&parsed)));
+        });
+    }
+
+    fn solve_timed(&self, input: &str) -> Result<Solved, ParseError> {
+        let start = Instant::now();
+        let parsed = S::try_parse(input)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let part_a = S::part_a(&parsed).to_string();
+        let part_a_time = start.elapsed();
+
+        let start = Instant::now();
+        let part_b = S::part_b(&parsed).to_string();
+        let part_b_time = start.elapsed();
+
+        Ok(Solved {
+            part_a,
+            part_b,
+            timings: Timings {
+                parse,
+                part_a: part_a_time,
+                part_b: part_b_time,
+            },
+        })
+    }
+
+    fn try_parse(&self, input: &str) -> Result<(), ParseError> {
+        S::try_parse(input).map(drop)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" | "1" => Ok(Part::A),
+            "b" | "B" | "2" => Ok(Part::B),
+            _ => Err(format!("unknown part `{s}`, expected `a` or `b`")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+/// A registry entry: the day of the month and the solution for that day.
+pub struct Day {
+    pub day: u8,
+    pub solution: &'static dyn DynSolution,
+    /// Whether the solution overrides [`Solution::try_parse`] to reject
+    /// malformed input instead of panicking. These days get fuzzed.
+    pub checked_parser: bool,
+}
+
+impl Day {
+    pub const fn new(day: u8, solution: &'static dyn DynSolution) -> Self {
+        Day {
+            day,
+            solution,
+            checked_parser: false,
+        }
+    }
+
+    /// A day with a checked parser, see [`Day::checked_parser`].
+    pub const fn checked(day: u8, solution: &'static dyn DynSolution) -> Self {
+        Day {
+            day,
+            solution,
+            checked_parser: true,
+        }
+    }
+}
+
+/// All registered days of one Advent of Code year, together with the folder
+/// their puzzle inputs are read from by default and the file their benchmark
+/// baseline is kept in.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+    pub input_dir: &'static str,
+    pub baseline: &'static str,
+}
+
+impl Year {
+    pub fn day(&self, day: u8) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+
+    /// The days whose parser rejects malformed input instead of panicking.
+    pub fn checked_days(&self) -> impl Iterator<Item = &'static Day> {
+        self.days.iter().filter(|d| d.checked_parser)
+    }
+
+    /// The folder with this year's inputs: `{root}/{year}` when an input root
+    /// is given, the year's own input folder otherwise.
+    pub fn input_dir(&self, root: Option<&Path>) -> PathBuf {
+        match root {
+            Some(root) => root.join(self.year.to_string()),
+            None => PathBuf::from(self.input_dir),
+        }
+    }
+
+    pub fn input_path(&self, root: Option<&Path>, day: u8) -> PathBuf {
+        self.input_dir(root).join(day_file_name(day))
+    }
+
+    /// The accepted answers are kept next to the inputs they belong to.
+    pub fn answers_path(&self, root: Option<&Path>) -> PathBuf {
+        self.input_dir(root).join("answers.toml")
+    }
+
+    pub fn load_input(&self, root: Option<&Path>, day: u8) -> Result<String, InputError> {
+        load_input(self.input_path(root, day))
+    }
+}
+
+/// Collects the `DAY` constant each of the given `day_NN` modules exports
+/// into a `DAYS` registry, in the order given.
+///
+/// The modules are declared with plain `mod` items next to it rather than
+/// by the macro, so that rustfmt can still find them.
+#[macro_export]
+macro_rules! days {
+    ($($module:ident),* $(,)?) => {
+        pub static DAYS: &[$crate::Day] = &[$($module::DAY),*];
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        type Parsed<'a> = Vec<&'a str>;
+        type AnswerA = usize;
+        type AnswerB = String;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            input.lines().collect()
+        }
+
+        fn part_a(parsed: &Self::Parsed<'_>) -> usize {
+            parsed.len()
+        }
+
+        fn part_b(parsed: &Self::Parsed<'_>) -> String {
+            parsed.concat()
+        }
+    }
+
+    #[test]
+    fn test_dyn_solve() {
+        let day = Day::new(1, &Lengths);
+        assert_eq!(day.solution.solve("ab\ncd"), ("2".into(), "abcd".into()));
+        assert_eq!(day.solution.solve_part("ab\ncd", Part::B), "abcd");
+    }
+
+    #[test]
+    fn test_for_each_step() {
+        let mut steps = Vec::new();
+        Lengths.for_each_step("ab\ncd", &mut |step, run| {
+            run();
+            steps.push(step.to_string());
+        });
+        assert_eq!(steps, ["parse", "part_a", "part_b"]);
+    }
+
+    #[test]
+    fn test_checked_days() {
+        static DAYS: &[Day] = &[Day::new(1, &Lengths), Day::checked(2, &Lengths)];
+        let year = Year {
+            year: 2022,
+            days: DAYS,
+            input_dir: "",
+            baseline: "",
+        };
+        let checked: Vec<u8> = year.checked_days().map(|d| d.day).collect();
+        assert_eq!(checked, [2]);
+    }
+
+    #[test]
+    fn test_input_path() {
+        let year = Year {
+            year: 2022,
+            days: &[],
+            input_dir: "crate/inputs",
+            baseline: "crate/benches/baseline.toml",
+        };
+        assert_eq!(year.input_path(None, 3), Path::new("crate/inputs/03.txt"));
+        assert_eq!(
+            year.input_path(Some(Path::new("all")), 14),
+            Path::new("all/2022/14.txt")
+        );
+        assert_eq!(
+            year.answers_path(None),
+            Path::new("crate/inputs/answers.toml")
+        );
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!("a".parse(), Ok(Part::A));
+        assert_eq!("2".parse(), Ok(Part::B));
+        assert!("c".parse::<Part>().is_err());
+    }
+}
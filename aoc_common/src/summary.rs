@@ -0,0 +1,135 @@
+use std::fmt::Write;
+
+use crate::{DayOutcome, Timings};
+
+/// Time spent on all days of one year that could be run.
+#[derive(Debug, PartialEq)]
+pub struct YearSummary {
+    pub year: u16,
+    pub days_run: usize,
+    pub days_failed: usize,
+    pub timings: Timings,
+}
+
+/// Groups the outcomes by year, in the order the years first appear.
+pub fn summarise(outcomes: &[DayOutcome]) -> Vec<YearSummary> {
+    let mut summaries: Vec<YearSummary> = Vec::new();
+    for outcome in outcomes {
+        let year = match outcome {
+            Ok(result) => result.year,
+            Err(err) => err.year,
+        };
+        let index = match summaries.iter().position(|summary| summary.year == year) {
+            Some(index) => index,
+            None => {
+                summaries.push(YearSummary {
+                    year,
+                    days_run: 0,
+                    days_failed: 0,
+                    timings: Timings::default(),
+                });
+                summaries.len() - 1
+            }
+        };
+        let summary = &mut summaries[index];
+        match outcome {
+            Ok(result) => {
+                summary.days_run += 1;
+                summary.timings = summary.timings + result.timings;
+            }
+            Err(_) => summary.days_failed += 1,
+        }
+    }
+    summaries
+}
+
+pub fn format_summary(summaries: &[YearSummary]) -> String {
+    let mut table = format!(
+        "{:<6}{:>6}{:>8}{:>12}{:>12}{:>12}{:>12}\n",
+        "Year", "Days", "Failed", "Parse", "Part A", "Part B", "Total"
+    );
+    let mut row = |year: &dyn std::fmt::Display, run: usize, failed: usize, t: &Timings| {
+        writeln!(
+            table,
+            "{year:<6}{run:>6}{failed:>8}{:>12.2?}{:>12.2?}{:>12.2?}{:>12.2?}",
+            t.parse,
+            t.part_a,
+            t.part_b,
+            t.total()
+        )
+        .expect("Writing to a String cannot fail");
+    };
+    for summary in summaries {
+        row(
+            &summary.year,
+            summary.days_run,
+            summary.days_failed,
+            &summary.timings,
+        );
+    }
+    if summaries.len() > 1 {
+        row(
+            &"All",
+            summaries.iter().map(|s| s.days_run).sum(),
+            summaries.iter().map(|s| s.days_failed).sum(),
+            &summaries.iter().map(|s| s.timings).sum(),
+        );
+    }
+    table
+}
+
+pub fn print_summary(outcomes: &[DayOutcome]) {
+    print!("{}", format_summary(&summarise(outcomes)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DayError, DayErrorKind, DayResult, InputError};
+    use std::time::Duration;
+
+    fn result(year: u16, day: u8, millis: u64) -> DayOutcome {
+        let time = Duration::from_millis(millis);
+        Ok(DayResult {
+            year,
+            day,
+            part_a: String::new(),
+            part_b: String::new(),
+            timings: Timings {
+                parse: time,
+                part_a: 2 * time,
+                part_b: 3 * time,
+            },
+        })
+    }
+
+    #[test]
+    fn test_summarise() {
+        let outcomes = vec![
+            result(2022, 1, 1),
+            Err(DayError {
+                year: 2022,
+                day: 2,
+                kind: DayErrorKind::Input(InputError::Missing("inputs/02.txt".into())),
+            }),
+            result(2022, 3, 2),
+            result(2024, 1, 5),
+        ];
+        let summaries = summarise(&outcomes);
+
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].year, 2022);
+        assert_eq!(summaries[0].days_run, 2);
+        assert_eq!(summaries[0].days_failed, 1);
+        assert_eq!(summaries[0].timings.total(), Duration::from_millis(18));
+        assert_eq!(summaries[1].timings.part_b, Duration::from_millis(15));
+
+        let table = format_summary(&summaries);
+        assert_eq!(table.lines().count(), 4);
+        assert!(table
+            .lines()
+            .last()
+            .unwrap()
+            .starts_with("All        3       1"));
+    }
+}
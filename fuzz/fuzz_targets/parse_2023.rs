@@ -0,0 +1,7 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (u8, &str)| {
+    aoc_fuzz::parse(&aoc_2023::days::YEAR, input);
+});
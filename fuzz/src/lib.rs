@@ -0,0 +1,15 @@
+//! Fuzzing for the day parsers that return a `ParseError` on bad input.
+//!
+//! Run a year's target with `cargo +nightly fuzz run parse_2024` from this
+//! directory. Any panic is a parser that still needs hardening.
+
+use aoc_common::Year;
+
+/// Feeds `input` to one of the year's checked days, picked by `day`.
+pub fn parse(year: &Year, (day, input): (u8, &str)) {
+    let days: Vec<_> = year.checked_days().collect();
+    if days.is_empty() {
+        return;
+    }
+    let _ = days[day as usize % days.len()].solution.try_parse(input);
+}